@@ -10,7 +10,8 @@ use structopt::StructOpt;
 use texture_common::math::{CheckedDiv, Decimal};
 use tracing::{info, warn};
 
-use price_proxy::state::price_feed::{FeedType, PriceFeedParams, SYMBOL_MAX_SIZE};
+use price_proxy::pda::PRICE_FEED_SEED_MAX_LEN;
+use price_proxy::state::price_feed::{FeedType, PriceFeedParams, PriceFeedSource, SYMBOL_MAX_SIZE};
 use price_proxy::state::stake_pool::StakePool;
 use price_proxy_client::{
     get_account_with_retries, PriceFeedSignatureView, PriceProxyClient as App, PriceProxyClient,
@@ -52,6 +53,24 @@ async fn main() {
             source_address,
             transform_source_address,
             verification_level,
+            max_confidence_bps,
+            transform_op,
+            fallback_source,
+            fallback_source_address,
+            fallback_after_sec,
+            reference_source,
+            reference_source_address,
+            max_divergence_bps,
+            max_deviation_bps,
+            deviation_window_sec,
+            min_price,
+            max_price,
+            ema_half_life_sec,
+            writer_quorum,
+            submission_window_sec,
+            max_future_sec,
+            pda,
+            seed,
         } => {
             if symbol.len() > SYMBOL_MAX_SIZE {
                 println!("Symbol name is too long. {} max.", SYMBOL_MAX_SIZE);
@@ -65,6 +84,24 @@ async fn main() {
                 return;
             }
 
+            if fallback_source.is_some() != fallback_source_address.is_some() {
+                println!("fallback source and fallback source address must be set together");
+                return;
+            }
+
+            if reference_source.is_some() != reference_source_address.is_some() {
+                println!("reference source and reference source address must be set together");
+                return;
+            }
+
+            if seed
+                .as_ref()
+                .is_some_and(|seed| seed.len() > PRICE_FEED_SEED_MAX_LEN)
+            {
+                println!("Seed is too long. {} max.", PRICE_FEED_SEED_MAX_LEN);
+                return;
+            }
+
             let transform_source = if let Some(transform_source) = transform_source {
                 transform_source
             } else {
@@ -85,11 +122,48 @@ async fn main() {
                 &logo_url,
                 source,
                 transform_source,
-            );
-            let created_price_feed = app
-                .create_price_feed(params, source_address, transform_source_address)
+            )
+            .with_max_confidence_bps(max_confidence_bps)
+            .with_transform_op(transform_op)
+            .with_circuit_breaker(max_deviation_bps, deviation_window_sec)
+            .with_price_bounds(min_price, max_price)
+            .with_ema_half_life(ema_half_life_sec)
+            .with_writer_quorum(writer_quorum, submission_window_sec);
+            let params = match (fallback_source, fallback_source_address) {
+                (Some(fallback_source), Some(fallback_source_address)) => {
+                    params.with_fallback(fallback_source, fallback_source_address)
+                }
+                _ => params,
+            };
+            let params = match (reference_source, reference_source_address) {
+                (Some(reference_source), Some(reference_source_address)) => params.with_reference(
+                    reference_source,
+                    reference_source_address,
+                    max_divergence_bps,
+                ),
+                _ => params,
+            };
+            let params = match max_future_sec {
+                Some(max_future_sec) => params.with_max_future(max_future_sec),
+                None => params,
+            };
+            let params = match fallback_after_sec {
+                Some(fallback_after_sec) => params.with_fallback_after(fallback_after_sec),
+                None => params,
+            };
+            let created_price_feed = if pda {
+                app.create_price_feed_pda(
+                    params,
+                    source_address,
+                    transform_source_address,
+                    seed.map(String::into_bytes),
+                )
                 .await
-                .expect("create price-feed");
+            } else {
+                app.create_price_feed(params, source_address, transform_source_address)
+                    .await
+            }
+            .expect("create price-feed");
             println_cmd_out!(&created_price_feed);
         }
         opts::Command::PriceFeed { key } => {
@@ -105,9 +179,13 @@ async fn main() {
             let price_feeds = res.expect("get price-feeds");
             println_cmd_out!(&price_feeds);
         }
-        opts::Command::WritePrice { key, price } => {
+        opts::Command::WritePrice {
+            key,
+            price,
+            confidence,
+        } => {
             let signature = app
-                .write_price(key, price, chrono::Utc::now().timestamp())
+                .write_price(key, price, chrono::Utc::now().timestamp(), confidence)
                 .await
                 .expect("write price");
             println_cmd_out!(&signature);
@@ -158,6 +236,7 @@ async fn main() {
             key,
             maximum_age_sec,
             pyth_api_url,
+            switchboard_api_url,
         } => {
             let client = PriceProxyClient {
                 rpc: RpcClient::new_with_commitment(
@@ -172,7 +251,7 @@ async fn main() {
             };
 
             let signatures = client
-                .holistic_update_price(&key, maximum_age_sec, pyth_api_url)
+                .holistic_update_price(&key, maximum_age_sec, pyth_api_url, switchboard_api_url)
                 .await
                 .map_err(|err| println!("Error updating price feed: {}", err))
                 .unwrap();
@@ -192,7 +271,61 @@ async fn main() {
             source_address,
             transform_source_address,
             verification_level,
+            max_confidence_bps,
+            transform_op,
+            fallback_source,
+            fallback_source_address,
+            fallback_after_sec,
+            reference_source,
+            reference_source_address,
+            max_divergence_bps,
+            max_deviation_bps,
+            deviation_window_sec,
+            min_price,
+            max_price,
+            ema_half_life_sec,
+            writer_quorum,
+            submission_window_sec,
+            max_future_sec,
         } => {
+            let fallback = match (fallback_source, fallback_source_address) {
+                (Some(PriceFeedSource::Unknown), _) => {
+                    Some((PriceFeedSource::Unknown, Pubkey::default()))
+                }
+                (Some(fallback_source), Some(fallback_source_address)) => {
+                    Some((fallback_source, fallback_source_address))
+                }
+                (None, None) => None,
+                _ => {
+                    println!("fallback source and fallback source address must be set together");
+                    return;
+                }
+            };
+
+            let reference = match (reference_source, reference_source_address) {
+                (Some(PriceFeedSource::Unknown), _) => {
+                    Some((PriceFeedSource::Unknown, Pubkey::default(), 0))
+                }
+                (Some(reference_source), Some(reference_source_address)) => Some((
+                    reference_source,
+                    reference_source_address,
+                    max_divergence_bps.unwrap_or_default(),
+                )),
+                (None, None) if max_divergence_bps.is_some() => {
+                    let price_feed = app.price_feed(&key).await.expect("get price-feed");
+                    Some((
+                        price_feed.price_feed.reference_source(),
+                        price_feed.price_feed.reference_source_address,
+                        max_divergence_bps.unwrap_or_default(),
+                    ))
+                }
+                (None, None) => None,
+                _ => {
+                    println!("reference source and reference source address must be set together");
+                    return;
+                }
+            };
+
             let signature = app
                 .alter_price_feed(
                     key,
@@ -205,6 +338,19 @@ async fn main() {
                     transform_source,
                     source_address,
                     transform_source_address,
+                    max_confidence_bps,
+                    transform_op,
+                    fallback,
+                    reference,
+                    max_deviation_bps,
+                    deviation_window_sec,
+                    min_price,
+                    max_price,
+                    ema_half_life_sec,
+                    writer_quorum,
+                    submission_window_sec,
+                    max_future_sec,
+                    fallback_after_sec,
                 )
                 .await
                 .expect("altered Price-feed");
@@ -219,6 +365,109 @@ async fn main() {
             println_cmd_out!(&signature);
             println!("Deleted Price-feed: {}", key);
         }
+        opts::Command::MigratePriceFeed { key } => {
+            let signature = app
+                .migrate_price_feed(key)
+                .await
+                .expect("migrate Price-feed");
+
+            println_cmd_out!(&signature);
+            println!("Migrated Price-feed: {}", key);
+        }
+        opts::Command::Twap { key, window_sec } => {
+            let twap = app.twap(key, window_sec).await.expect("get TWAP");
+
+            println_cmd_out!(&twap);
+        }
+        opts::Command::OverrideCircuitBreaker { key, price } => {
+            let signature = app
+                .override_circuit_breaker(key, price)
+                .await
+                .expect("override circuit breaker");
+
+            println_cmd_out!(&signature);
+            println!("Overridden circuit breaker of Price-feed: {}", key);
+        }
+        opts::Command::ProposeAuthority { key, new_authority } => {
+            let signature = app
+                .propose_authority(key, new_authority)
+                .await
+                .expect("propose authority");
+
+            println_cmd_out!(&signature);
+            println!(
+                "Proposed authority {} of Price-feed: {}",
+                new_authority, key
+            );
+        }
+        opts::Command::AcceptAuthority { key } => {
+            let signature = app.accept_authority(key).await.expect("accept authority");
+
+            println_cmd_out!(&signature);
+            println!("Accepted authority of Price-feed: {}", key);
+        }
+        opts::Command::AddWriter { key, writer } => {
+            let signature = app.add_writer(key, writer).await.expect("add writer");
+
+            println_cmd_out!(&signature);
+            println!("Added writer {} of Price-feed: {}", writer, key);
+        }
+        opts::Command::RemoveWriter { key, writer } => {
+            let signature = app.remove_writer(key, writer).await.expect("remove writer");
+
+            println_cmd_out!(&signature);
+            println!("Removed writer {} of Price-feed: {}", writer, key);
+        }
+        opts::Command::CreateCompositeFeed { legs, quorum } => {
+            let created_composite_feed = app
+                .create_composite_feed(legs, quorum)
+                .await
+                .expect("create composite-feed");
+            println_cmd_out!(&created_composite_feed);
+        }
+        opts::Command::AlterCompositeFeed { key, legs, quorum } => {
+            let signature = app
+                .alter_composite_feed(key, legs, quorum)
+                .await
+                .expect("altered CompositeFeed");
+
+            println_cmd_out!(&signature);
+            println!("Altered CompositeFeed: {}", key);
+        }
+        opts::Command::DeleteCompositeFeed { key } => {
+            let signature = app
+                .delete_composite_feed(key)
+                .await
+                .expect("delete CompositeFeed");
+
+            println_cmd_out!(&signature);
+            println!("Deleted CompositeFeed: {}", key);
+        }
+        opts::Command::CompositeFeed { key } => {
+            let composite_feed = app.composite_feed(&key).await.expect("get composite-feed");
+            println_cmd_out!(&composite_feed);
+        }
+        opts::Command::CreatePriceHistory { price_feed } => {
+            let created_price_history = app
+                .create_price_history(price_feed)
+                .await
+                .expect("create PriceHistory");
+
+            println_cmd_out!(&created_price_history);
+        }
+        opts::Command::DeletePriceHistory { key } => {
+            let signature = app
+                .delete_price_history(key)
+                .await
+                .expect("delete PriceHistory");
+
+            println_cmd_out!(&signature);
+            println!("Deleted PriceHistory: {}", key);
+        }
+        opts::Command::PriceHistory { key } => {
+            let price_history = app.price_history(&key).await.expect("get price-history");
+            println_cmd_out!(&price_history);
+        }
         opts::Command::ShowStakePoolPrice { key, symbol } => loop {
             let stakepool_acc = get_account_with_retries(&app.rpc, &key)
                 .await
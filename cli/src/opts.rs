@@ -4,8 +4,9 @@ use derive_more::FromStr;
 use solana_sdk::{commitment_config::CommitmentLevel, pubkey::Pubkey};
 use structopt::StructOpt;
 
+use price_proxy::state::composite_feed::CompositeLegParams;
 use price_proxy::state::price_feed::{
-    FeedType, PriceFeedSource, QuoteSymbol, WormholeVerificationLevel,
+    FeedType, PriceFeedSource, QuoteSymbol, TransformOp, WormholeVerificationLevel,
 };
 use texture_common::math::Decimal;
 
@@ -64,19 +65,88 @@ pub enum Command {
         verification_level: WormholeVerificationLevel,
         /// 1. When source is Pyth - pass feed address. Use GetFeedIdFromHex
         /// 2. When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet
-        /// 3. When source is StakePool - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml
+        /// 3. When source is StakePool or StakePoolNetOfFee - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml
         /// 4. When source is Superlendy - pass reserve address
         /// 5. When source is OffChain - pass `authority`.
+        /// 6. When source is Chainlink - pass store feed (transmissions) account address
+        /// 7. When source is SwitchboardOnDemand - pass pull feed address
+        /// 8. When source is Marinade - pass Marinade state address
+        /// 9. When source is SanctumSpl - pass Sanctum SPL stake pool address
+        /// 10. When feed type is Composite or Median - pass CompositeFeed address
         #[structopt(long)]
         source_address: Pubkey,
         /// 1. When source is Pyth - pass feed address. Use GetFeedIdFromHex
         /// 2. When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet
-        /// 3. When source is StakePool - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml
+        /// 3. When source is StakePool or StakePoolNetOfFee - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml
         /// 4. When source is Superlendy - pass reserve address
         /// 5. When source is OffChain - pass `authority`.
+        /// 6. When source is Chainlink - pass store feed (transmissions) account address
+        /// 7. When source is SwitchboardOnDemand - pass pull feed address
+        /// 8. When source is Marinade - pass Marinade state address
+        /// 9. When source is SanctumSpl - pass Sanctum SPL stake pool address
         /// For Transform feed type only.
         #[structopt(long)]
         transform_source_address: Option<Pubkey>,
+        /// Maximum allowed confidence / price ratio in basis points. 0 - disabled
+        #[structopt(long, default_value = "0")]
+        max_confidence_bps: u16,
+        /// How source and transform source prices are combined: multiply or divide.
+        /// For Transform feed type only.
+        #[structopt(long, default_value = "multiply")]
+        transform_op: TransformOp,
+        /// Price source used when the primary source is stale.
+        /// For Direct and Transform feed types only.
+        #[structopt(long)]
+        fallback_source: Option<PriceFeedSource>,
+        /// Fallback source address. Same rules as for source address.
+        #[structopt(long)]
+        fallback_source_address: Option<Pubkey>,
+        /// Number of seconds the primary source must produce no price before the fallback
+        /// source is used. 0 - default of 300 sec
+        #[structopt(long)]
+        fallback_after_sec: Option<u32>,
+        /// Price source which the price is compared with on every update.
+        #[structopt(long)]
+        reference_source: Option<PriceFeedSource>,
+        /// Reference source address. Same rules as for source address.
+        #[structopt(long)]
+        reference_source_address: Option<Pubkey>,
+        /// Maximum allowed divergence of the price from the reference price in basis points.
+        #[structopt(long, default_value = "0")]
+        max_divergence_bps: u16,
+        /// Maximum allowed move of the price within deviation window in basis points. 0 - disabled
+        #[structopt(long, default_value = "0")]
+        max_deviation_bps: u16,
+        /// Window in seconds, in which the price may not move more than max deviation.
+        #[structopt(long, default_value = "0")]
+        deviation_window_sec: u32,
+        /// Lowest accepted price
+        #[structopt(long)]
+        min_price: Option<Decimal>,
+        /// Highest accepted price
+        #[structopt(long)]
+        max_price: Option<Decimal>,
+        /// Half-life in seconds of EMA computed on-chain for non-Pyth sources. 0 - EMA follows the price
+        #[structopt(long, default_value = "0")]
+        ema_half_life_sec: u32,
+        /// Minimum number of fresh writer submissions the price of OffChain feed is the median of.
+        /// 0 - every written price is the price
+        #[structopt(long, default_value = "0")]
+        writer_quorum: u8,
+        /// Writer submissions older than this number of seconds are not counted for the quorum.
+        #[structopt(long, default_value = "0")]
+        submission_window_sec: u32,
+        /// Maximum number of seconds written price timestamp may be ahead of the cluster clock.
+        /// 0 - default of 120 sec, 4294967295 - not limited
+        #[structopt(long)]
+        max_future_sec: Option<u32>,
+        /// Create Price-feed at PDA derived from symbol, quote symbol and authority
+        /// instead of a new keypair
+        #[structopt(long)]
+        pda: bool,
+        /// Seed to derive Price-feed PDA from instead of symbol, up to 32 bytes. Requires `--pda`
+        #[structopt(long, requires = "pda")]
+        seed: Option<String>,
     },
     /// Print Price-feed data
     PriceFeed {
@@ -95,8 +165,11 @@ pub enum Command {
         key: Pubkey,
         /// Current price
         price: Decimal,
+        /// Confidence interval of the price
+        #[structopt(long)]
+        confidence: Option<Decimal>,
     },
-    /// Update Price-feed from Pyth or Switchboard feed
+    /// Update Price-feed from Pyth, Switchboard or other on-chain feed
     UpdatePrice {
         /// Price-feed account pubkey
         key: Pubkey,
@@ -106,6 +179,9 @@ pub enum Command {
         /// URL of Pyth API to receive the message
         #[structopt(long)]
         pyth_api_url: Option<String>,
+        /// URL of Switchboard Crossbar API to receive pull feed updates
+        #[structopt(long)]
+        switchboard_api_url: Option<String>,
     },
     /// Force refresh update-timestamp of Price-feed(s). Off-chain source only
     ForcePriceTimestamp {
@@ -150,26 +226,188 @@ pub enum Command {
         /// Asset price source address
         /// 1. When source is Pyth - pass feed address. Use GetFeedIdFromHex
         /// 2. When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet
-        /// 3. When source is StakePool - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml
+        /// 3. When source is StakePool or StakePoolNetOfFee - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml
         /// 4. When source is Superlendy - pass reserve address
         /// 5. When source is OffChain - pass `authority`.
+        /// 6. When source is Chainlink - pass store feed (transmissions) account address
+        /// 7. When source is SwitchboardOnDemand - pass pull feed address
+        /// 8. When source is Marinade - pass Marinade state address
+        /// 9. When source is SanctumSpl - pass Sanctum SPL stake pool address
+        /// 10. When feed type is Composite or Median - pass CompositeFeed address
         #[structopt(long)]
         source_address: Option<Pubkey>,
         /// Asset price transform source address
         /// 1. When source is Pyth - pass feed address. Use GetFeedIdFromHex
         /// 2. When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet
-        /// 3. When source is StakePool - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml
+        /// 3. When source is StakePool or StakePoolNetOfFee - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml
         /// 4. When source is Superlendy - pass reserve address
         /// 5. When source is OffChain - pass `authority`.
+        /// 6. When source is Chainlink - pass store feed (transmissions) account address
+        /// 7. When source is SwitchboardOnDemand - pass pull feed address
+        /// 8. When source is Marinade - pass Marinade state address
+        /// 9. When source is SanctumSpl - pass Sanctum SPL stake pool address
         /// For Transform feed type only.
         #[structopt(long)]
         transform_source_address: Option<Pubkey>,
+        /// Maximum allowed confidence / price ratio in basis points. 0 - disabled
+        #[structopt(long)]
+        max_confidence_bps: Option<u16>,
+        /// How source and transform source prices are combined: multiply or divide.
+        /// For Transform feed type only.
+        #[structopt(long)]
+        transform_op: Option<TransformOp>,
+        /// Price source used when the primary source is stale.
+        /// For Direct and Transform feed types only. Pass `unknown` to remove the fallback.
+        #[structopt(long)]
+        fallback_source: Option<PriceFeedSource>,
+        /// Fallback source address. Same rules as for source address.
+        #[structopt(long)]
+        fallback_source_address: Option<Pubkey>,
+        /// Number of seconds the primary source must produce no price before the fallback
+        /// source is used. 0 - default of 300 sec
+        #[structopt(long)]
+        fallback_after_sec: Option<u32>,
+        /// Price source which the price is compared with on every update.
+        /// Pass `unknown` to remove the reference.
+        #[structopt(long)]
+        reference_source: Option<PriceFeedSource>,
+        /// Reference source address. Same rules as for source address.
+        #[structopt(long)]
+        reference_source_address: Option<Pubkey>,
+        /// Maximum allowed divergence of the price from the reference price in basis points.
+        #[structopt(long)]
+        max_divergence_bps: Option<u16>,
+        /// Maximum allowed move of the price within deviation window in basis points. 0 - disabled
+        #[structopt(long)]
+        max_deviation_bps: Option<u16>,
+        /// Window in seconds, in which the price may not move more than max deviation.
+        #[structopt(long)]
+        deviation_window_sec: Option<u32>,
+        /// Lowest accepted price. 0 - remove the bound
+        #[structopt(long)]
+        min_price: Option<Decimal>,
+        /// Highest accepted price. 0 - remove the bound
+        #[structopt(long)]
+        max_price: Option<Decimal>,
+        /// Half-life in seconds of EMA computed on-chain for non-Pyth sources. 0 - EMA follows the price
+        #[structopt(long)]
+        ema_half_life_sec: Option<u32>,
+        /// Minimum number of fresh writer submissions the price of OffChain feed is the median of.
+        /// 0 - every written price is the price
+        #[structopt(long)]
+        writer_quorum: Option<u8>,
+        /// Writer submissions older than this number of seconds are not counted for the quorum.
+        #[structopt(long)]
+        submission_window_sec: Option<u32>,
+        /// Maximum number of seconds written price timestamp may be ahead of the cluster clock.
+        /// 0 - default of 120 sec, 4294967295 - not limited
+        #[structopt(long)]
+        max_future_sec: Option<u32>,
     },
     /// Delete Price-feed account.
     DeletePriceFeed {
         /// Price-feed account pubkey
         key: Pubkey,
     },
+    /// Grow Price-feed account created by previous program version to the current size.
+    MigratePriceFeed {
+        /// Price-feed account pubkey
+        key: Pubkey,
+    },
+    /// Print time-weighted average price of Price-feed
+    Twap {
+        /// Price-feed account pubkey
+        key: Pubkey,
+        /// TWAP window in seconds
+        #[structopt(long)]
+        window_sec: u32,
+    },
+    /// Allow the next price update of Price-feed with price within max deviation of the approved
+    /// price to bypass the deviation circuit breaker.
+    OverrideCircuitBreaker {
+        /// Price-feed account pubkey
+        key: Pubkey,
+        /// Approved price
+        #[structopt(long)]
+        price: Decimal,
+    },
+    /// Propose new update authority of Price-feed. Proposing the current authority cancels
+    /// the pending transfer.
+    ProposeAuthority {
+        /// Price-feed account pubkey
+        key: Pubkey,
+        /// Proposed update authority
+        new_authority: Pubkey,
+    },
+    /// Accept update authority of Price-feed. Must be run with the proposed authority keypair.
+    AcceptAuthority {
+        /// Price-feed account pubkey
+        key: Pubkey,
+    },
+    /// Authorize writer of off-chain Price-feed. Once any writer is authorized, source address
+    /// can't write price anymore.
+    AddWriter {
+        /// Price-feed account pubkey
+        key: Pubkey,
+        /// Writer pubkey
+        writer: Pubkey,
+    },
+    /// Revoke writer of off-chain Price-feed.
+    RemoveWriter {
+        /// Price-feed account pubkey
+        key: Pubkey,
+        /// Writer pubkey
+        writer: Pubkey,
+    },
+    /// Creates CompositeFeed account which is used as source of Composite or Median Price-feed.
+    CreateCompositeFeed {
+        /// Leg in form `source:address[:op]`, e.g. `pyth:<feed address>:multiply`.
+        /// Legs are applied in order. Can be specified multiple times
+        #[structopt(long = "leg", parse(try_from_str = parse_composite_leg))]
+        legs: Vec<CompositeLegParams>,
+        /// Minimum number of fresh legs for Median Price-feed, 0 - all legs. Lower quorum lets
+        /// anyone skip legs by passing their older accounts. Not used by Composite Price-feed
+        #[structopt(long, default_value = "0")]
+        quorum: u8,
+    },
+    /// Replace legs of CompositeFeed account.
+    AlterCompositeFeed {
+        /// CompositeFeed account pubkey
+        key: Pubkey,
+        /// Leg in form `source:address[:op]`, e.g. `pyth:<feed address>:multiply`.
+        /// Legs are applied in order. Can be specified multiple times
+        #[structopt(long = "leg", parse(try_from_str = parse_composite_leg))]
+        legs: Vec<CompositeLegParams>,
+        /// Minimum number of fresh legs for Median Price-feed, 0 - all legs. Lower quorum lets
+        /// anyone skip legs by passing their older accounts. Not used by Composite Price-feed
+        #[structopt(long, default_value = "0")]
+        quorum: u8,
+    },
+    /// Delete CompositeFeed account.
+    DeleteCompositeFeed {
+        /// CompositeFeed account pubkey
+        key: Pubkey,
+    },
+    /// Print CompositeFeed data
+    CompositeFeed {
+        /// CompositeFeed account pubkey
+        key: Pubkey,
+    },
+    /// Creates PriceHistory account recording every price update of Price-feed.
+    CreatePriceHistory {
+        /// Price-feed account pubkey
+        price_feed: Pubkey,
+    },
+    /// Delete PriceHistory account.
+    DeletePriceHistory {
+        /// PriceHistory account pubkey
+        key: Pubkey,
+    },
+    /// Print PriceHistory observations from the oldest to the latest
+    PriceHistory {
+        /// PriceHistory account pubkey
+        key: Pubkey,
+    },
     ShowStakePoolPrice {
         /// StakePool account pubkey
         key: Pubkey,
@@ -200,3 +438,22 @@ impl Display for KeypairPath {
 fn parse_humantime_duration(src: &str) -> Result<Duration, humantime::DurationError> {
     humantime::Duration::from_str(src).map(Into::into)
 }
+
+fn parse_composite_leg(src: &str) -> anyhow::Result<CompositeLegParams> {
+    let mut parts = src.split(':');
+    let (Some(source), Some(address), op, None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        anyhow::bail!("leg must be in form `source:address[:op]`, given `{}`", src);
+    };
+    let source = PriceFeedSource::from_str(source)
+        .map_err(|_| anyhow::anyhow!("invalid leg source `{}`", source))?;
+    let address = Pubkey::from_str(address)?;
+    let op = match op {
+        Some(op) => {
+            TransformOp::from_str(op).map_err(|_| anyhow::anyhow!("invalid leg op `{}`", op))?
+        }
+        None => TransformOp::Multiply,
+    };
+    Ok(CompositeLegParams::new(source, address, op))
+}
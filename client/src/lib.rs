@@ -19,7 +19,7 @@ use solana_program::{system_instruction, system_program};
 use solana_sdk::account::Account;
 use solana_sdk::clock::Slot;
 use solana_sdk::compute_budget::ComputeBudgetInstruction;
-use solana_sdk::instruction::Instruction;
+use solana_sdk::instruction::{AccountMeta, Instruction};
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::{Keypair, Signature};
 use solana_sdk::signer::Signer;
@@ -34,11 +34,21 @@ use tracing::debug;
 use wormhole_sdk::vaa::{Body, Header};
 
 use price_proxy::instruction::{
-    AlterPriceFeed, CreatePriceFeed, DeletePriceFeed, UpdatePrice, Version, WritePrice,
+    AcceptAuthority, AddWriter, AlterCompositeFeed, AlterPriceFeed, CreateCompositeFeed,
+    CreatePriceFeed, CreatePriceFeedPda, CreatePriceHistory, DeleteCompositeFeed, DeletePriceFeed,
+    DeletePriceHistory, GetTwap, MigratePriceFeed, OverrideCircuitBreaker, ProposeAuthority,
+    RemoveWriter, UpdatePrice, Version, WritePrice, WritePriceWithConfidence, WriteSignedPrice,
 };
+use price_proxy::pda::{
+    find_price_feed_address_by_symbol, find_price_feed_address_with_seed, PRICE_FEED_SEED_MAX_LEN,
+};
+use price_proxy::signed_price::{new_ed25519_instruction, SignedPriceMessage, SIGNATURE_BYTES};
+use price_proxy::state::composite_feed::{CompositeFeed, CompositeLegParams};
 use price_proxy::state::price_feed::{
-    FeedType, PriceFeed, PriceFeedParams, PriceFeedSource, QuoteSymbol, WormholeVerificationLevel,
+    FeedType, PriceFeed, PriceFeedParams, PriceFeedSource, QuoteSymbol, TransformOp,
+    WormholeVerificationLevel,
 };
+use price_proxy::state::price_history::{PriceHistory, PriceObservation};
 use price_proxy::state::utils::str_to_array;
 use pyth_solana_receiver_interface::instruction::{PostUpdate, PostUpdateAtomic, ReclaimRent};
 use pyth_solana_receiver_interface::types::{
@@ -52,6 +62,8 @@ use wormhole_core_bridge_solana_interface::types::WriteEncodedVaaArgs;
 mod core_bridge_state;
 
 const PYTH_PRICE_MESSAGE_API_URL: &str = "https://hermes.pyth.network/api/latest_vaas";
+const SWITCHBOARD_CROSSBAR_API_URL: &str =
+    "https://crossbar.switchboard.xyz/updates/solana/mainnet";
 /**
  * This constant is used to efficiently pack transactions when writing an encoded Pyth VAA to the Wormhole contract.
  * Posting a VAA requires two transactions. If you split the VAA at this index when writing it, the first transaction will be almost full.
@@ -139,6 +151,133 @@ impl From<(HashMap<Pubkey, PriceFeed>, Slot)> for PriceFeedsView {
     }
 }
 
+#[serde_with::serde_as]
+#[derive(serde::Serialize, serde::Deserialize, display_json::DisplayAsJsonPretty)]
+pub struct TwapView {
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub key: Pubkey,
+    pub window_sec: u32,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub twap: Decimal,
+    pub slot: Slot,
+}
+
+#[serde_with::serde_as]
+#[derive(serde::Serialize, serde::Deserialize, display_json::DisplayAsJsonPretty)]
+pub struct PriceHistorySignatureView {
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub price_history: Pubkey,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub signature: Signature,
+}
+
+/// `PriceHistory` with observations ordered from the oldest to the latest.
+#[serde_with::serde_as]
+#[derive(serde::Serialize, serde::Deserialize, display_json::DisplayAsJsonPretty)]
+pub struct PriceHistoryView {
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub key: Pubkey,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub price_feed: Pubkey,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub authority: Pubkey,
+    pub observations: Vec<PriceObservation>,
+    pub slot: Slot,
+}
+impl From<(Pubkey, PriceHistory, Slot)> for PriceHistoryView {
+    fn from((key, price_history, slot): (Pubkey, PriceHistory, Slot)) -> Self {
+        Self {
+            key,
+            price_feed: price_history.price_feed,
+            authority: price_history.authority,
+            observations: price_history.observations(),
+            slot,
+        }
+    }
+}
+
+#[serde_with::serde_as]
+#[derive(serde::Serialize, serde::Deserialize, display_json::DisplayAsJsonPretty)]
+pub struct CompositeFeedSignatureView {
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub composite_feed: Pubkey,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub signature: Signature,
+}
+
+#[serde_with::serde_as]
+#[derive(serde::Serialize, serde::Deserialize, display_json::DisplayAsJsonPretty)]
+pub struct CompositeFeedView {
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub key: Pubkey,
+    pub composite_feed: CompositeFeed,
+    pub slot: Slot,
+}
+impl From<(Pubkey, CompositeFeed, Slot)> for CompositeFeedView {
+    fn from((key, composite_feed, slot): (Pubkey, CompositeFeed, Slot)) -> Self {
+        Self {
+            key,
+            composite_feed,
+            slot,
+        }
+    }
+}
+
+/// Response of Switchboard Crossbar API to pull feed update request.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SwitchboardPullUpdate {
+    pub success: bool,
+    /// Oracle signatures verification and `pull_feed` submit instructions
+    #[serde(rename = "pullIxns")]
+    pub pull_ixs: Vec<SwitchboardInstruction>,
+}
+
+#[serde_with::serde_as]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SwitchboardInstruction {
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub program_id: Pubkey,
+    pub keys: Vec<SwitchboardAccountMeta>,
+    /// Base64 encoded instruction data
+    pub data: String,
+}
+
+#[serde_with::serde_as]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SwitchboardAccountMeta {
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl TryFrom<SwitchboardInstruction> for Instruction {
+    type Error = anyhow::Error;
+
+    fn try_from(ix: SwitchboardInstruction) -> Result<Self> {
+        let accounts = ix
+            .keys
+            .into_iter()
+            .map(|meta| AccountMeta {
+                pubkey: meta.pubkey,
+                is_signer: meta.is_signer,
+                is_writable: meta.is_writable,
+            })
+            .collect();
+        let data = base64::decode(ix.data)
+            .map_err(|err| anyhow!("decode switchboard instruction data: {}", err))?;
+
+        Ok(Instruction {
+            program_id: ix.program_id,
+            accounts,
+            data,
+        })
+    }
+}
+
 pub struct PriceProxyClient {
     pub rpc: RpcClient,
     pub authority: Keypair,
@@ -235,6 +374,42 @@ impl PriceProxyClient {
         Ok(PriceFeedSignatureView::success(price_feed, signature))
     }
 
+    /// Creates Price-feed at PDA derived from `(symbol, quote_symbol, authority)` or from
+    /// `(seed, authority)` when `seed` is set.
+    pub async fn create_price_feed_pda(
+        &self,
+        params: PriceFeedParams,
+        source_address: Pubkey,
+        transform_source_address: Pubkey,
+        seed: Option<Vec<u8>>,
+    ) -> Result<PriceFeedSignatureView> {
+        let authority = self.authority.pubkey();
+
+        let (price_feed, _) = match &seed {
+            Some(seed) if seed.len() > PRICE_FEED_SEED_MAX_LEN => {
+                bail!("seed must be up to {} bytes", PRICE_FEED_SEED_MAX_LEN)
+            }
+            Some(seed) => find_price_feed_address_with_seed(seed, &authority),
+            None => {
+                find_price_feed_address_by_symbol(&params.symbol, params.quote_symbol, &authority)
+            }
+        };
+
+        let ixs = vec![CreatePriceFeedPda {
+            price_feed,
+            authority,
+            source_address,
+            transform_source_address,
+            params,
+            seed,
+        }
+        .into_instruction()];
+
+        let signature = self.send_transaction_by(ixs, &[&self.authority]).await?;
+
+        Ok(PriceFeedSignatureView::success(price_feed, signature))
+    }
+
     pub async fn write_encoded_vaa_ix(
         &self,
         message: &String,
@@ -506,6 +681,53 @@ impl PriceProxyClient {
         vec![update_price_ix]
     }
 
+    /// `UpdatePrice` ix for Composite feed. `leg_addresses` are source accounts of
+    /// `composite_feed` legs in order of legs.
+    pub async fn update_composite_price_ix(
+        &self,
+        price_feed: Pubkey,
+        composite_feed: Pubkey,
+        leg_addresses: &[Pubkey],
+        maximum_age_sec: u64,
+    ) -> Vec<Instruction> {
+        let mut update_price_ix = UpdatePrice {
+            price_feed,
+            source_address: composite_feed,
+            transform_source_address: composite_feed,
+            maximum_age_sec,
+        }
+        .into_instruction();
+        update_price_ix.accounts.extend(
+            leg_addresses
+                .iter()
+                .map(|address| AccountMeta::new_readonly(*address, false)),
+        );
+        vec![update_price_ix]
+    }
+
+    /// `UpdatePrice` ix with `fallback_address` - account of the feed's fallback source. It is
+    /// used only when the primary source is stale.
+    pub async fn update_fallback_price_ix(
+        &self,
+        price_feed: Pubkey,
+        source_address: Pubkey,
+        transform_source_address: Pubkey,
+        fallback_address: Pubkey,
+        maximum_age_sec: u64,
+    ) -> Vec<Instruction> {
+        let mut update_price_ix = UpdatePrice {
+            price_feed,
+            source_address,
+            transform_source_address,
+            maximum_age_sec,
+        }
+        .into_instruction();
+        update_price_ix
+            .accounts
+            .push(AccountMeta::new_readonly(fallback_address, false));
+        vec![update_price_ix]
+    }
+
     pub async fn update_price(
         &self,
         price_feed: Pubkey,
@@ -513,14 +735,17 @@ impl PriceProxyClient {
         transform_source_address: Pubkey,
         maximum_age_sec: u64,
     ) -> Result<SignatureView> {
-        let ixs = self
-            .update_price_ix(
+        let price_feed_view = self.price_feed(&price_feed).await?;
+        let ixs = with_price_history(
+            self.update_price_ix(
                 price_feed,
                 source_address,
                 transform_source_address,
                 maximum_age_sec,
             )
-            .await;
+            .await,
+            &price_feed_view.price_feed,
+        );
 
         let signature = self.send_transaction_by(ixs, &[&self.authority]).await?;
 
@@ -540,6 +765,19 @@ impl PriceProxyClient {
         transform_source: Option<PriceFeedSource>,
         source_address: Option<Pubkey>,
         transform_source_address: Option<Pubkey>,
+        max_confidence_bps: Option<u16>,
+        transform_op: Option<TransformOp>,
+        fallback: Option<(PriceFeedSource, Pubkey)>,
+        reference: Option<(PriceFeedSource, Pubkey, u16)>,
+        max_deviation_bps: Option<u16>,
+        deviation_window_sec: Option<u32>,
+        min_price: Option<Decimal>,
+        max_price: Option<Decimal>,
+        ema_half_life_sec: Option<u32>,
+        writer_quorum: Option<u8>,
+        submission_window_sec: Option<u32>,
+        max_future_sec: Option<u32>,
+        fallback_after_sec: Option<u32>,
     ) -> Result<SignatureView> {
         let authority = self.authority.pubkey();
 
@@ -554,6 +792,22 @@ impl PriceProxyClient {
             logo_url: price_feed_acc.logo_url,
             source: price_feed_acc.source(),
             transform_source: price_feed_acc.transform_source(),
+            max_confidence_bps: price_feed_acc.max_confidence_bps,
+            transform_op: price_feed_acc.transform_op(),
+            fallback_source: price_feed_acc.fallback_source(),
+            fallback_source_address: price_feed_acc.fallback_source_address,
+            fallback_after_sec: price_feed_acc.fallback_after_sec,
+            reference_source: price_feed_acc.reference_source(),
+            reference_source_address: price_feed_acc.reference_source_address,
+            max_divergence_bps: price_feed_acc.max_divergence_bps,
+            max_deviation_bps: price_feed_acc.max_deviation_bps,
+            deviation_window_sec: price_feed_acc.deviation_window_sec,
+            min_price: price_feed_acc.try_min_price().expect("min price"),
+            max_price: price_feed_acc.try_max_price().expect("max price"),
+            ema_half_life_sec: price_feed_acc.ema_half_life_sec,
+            writer_quorum: price_feed_acc.writer_quorum,
+            submission_window_sec: price_feed_acc.submission_window_sec,
+            max_future_sec: price_feed_acc.max_future_sec,
         };
 
         if let Some(symbol) = symbol {
@@ -584,6 +838,63 @@ impl PriceProxyClient {
             params.transform_source = transform_source;
         }
 
+        if let Some(max_confidence_bps) = max_confidence_bps {
+            params.max_confidence_bps = max_confidence_bps;
+        }
+
+        if let Some(transform_op) = transform_op {
+            params.transform_op = transform_op;
+        }
+
+        if let Some((fallback_source, fallback_source_address)) = fallback {
+            params = params.with_fallback(fallback_source, fallback_source_address);
+        }
+
+        if let Some((reference_source, reference_source_address, max_divergence_bps)) = reference {
+            params = params.with_reference(
+                reference_source,
+                reference_source_address,
+                max_divergence_bps,
+            );
+        }
+
+        if let Some(max_deviation_bps) = max_deviation_bps {
+            params.max_deviation_bps = max_deviation_bps;
+        }
+
+        if let Some(deviation_window_sec) = deviation_window_sec {
+            params.deviation_window_sec = deviation_window_sec;
+        }
+
+        // Zero removes the bound
+        if let Some(min_price) = min_price {
+            params.min_price = (min_price != Decimal::ZERO).then_some(min_price);
+        }
+
+        if let Some(max_price) = max_price {
+            params.max_price = (max_price != Decimal::ZERO).then_some(max_price);
+        }
+
+        if let Some(ema_half_life_sec) = ema_half_life_sec {
+            params.ema_half_life_sec = ema_half_life_sec;
+        }
+
+        if let Some(writer_quorum) = writer_quorum {
+            params.writer_quorum = writer_quorum;
+        }
+
+        if let Some(submission_window_sec) = submission_window_sec {
+            params.submission_window_sec = submission_window_sec;
+        }
+
+        if let Some(max_future_sec) = max_future_sec {
+            params.max_future_sec = max_future_sec;
+        }
+
+        if let Some(fallback_after_sec) = fallback_after_sec {
+            params.fallback_after_sec = fallback_after_sec;
+        }
+
         let mut ixs = vec![];
 
         ixs.push(
@@ -626,6 +937,257 @@ impl PriceProxyClient {
         Ok(signature.into())
     }
 
+    /// Grows `price_feed` created by previous program version to the current size.
+    pub async fn migrate_price_feed(&self, price_feed: Pubkey) -> Result<SignatureView> {
+        let authority = self.authority.pubkey();
+
+        let ixs = vec![MigratePriceFeed {
+            price_feed,
+            authority,
+        }
+        .into_instruction()];
+
+        let signature = self.send_transaction_by(ixs, &[&self.authority]).await?;
+
+        Ok(signature.into())
+    }
+
+    /// Time-weighted average price of `price_feed` over at least `window_sec`. `GetTwap` is
+    /// simulated, so nothing is paid.
+    pub async fn twap(&self, price_feed: Pubkey, window_sec: u32) -> Result<TwapView> {
+        let ix = GetTwap {
+            price_feed,
+            window_sec,
+        }
+        .into_instruction();
+
+        let blockhash = self.rpc.get_latest_blockhash().await?;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.authority.pubkey()),
+            &[&self.authority],
+            blockhash,
+        );
+        let response = self.rpc.simulate_transaction(&tx).await?;
+        let result = response.value;
+        if let Some(err) = result.err {
+            bail!("GetTwap failed: {}, logs: {:?}", err, result.logs);
+        }
+
+        let return_data = result
+            .return_data
+            .ok_or_else(|| anyhow!("GetTwap returned no data"))?;
+        let data = base64::decode(return_data.data.0)
+            .map_err(|err| anyhow!("decoding GetTwap return data: {}", err))?;
+        let twap = solana_program::borsh1::try_from_slice_unchecked::<Decimal>(&data)
+            .map_err(|err| anyhow!("deserializing TWAP: {}", err))?;
+
+        Ok(TwapView {
+            key: price_feed,
+            window_sec,
+            twap,
+            slot: response.context.slot,
+        })
+    }
+
+    /// Allows the next price update of `price_feed` with price within max deviation of `price`
+    /// to bypass the deviation circuit breaker.
+    pub async fn override_circuit_breaker(
+        &self,
+        price_feed: Pubkey,
+        price: Decimal,
+    ) -> Result<SignatureView> {
+        let authority = self.authority.pubkey();
+
+        let ixs = vec![OverrideCircuitBreaker {
+            price_feed,
+            authority,
+            price,
+        }
+        .into_instruction()];
+
+        let signature = self.send_transaction_by(ixs, &[&self.authority]).await?;
+
+        Ok(signature.into())
+    }
+
+    /// Proposes `new_authority` as update authority of `price_feed`. Proposing the current
+    /// authority cancels the pending transfer.
+    pub async fn propose_authority(
+        &self,
+        price_feed: Pubkey,
+        new_authority: Pubkey,
+    ) -> Result<SignatureView> {
+        let authority = self.authority.pubkey();
+
+        let ixs = vec![ProposeAuthority {
+            price_feed,
+            authority,
+            new_authority,
+        }
+        .into_instruction()];
+
+        let signature = self.send_transaction_by(ixs, &[&self.authority]).await?;
+
+        Ok(signature.into())
+    }
+
+    /// Accepts update authority of `price_feed` proposed to the client's authority.
+    pub async fn accept_authority(&self, price_feed: Pubkey) -> Result<SignatureView> {
+        let new_authority = self.authority.pubkey();
+
+        let ixs = vec![AcceptAuthority {
+            price_feed,
+            new_authority,
+        }
+        .into_instruction()];
+
+        let signature = self.send_transaction_by(ixs, &[&self.authority]).await?;
+
+        Ok(signature.into())
+    }
+
+    /// Authorizes `writer` to write price into off-chain `price_feed`.
+    pub async fn add_writer(&self, price_feed: Pubkey, writer: Pubkey) -> Result<SignatureView> {
+        let authority = self.authority.pubkey();
+
+        let ixs = vec![AddWriter {
+            price_feed,
+            authority,
+            writer,
+        }
+        .into_instruction()];
+
+        let signature = self.send_transaction_by(ixs, &[&self.authority]).await?;
+
+        Ok(signature.into())
+    }
+
+    /// Revokes `writer` of off-chain `price_feed`.
+    pub async fn remove_writer(&self, price_feed: Pubkey, writer: Pubkey) -> Result<SignatureView> {
+        let authority = self.authority.pubkey();
+
+        let ixs = vec![RemoveWriter {
+            price_feed,
+            authority,
+            writer,
+        }
+        .into_instruction()];
+
+        let signature = self.send_transaction_by(ixs, &[&self.authority]).await?;
+
+        Ok(signature.into())
+    }
+
+    /// Creates price history of `price_feed`. From now on every price update of the feed is
+    /// recorded there.
+    pub async fn create_price_history(
+        &self,
+        price_feed: Pubkey,
+    ) -> Result<PriceHistorySignatureView> {
+        let authority = self.authority.pubkey();
+
+        let price_history_keypair = Keypair::new();
+        let price_history = price_history_keypair.pubkey();
+
+        let ixs = vec![CreatePriceHistory {
+            price_history,
+            price_feed,
+            authority,
+        }
+        .into_instruction()];
+
+        let signature = self
+            .send_transaction_by(ixs, &[&self.authority, &price_history_keypair])
+            .await?;
+
+        Ok(PriceHistorySignatureView {
+            price_history,
+            signature,
+        })
+    }
+
+    pub async fn delete_price_history(&self, price_history: Pubkey) -> Result<SignatureView> {
+        let authority = self.authority.pubkey();
+        let price_history_view = self.price_history(&price_history).await?;
+
+        let ixs = vec![DeletePriceHistory {
+            price_history,
+            price_feed: price_history_view.price_feed,
+            authority,
+        }
+        .into_instruction()];
+
+        let signature = self.send_transaction_by(ixs, &[&self.authority]).await?;
+
+        Ok(signature.into())
+    }
+
+    /// `quorum` - minimum number of fresh legs for Median feed, 0 - all legs. Not used by
+    /// Composite feed.
+    pub async fn create_composite_feed(
+        &self,
+        legs: Vec<CompositeLegParams>,
+        quorum: u8,
+    ) -> Result<CompositeFeedSignatureView> {
+        let authority = self.authority.pubkey();
+
+        let composite_feed_keypair = Keypair::new();
+        let composite_feed = composite_feed_keypair.pubkey();
+
+        let ixs = vec![CreateCompositeFeed {
+            composite_feed,
+            authority,
+            legs,
+            quorum,
+        }
+        .into_instruction()];
+
+        let signature = self
+            .send_transaction_by(ixs, &[&self.authority, &composite_feed_keypair])
+            .await?;
+
+        Ok(CompositeFeedSignatureView {
+            composite_feed,
+            signature,
+        })
+    }
+
+    pub async fn alter_composite_feed(
+        &self,
+        composite_feed: Pubkey,
+        legs: Vec<CompositeLegParams>,
+        quorum: u8,
+    ) -> Result<SignatureView> {
+        let authority = self.authority.pubkey();
+
+        let ixs = vec![AlterCompositeFeed {
+            composite_feed,
+            authority,
+            legs,
+            quorum,
+        }
+        .into_instruction()];
+
+        let signature = self.send_transaction_by(ixs, &[&self.authority]).await?;
+
+        Ok(signature.into())
+    }
+
+    pub async fn delete_composite_feed(&self, composite_feed: Pubkey) -> Result<SignatureView> {
+        let authority = self.authority.pubkey();
+
+        let ixs = vec![DeleteCompositeFeed {
+            composite_feed,
+            authority,
+        }
+        .into_instruction()];
+
+        let signature = self.send_transaction_by(ixs, &[&self.authority]).await?;
+
+        Ok(signature.into())
+    }
+
     pub async fn close_price_update_ix(&self, price_update: Pubkey) -> Vec<Instruction> {
         let authority = self.authority.pubkey();
         let reclaim_rent_ix = ReclaimRent {
@@ -659,13 +1221,51 @@ impl PriceProxyClient {
             .query(&query)
             .send()
             .await
-            .expect("get pyth prices")
+            .map_err(|err| anyhow!("Getting Pyth prices: {}", err))?
             .json::<Vec<String>>()
             .await
-            .expect("parse json schema");
+            .map_err(|err| anyhow!("Parsing Pyth prices: {}", err))?;
         Ok(result)
     }
 
+    /// Fetches instructions which submit fresh oracle responses to Switchboard On-Demand `pull_feed`.
+    /// They must land in the same TX before `UpdatePrice`.
+    pub async fn get_switchboard_pull_ixs(
+        &self,
+        pull_feed: &Pubkey,
+        switchboard_api_url: Option<String>,
+    ) -> Result<Vec<Instruction>> {
+        let client = reqwest::Client::new();
+        let url = format!(
+            "{}/{}",
+            switchboard_api_url.unwrap_or(SWITCHBOARD_CROSSBAR_API_URL.to_string()),
+            pull_feed
+        );
+
+        let updates = client
+            .get(url)
+            .send()
+            .await
+            .map_err(|err| anyhow!("get switchboard updates: {}", err))?
+            .json::<Vec<SwitchboardPullUpdate>>()
+            .await
+            .map_err(|err| anyhow!("parse switchboard updates: {}", err))?;
+
+        let update = updates
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow!("no switchboard update for {}", pull_feed))?;
+        if !update.success {
+            bail!("switchboard update for {} was not successful", pull_feed);
+        }
+
+        update
+            .pull_ixs
+            .into_iter()
+            .map(Instruction::try_from)
+            .collect()
+    }
+
     pub async fn price_feed(&self, key: &Pubkey) -> Result<PriceFeedView> {
         self.get_pod_account::<PriceFeed>(key)
             .await
@@ -673,6 +1273,20 @@ impl PriceProxyClient {
             .map(Into::into)
     }
 
+    pub async fn composite_feed(&self, key: &Pubkey) -> Result<CompositeFeedView> {
+        self.get_pod_account::<CompositeFeed>(key)
+            .await
+            .map(|(composite_feed, slot)| (*key, composite_feed, slot))
+            .map(Into::into)
+    }
+
+    pub async fn price_history(&self, key: &Pubkey) -> Result<PriceHistoryView> {
+        self.get_pod_account::<PriceHistory>(key)
+            .await
+            .map(|(price_history, slot)| (*key, price_history, slot))
+            .map(Into::into)
+    }
+
     pub async fn price_feeds(&self) -> Result<PriceFeedsView> {
         load_price_feeds(&self.rpc).await.map(Into::into)
     }
@@ -688,15 +1302,27 @@ impl PriceProxyClient {
         price_feed: Pubkey,
         price: impl Into<Decimal>,
         price_timestamp: i64,
+        confidence: Option<Decimal>,
     ) -> Vec<Instruction> {
         let authority = self.authority.pubkey();
-        vec![WritePrice {
-            price_feed,
-            authority,
-            price: price.into(),
-            price_timestamp,
-        }
-        .into_instruction()]
+        let ix = match confidence {
+            Some(confidence) => WritePriceWithConfidence {
+                price_feed,
+                authority,
+                price: price.into(),
+                price_timestamp,
+                confidence,
+            }
+            .into_instruction(),
+            None => WritePrice {
+                price_feed,
+                authority,
+                price: price.into(),
+                price_timestamp,
+            }
+            .into_instruction(),
+        };
+        vec![ix]
     }
 
     pub async fn write_price(
@@ -704,10 +1330,32 @@ impl PriceProxyClient {
         price_feed: Pubkey,
         price: impl Into<Decimal>,
         price_timestamp: i64,
+        confidence: Option<Decimal>,
     ) -> Result<SignatureView> {
-        let ixs = self
-            .write_price_ix(price_feed, price, price_timestamp)
-            .await;
+        let price_feed_view = self.price_feed(&price_feed).await?;
+        let ixs = with_price_history(
+            self.write_price_ix(price_feed, price, price_timestamp, confidence)
+                .await,
+            &price_feed_view.price_feed,
+        );
+
+        let signature = self.send_transaction_by(ixs, &[&self.authority]).await?;
+
+        Ok(signature.into())
+    }
+
+    /// Relays price signed by `writer` with `sign_price`. The client's authority only pays fees.
+    pub async fn write_signed_price(
+        &self,
+        writer: &Pubkey,
+        signature: &Signature,
+        message: &SignedPriceMessage,
+    ) -> Result<SignatureView> {
+        let price_feed_view = self.price_feed(&message.price_feed).await?;
+        let ixs = with_price_history(
+            write_signed_price_ixs(writer, signature, message),
+            &price_feed_view.price_feed,
+        );
 
         let signature = self.send_transaction_by(ixs, &[&self.authority]).await?;
 
@@ -740,6 +1388,7 @@ impl PriceProxyClient {
                 key,
                 feed.try_price().unwrap(),
                 chrono::Utc::now().timestamp(),
+                Some(feed.try_confidence().unwrap()),
             )
             .map_ok_or_else(
                 move |err| PriceFeedSignatureView::failure(key, err),
@@ -758,11 +1407,255 @@ impl PriceProxyClient {
             .expect("Read logs below to see the contract version");
     }
 
+    /// Refreshes SuperLendy Reserve used as price source so that it holds actual LP token price.
+    /// The algorithm:
+    /// 1. Refresh Reserve0 which is the source of LP tokens (used as liquidity in Reserve1 -
+    ///    reserve of our final interest), also refresh its marke price feed before refreshing the Reserve
+    /// 2. Caller then invokes PriceProxy::UpdatePrice for the price feed used in Reserve1. This action will use
+    ///    LP token price, calculated by SuperLendy on step 1.
+    async fn refresh_super_lendy_reserve(
+        &self,
+        reserve: &Pubkey,
+        maximum_age_sec: u64,
+    ) -> Result<()> {
+        let reserve_data = self
+            .rpc
+            .get_account_data(reserve)
+            .await
+            .map_err(|err| anyhow!("getting Reserve account: {}", err))?;
+        let unpacked_reserve = Reserve::try_from_bytes(&reserve_data)
+            .map_err(|err| anyhow!("unpacking Reserve: {}", err))?;
+
+        let second_level_price_feed = self
+            .price_feed(&unpacked_reserve.config.market_price_feed)
+            .await?;
+
+        if second_level_price_feed.price_feed.source() != PriceFeedSource::OffChain {
+            let _ = self
+                .update_price(
+                    unpacked_reserve.config.market_price_feed,
+                    second_level_price_feed.price_feed.source_address,
+                    second_level_price_feed.price_feed.transform_source_address,
+                    maximum_age_sec,
+                )
+                .await?;
+            debug!(
+                "Updated second level price feed {}",
+                unpacked_reserve.config.market_price_feed
+            );
+        }
+
+        let ixs = vec![RefreshReserve {
+            reserve: *reserve,
+            market_price_feed: unpacked_reserve.config.market_price_feed,
+            irm: unpacked_reserve.config.irm,
+        }
+        .into_instruction()];
+
+        let _ = self
+            .send_transaction_by(ixs, &[&self.authority])
+            .await
+            .map_err(|err| anyhow!("Sending TX: {}", err))?;
+        debug!("Updated second level reserve {}", reserve);
+
+        Ok(())
+    }
+
+    /// Posts Pyth price update of `feed_id` onto Solana. Returns created PriceUpdate account.
+    async fn post_pyth_price_update(
+        &self,
+        feed_id: &Pubkey,
+        verification_level: WormholeVerificationLevel,
+        pyth_api_url: Option<String>,
+        signatures: &mut Vec<SignatureView>,
+    ) -> Result<Pubkey> {
+        // 0. Convert feed pubkey to hex
+        let hex: String = feed_id.encode_hex();
+
+        // 1. Get a Hermes update from Hermes stable
+        let message = self.get_message_by_hex(&hex, pyth_api_url).await?;
+
+        // 2. Post a Pyth price update onto Solana
+        let (price_update, local_signatures) = self
+            .post_update(
+                message
+                    .first()
+                    .ok_or_else(|| anyhow!("no Pyth price message for {}", feed_id))?,
+                verification_level,
+            )
+            .await?;
+        signatures.extend(local_signatures.into_iter().map(SignatureView::from));
+
+        Ok(price_update)
+    }
+
+    /// Makes `address` of `source` ready to be read by `UpdatePrice`. Returns account to pass to
+    /// the ix. Pyth price update is posted and pushed to `price_updates` to be closed after the
+    /// update, Switchboard On-Demand pull ixs are pushed to `ixs` to be sent along with the update.
+    #[allow(clippy::too_many_arguments)]
+    async fn prepare_source_account(
+        &self,
+        source: PriceFeedSource,
+        address: &Pubkey,
+        verification_level: WormholeVerificationLevel,
+        maximum_age_sec: u64,
+        pyth_api_url: Option<String>,
+        switchboard_api_url: Option<String>,
+        ixs: &mut Vec<Instruction>,
+        price_updates: &mut Vec<Pubkey>,
+        signatures: &mut Vec<SignatureView>,
+    ) -> Result<Pubkey> {
+        let source_address = match source {
+            PriceFeedSource::Pyth => {
+                let price_update = self
+                    .post_pyth_price_update(address, verification_level, pyth_api_url, signatures)
+                    .await?;
+                price_updates.push(price_update);
+                price_update
+            }
+            PriceFeedSource::SwitchboardOnDemand => {
+                ixs.extend(
+                    self.get_switchboard_pull_ixs(address, switchboard_api_url)
+                        .await?,
+                );
+                *address
+            }
+            PriceFeedSource::SuperLendy => {
+                self.refresh_super_lendy_reserve(address, maximum_age_sec)
+                    .await?;
+                *address
+            }
+            // OffChain sources refer to PriceFeeds updated by their writers
+            _ => *address,
+        };
+
+        Ok(source_address)
+    }
+
+    /// Refreshes fallback source of Direct or Transform price feed and updates the feed from it.
+    /// Primary source account is passed as is, so `UpdatePrice` fails over to the fallback. For
+    /// Pyth primary it is the feed id, which `UpdatePrice` takes as the missing price update.
+    async fn holistic_update_fallback_price(
+        &self,
+        price_feed_key: &Pubkey,
+        price_feed: &PriceFeed,
+        transform_source_address: Pubkey,
+        reference: &Option<(Pubkey, Vec<Instruction>)>,
+        maximum_age_sec: u64,
+        pyth_api_url: Option<String>,
+        switchboard_api_url: Option<String>,
+    ) -> Result<Vec<SignatureView>> {
+        let mut signatures = Vec::new();
+        let mut ixs = Vec::new();
+        let mut price_updates = Vec::new();
+        let fallback_address = self
+            .prepare_source_account(
+                price_feed.fallback_source(),
+                &price_feed.fallback_source_address,
+                price_feed.verification_level(),
+                maximum_age_sec,
+                pyth_api_url,
+                switchboard_api_url,
+                &mut ixs,
+                &mut price_updates,
+                &mut signatures,
+            )
+            .await?;
+
+        ixs.extend(with_price_history(
+            with_reference(
+                self.update_fallback_price_ix(
+                    *price_feed_key,
+                    price_feed.source_address,
+                    transform_source_address,
+                    fallback_address,
+                    maximum_age_sec,
+                )
+                .await,
+                reference,
+            ),
+            price_feed,
+        ));
+        let signature = self.send_transaction_by(ixs, &[&self.authority]).await?;
+        signatures.push(signature.into());
+
+        for price_update in price_updates {
+            // Close a price update account, recovering the rent.
+            let signature = self.close_price_update(price_update).await?;
+            signatures.push(signature);
+        }
+
+        Ok(signatures)
+    }
+
+    /// Refreshes every leg of Composite or Median price feed and updates the feed.
+    async fn holistic_update_composite_price(
+        &self,
+        price_feed_key: &Pubkey,
+        price_feed: &PriceFeed,
+        reference: &Option<(Pubkey, Vec<Instruction>)>,
+        maximum_age_sec: u64,
+        pyth_api_url: Option<String>,
+        switchboard_api_url: Option<String>,
+    ) -> Result<Vec<SignatureView>> {
+        let mut signatures = Vec::new();
+        let composite_feed = self.composite_feed(&price_feed.source_address).await?;
+
+        let mut ixs = Vec::new();
+        let mut leg_addresses = Vec::new();
+        let mut price_updates = Vec::new();
+        for leg in composite_feed.composite_feed.legs() {
+            let leg_address = self
+                .prepare_source_account(
+                    leg.source(),
+                    &leg.address,
+                    price_feed.verification_level(),
+                    maximum_age_sec,
+                    pyth_api_url.clone(),
+                    switchboard_api_url.clone(),
+                    &mut ixs,
+                    &mut price_updates,
+                    &mut signatures,
+                )
+                .await?;
+            leg_addresses.push(leg_address);
+        }
+
+        ixs.extend(with_price_history(
+            with_reference(
+                self.update_composite_price_ix(
+                    *price_feed_key,
+                    price_feed.source_address,
+                    &leg_addresses,
+                    maximum_age_sec,
+                )
+                .await,
+                reference,
+            ),
+            price_feed,
+        ));
+        let signature = self.send_transaction_by(ixs, &[&self.authority]).await?;
+        signatures.push(signature.into());
+
+        for price_update in price_updates {
+            // Close a price update account, recovering the rent.
+            let signature = self.close_price_update(price_update).await?;
+            signatures.push(signature);
+        }
+
+        Ok(signatures)
+    }
+
     /// Constructs and send TXes to update given `price_feed_key`.
     /// This function does NOT process off-chain type feeds.
     /// `maximum_age_sec` maximum price age in seconds which is acceptable to put in to PriceFeed. If
     /// actual price age is greater than TX will fail and no update will happen.
     /// `pyth_api_url` - caller provided override for Pyth off chain API.
+    /// `switchboard_api_url` - caller provided override for Switchboard On-Demand off chain API.
+    /// Composite and Median feeds get every leg refreshed before the update.
+    /// When update from the primary source fails and the feed has fallback source, the fallback
+    /// gets refreshed and the feed is updated from it.
+    /// Reference source, when the feed has one, is refreshed and passed along with every update.
     ///
     /// TODO: Only Transform feeds with Transform source = Pyth are supported!
     pub async fn holistic_update_price(
@@ -770,30 +1663,66 @@ impl PriceProxyClient {
         price_feed_key: &Pubkey,
         maximum_age_sec: u64,
         pyth_api_url: Option<String>,
+        switchboard_api_url: Option<String>,
     ) -> Result<Vec<SignatureView>> {
-        let mut signatures = Vec::new();
         let price_feed = self.price_feed(price_feed_key).await?;
+
+        let mut signatures = Vec::new();
+        let mut reference_price_updates = Vec::new();
+        let reference = if price_feed.price_feed.has_reference() {
+            let mut reference_ixs = Vec::new();
+            let reference_address = self
+                .prepare_source_account(
+                    price_feed.price_feed.reference_source(),
+                    &price_feed.price_feed.reference_source_address,
+                    price_feed.price_feed.verification_level(),
+                    maximum_age_sec,
+                    pyth_api_url.clone(),
+                    switchboard_api_url.clone(),
+                    &mut reference_ixs,
+                    &mut reference_price_updates,
+                    &mut signatures,
+                )
+                .await?;
+            Some((reference_address, reference_ixs))
+        } else {
+            None
+        };
+
+        if matches!(
+            price_feed.price_feed.feed_type(),
+            FeedType::Composite | FeedType::Median
+        ) {
+            signatures.extend(
+                self.holistic_update_composite_price(
+                    price_feed_key,
+                    &price_feed.price_feed,
+                    &reference,
+                    maximum_age_sec,
+                    pyth_api_url,
+                    switchboard_api_url,
+                )
+                .await?,
+            );
+            for price_update in reference_price_updates {
+                // Close a price update account, recovering the rent.
+                let signature = self.close_price_update(price_update).await?;
+                signatures.push(signature);
+            }
+            return Ok(signatures);
+        }
+
         let transform_price_update = if price_feed.price_feed.feed_type() == FeedType::Transform
             && price_feed.price_feed.transform_source() == PriceFeedSource::Pyth
         {
-            // 0. Convert feed pubkey to hex
-            let hex: String = price_feed.price_feed.transform_source_address.encode_hex();
-
-            // 1. Get a Hermes update from Hermes stable
-            let message = self.get_message_by_hex(&hex, pyth_api_url.clone()).await?;
-
-            // 2. Post a Pyth price update onto Solana
-            let (price_update, local_signatures) = self
-                .post_update(
-                    message.first().unwrap(),
+            let price_update = self
+                .post_pyth_price_update(
+                    &price_feed.price_feed.transform_source_address,
                     price_feed.price_feed.verification_level(),
+                    pyth_api_url.clone(),
+                    &mut signatures,
                 )
                 .await?;
-            let sig_views: Vec<SignatureView> = local_signatures
-                .into_iter()
-                .map(SignatureView::from)
-                .collect();
-            signatures.extend(sig_views);
 
             Some(price_update)
         } else {
@@ -808,113 +1737,133 @@ impl PriceProxyClient {
         };
 
         let source = price_feed.price_feed.source();
+        let fallback_pyth_api_url = pyth_api_url.clone();
+        let fallback_switchboard_api_url = switchboard_api_url.clone();
+
+        let primary_result: Result<()> = async {
+            match source {
+                PriceFeedSource::Pyth => {
+                    // 0-2. Post a Pyth price update onto Solana
+                    let price_update = self
+                        .post_pyth_price_update(
+                            &price_feed.price_feed.source_address,
+                            price_feed.price_feed.verification_level(),
+                            pyth_api_url,
+                            &mut signatures,
+                        )
+                        .await?;
 
-        match source {
-            PriceFeedSource::Pyth => {
-                // 0. Convert feed pubkey to hex
-                let hex: String = price_feed.price_feed.source_address.encode_hex();
-
-                // 1. Get a Hermes update from Hermes stable
-                let message = self.get_message_by_hex(&hex, pyth_api_url).await?;
-
-                // 2. Post a Pyth price update onto Solana
-                let (price_update, local_signatures) = self
-                    .post_update(
-                        message.first().unwrap(),
-                        price_feed.price_feed.verification_level(),
-                    )
-                    .await?;
-
-                let sig_views: Vec<SignatureView> = local_signatures
-                    .into_iter()
-                    .map(SignatureView::from)
-                    .collect();
-                signatures.extend(sig_views);
-
-                // 3. Update Price-feed
-                let signature = self
-                    .update_price(
-                        *price_feed_key,
-                        price_update,
-                        transform_source_address,
-                        maximum_age_sec,
-                    )
-                    .await?;
-                signatures.push(signature);
+                    // 3. Update Price-feed
+                    let ixs = with_price_history(
+                        with_reference(
+                            self.update_price_ix(
+                                *price_feed_key,
+                                price_update,
+                                transform_source_address,
+                                maximum_age_sec,
+                            )
+                            .await,
+                            &reference,
+                        ),
+                        &price_feed.price_feed,
+                    );
+                    let signature = self.send_transaction_by(ixs, &[&self.authority]).await?;
+                    signatures.push(signature.into());
 
-                // 4. Close a price update account, recovering the rent.
-                let signature = self.close_price_update(price_update).await?;
+                    // 4. Close a price update account, recovering the rent.
+                    let signature = self.close_price_update(price_update).await?;
 
-                signatures.push(signature);
-            }
+                    signatures.push(signature);
+                }
 
-            PriceFeedSource::SuperLendy
-            | PriceFeedSource::Switchboard
-            | PriceFeedSource::StakePool => {
-                if source == PriceFeedSource::SuperLendy {
-                    // The algorithm:
-                    // 1. Refresh Reserve0 which is the source of LP tokens (used as liquidity in Reserve1 -
-                    //    reserve of our final interest), also refresh its marke price feed before refreshing the Reserve
-                    // 2. Call PriceProxy::UpdatePrice for the price feed used in Reserve1. This action will use
-                    //    LP token price, calculated by SuperLendy on step 1.
-                    let reserve_data = self
-                        .rpc
-                        .get_account_data(&price_feed.price_feed.source_address)
-                        .await
-                        .map_err(|err| anyhow!("getting Reserve account: {}", err))?;
-                    let unpacked_reserve = Reserve::try_from_bytes(&reserve_data)
-                        .map_err(|err| anyhow!("unpacking Reserve: {}", err))?;
-
-                    let second_level_price_feed = self
-                        .price_feed(&unpacked_reserve.config.market_price_feed)
+                PriceFeedSource::SwitchboardOnDemand => {
+                    // 1. Get signed oracle responses for the pull feed
+                    let mut ixs = self
+                        .get_switchboard_pull_ixs(
+                            &price_feed.price_feed.source_address,
+                            switchboard_api_url,
+                        )
                         .await?;
 
-                    if second_level_price_feed.price_feed.source() != PriceFeedSource::OffChain {
-                        let _ = self
-                            .update_price(
-                                unpacked_reserve.config.market_price_feed,
-                                second_level_price_feed.price_feed.source_address,
-                                second_level_price_feed.price_feed.transform_source_address,
+                    // 2. Submit them and update Price-feed within one TX
+                    ixs.extend(with_price_history(
+                        with_reference(
+                            self.update_price_ix(
+                                *price_feed_key,
+                                price_feed.price_feed.source_address,
+                                transform_source_address,
                                 maximum_age_sec,
                             )
-                            .await?;
-                        debug!(
-                            "Updated second level price feed {}",
-                            unpacked_reserve.config.market_price_feed
-                        );
-                    }
+                            .await,
+                            &reference,
+                        ),
+                        &price_feed.price_feed,
+                    ));
+
+                    let signature = self.send_transaction_by(ixs, &[&self.authority]).await?;
+                    signatures.push(signature.into());
+                }
 
-                    let ixs = vec![RefreshReserve {
-                        reserve: price_feed.price_feed.source_address,
-                        market_price_feed: unpacked_reserve.config.market_price_feed,
-                        irm: unpacked_reserve.config.irm,
+                PriceFeedSource::SuperLendy
+                | PriceFeedSource::Switchboard
+                | PriceFeedSource::StakePool
+                | PriceFeedSource::StakePoolNetOfFee
+                | PriceFeedSource::Marinade
+                | PriceFeedSource::SanctumSpl
+                | PriceFeedSource::Chainlink => {
+                    if source == PriceFeedSource::SuperLendy {
+                        self.refresh_super_lendy_reserve(
+                            &price_feed.price_feed.source_address,
+                            maximum_age_sec,
+                        )
+                        .await?;
                     }
-                    .into_instruction()];
-
-                    let _ = self
-                        .send_transaction_by(ixs, &[&self.authority])
-                        .await
-                        .map_err(|err| anyhow!("Sending TX: {}", err))?;
-                    debug!(
-                        "Updated second level reserve {}",
-                        price_feed.price_feed.source_address
-                    );
-                }
 
-                let signature = self
-                    .update_price(
-                        *price_feed_key,
-                        price_feed.price_feed.source_address,
-                        transform_source_address,
-                        maximum_age_sec,
-                    )
-                    .await?;
+                    let ixs = with_price_history(
+                        with_reference(
+                            self.update_price_ix(
+                                *price_feed_key,
+                                price_feed.price_feed.source_address,
+                                transform_source_address,
+                                maximum_age_sec,
+                            )
+                            .await,
+                            &reference,
+                        ),
+                        &price_feed.price_feed,
+                    );
+                    let signature = self.send_transaction_by(ixs, &[&self.authority]).await?;
 
-                signatures.push(signature);
+                    signatures.push(signature.into());
+                }
+                _ => {
+                    bail!("unsupported source {}", price_feed.price_feed.source());
+                }
             }
-            _ => {
-                bail!("unsupported source {}", price_feed.price_feed.source());
+            Ok(())
+        }
+        .await;
+
+        if let Err(err) = primary_result {
+            if !price_feed.price_feed.has_fallback() {
+                return Err(err);
             }
+            debug!(
+                "Update of {} from primary source failed: {}. Updating from fallback",
+                price_feed_key, err
+            );
+            signatures.extend(
+                self.holistic_update_fallback_price(
+                    price_feed_key,
+                    &price_feed.price_feed,
+                    transform_source_address,
+                    &reference,
+                    maximum_age_sec,
+                    fallback_pyth_api_url,
+                    fallback_switchboard_api_url,
+                )
+                .await?,
+            );
         }
 
         if let Some(transform_price_update) = transform_price_update {
@@ -924,10 +1873,88 @@ impl PriceProxyClient {
             signatures.push(signature);
         }
 
+        for price_update in reference_price_updates {
+            // Close a price update account, recovering the rent.
+            let signature = self.close_price_update(price_update).await?;
+            signatures.push(signature);
+        }
+
         Ok(signatures)
     }
 }
 
+/// Inserts `reference_address` - account of the feed's reference source, into `UpdatePrice` ix
+/// made by one of `update_*price_ix`. It goes first of remaining accounts.
+pub fn insert_reference_account(update_price_ix: &mut Instruction, reference_address: Pubkey) {
+    // price_feed, source_address, transform_source_address
+    const UPDATE_PRICE_ACCOUNTS_LEN: usize = 3;
+    update_price_ix.accounts.insert(
+        UPDATE_PRICE_ACCOUNTS_LEN,
+        AccountMeta::new_readonly(reference_address, false),
+    );
+}
+
+/// Adds prepared `reference` - reference source account and ixs refreshing it, to `ixs` ending
+/// with `UpdatePrice` ix.
+fn with_reference(
+    ixs: Vec<Instruction>,
+    reference: &Option<(Pubkey, Vec<Instruction>)>,
+) -> Vec<Instruction> {
+    let Some((reference_address, reference_ixs)) = reference else {
+        return ixs;
+    };
+
+    let mut result = reference_ixs.clone();
+    result.extend(ixs);
+    if let Some(update_price_ix) = result.last_mut() {
+        insert_reference_account(update_price_ix, *reference_address);
+    }
+    result
+}
+
+/// Signs price `message` by off-chain `writer` of the feed. Anyone can relay the signed price
+/// with `write_signed_price_ixs`.
+pub fn sign_price(writer: &Keypair, message: &SignedPriceMessage) -> Signature {
+    writer.sign_message(&message.to_bytes())
+}
+
+/// Ed25519 program ix verifying `signature` of `message` by `writer` followed by
+/// `WriteSignedPrice` ix.
+pub fn write_signed_price_ixs(
+    writer: &Pubkey,
+    signature: &Signature,
+    message: &SignedPriceMessage,
+) -> Vec<Instruction> {
+    let signature: &[u8; SIGNATURE_BYTES] =
+        signature.as_ref().try_into().expect("ed25519 signature");
+
+    vec![
+        new_ed25519_instruction(writer, signature, &message.to_bytes()),
+        WriteSignedPrice {
+            price_feed: message.price_feed,
+            instructions_sysvar: solana_program::sysvar::instructions::ID,
+        }
+        .into_instruction(),
+    ]
+}
+
+/// Appends `price_history` account to `WritePrice` or `UpdatePrice` ix of the feed having
+/// price history.
+pub fn append_price_history_account(ix: &mut Instruction, price_history: Pubkey) {
+    ix.accounts.push(AccountMeta::new(price_history, false));
+}
+
+/// Appends price history account of `price_feed`, if it has one, to `ixs` ending with
+/// `WritePrice` or `UpdatePrice` ix.
+fn with_price_history(mut ixs: Vec<Instruction>, price_feed: &PriceFeed) -> Vec<Instruction> {
+    if price_feed.has_price_history() {
+        if let Some(ix) = ixs.last_mut() {
+            append_price_history_account(ix, price_feed.price_history);
+        }
+    }
+    ixs
+}
+
 pub async fn get_account_with_retries(rpc: &RpcClient, pubkey: &Pubkey) -> Result<Account> {
     let consecutive_errors = 0;
     loop {
@@ -0,0 +1,108 @@
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::instruction::AccountMeta;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+use price_proxy_client::{
+    PriceProxyClient, SwitchboardAccountMeta, SwitchboardInstruction, SwitchboardPullUpdate,
+};
+
+/// Serves `body` as JSON response to the single incoming request and returns requested path.
+async fn mock_server(body: String) -> (String, tokio::task::JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    let handle = tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut buf = vec![0; 4096];
+        let len = stream.read(&mut buf).await.unwrap();
+        let request = String::from_utf8_lossy(&buf[..len]).to_string();
+        let path = request
+            .split_whitespace()
+            .nth(1)
+            .unwrap_or_default()
+            .to_string();
+
+        let response = format!(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        stream.write_all(response.as_bytes()).await.unwrap();
+        stream.shutdown().await.unwrap();
+        path
+    });
+
+    (url, handle)
+}
+
+fn client() -> PriceProxyClient {
+    PriceProxyClient {
+        rpc: RpcClient::new("http://127.0.0.1:8899".to_string()),
+        authority: Keypair::new(),
+        priority_fee: None,
+        show_spinner: false,
+    }
+}
+
+#[tokio::test]
+async fn switchboard_pull_ixs() {
+    let pull_feed = Pubkey::new_unique();
+    let queue = Pubkey::new_unique();
+    let program_id = Pubkey::new_unique();
+    let response = vec![SwitchboardPullUpdate {
+        success: true,
+        pull_ixs: vec![SwitchboardInstruction {
+            program_id,
+            keys: vec![
+                SwitchboardAccountMeta {
+                    pubkey: pull_feed,
+                    is_signer: false,
+                    is_writable: true,
+                },
+                SwitchboardAccountMeta {
+                    pubkey: queue,
+                    is_signer: false,
+                    is_writable: false,
+                },
+            ],
+            data: base64::encode([1, 2, 3, 4]),
+        }],
+    }];
+    let (url, server) = mock_server(serde_json::to_string(&response).unwrap()).await;
+
+    let ixs = client()
+        .get_switchboard_pull_ixs(&pull_feed, Some(url))
+        .await
+        .expect("get pull ixs");
+
+    assert_eq!(server.await.unwrap(), format!("/{}", pull_feed));
+    assert_eq!(ixs.len(), 1);
+    assert_eq!(ixs[0].program_id, program_id);
+    assert_eq!(
+        ixs[0].accounts,
+        vec![
+            AccountMeta::new(pull_feed, false),
+            AccountMeta::new_readonly(queue, false)
+        ]
+    );
+    assert_eq!(ixs[0].data, vec![1, 2, 3, 4]);
+}
+
+#[tokio::test]
+async fn switchboard_pull_ixs_failure() {
+    let pull_feed = Pubkey::new_unique();
+    let response = vec![SwitchboardPullUpdate {
+        success: false,
+        pull_ixs: vec![],
+    }];
+    let (url, _server) = mock_server(serde_json::to_string(&response).unwrap()).await;
+
+    let result = client()
+        .get_switchboard_pull_ixs(&pull_feed, Some(url))
+        .await;
+
+    assert!(result.is_err());
+}
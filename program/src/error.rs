@@ -9,7 +9,7 @@ use thiserror::Error;
 
 use texture_common::account;
 use texture_common::error;
-use texture_common::math::MathError;
+use texture_common::math::{Decimal, MathError};
 use texture_common::remote::RemoteError;
 
 use crate::state::price_feed::PriceFeedSource;
@@ -115,6 +115,136 @@ pub enum PriceProxyError {
     #[error("invalid price or exponent")]
     InvalidPriceOrExpo,
 
+    #[error("confidence {confidence} is too wide for price {price}, max {max_confidence_bps} bps")]
+    ConfidenceTooWide {
+        price: Decimal,
+        confidence: Decimal,
+        max_confidence_bps: u16,
+    },
+
+    #[error("Pyth price update account can not be deserialized")]
+    PythDeserialize,
+
+    #[error("Pyth price is older than maximum age")]
+    PythPriceTooOld,
+
+    #[error("Pyth price update has insufficient verification level")]
+    PythVerificationLevel,
+
+    #[error("Switchboard aggregator account can not be deserialized")]
+    SwitchboardDeserialize,
+
+    #[error("Switchboard price is stale")]
+    SwitchboardStale,
+
+    #[error("Switchboard aggregator has no valid result")]
+    SwitchboardPrice,
+
+    #[error("SuperLendy reserve LP market price can not be calculated")]
+    SuperLendyLpPrice,
+
+    #[error("Chainlink transmissions account can not be deserialized")]
+    ChainlinkDeserialize,
+
+    #[error("Chainlink feed has no transmitted rounds")]
+    ChainlinkNoRound,
+
+    #[error("Switchboard On-Demand pull feed account can not be deserialized")]
+    SwitchboardOnDemandDeserialize,
+
+    #[error("Switchboard On-Demand result has {samples} samples, at least {min_samples} required")]
+    SwitchboardOnDemandNotEnoughSamples { samples: u8, min_samples: u8 },
+
+    #[error("Marinade state account can not be deserialized")]
+    MarinadeDeserialize,
+
+    #[error("Pyth value {value} scaled by 10^{exponent} overflows")]
+    PythExponentOverflow { value: i128, exponent: i32 },
+
+    #[error("invalid number of composite feed legs: {0}")]
+    InvalidCompositeLegs(usize),
+
+    #[error("source account of composite feed leg {0} is not provided")]
+    CompositeLegAccountMissing(u8),
+
+    #[error("{price_source} source account is owned by unexpected program {owner}")]
+    SourceOwnerMismatch {
+        price_source: PriceFeedSource,
+        owner: Pubkey,
+    },
+
+    #[error("quorum {quorum} is greater than number of legs {legs}")]
+    InvalidQuorum { quorum: u8, legs: u8 },
+
+    #[error("only {fresh} sources are fresh, quorum is {quorum}")]
+    QuorumNotReached { fresh: u8, quorum: u8 },
+
+    #[error("reference source account is not provided")]
+    ReferenceAccountMissing,
+
+    #[error("price {price} diverges from reference price {reference_price} by more than {max_divergence_bps} bps")]
+    PriceDivergence {
+        price: Decimal,
+        reference_price: Decimal,
+        max_divergence_bps: u16,
+    },
+
+    #[error("price {price} deviates from previous price {previous_price} by more than {max_deviation_bps} bps")]
+    PriceDeviationTooLarge {
+        price: Decimal,
+        previous_price: Decimal,
+        max_deviation_bps: u16,
+    },
+
+    #[error("price {0} must be positive")]
+    NonPositivePrice(Decimal),
+
+    #[error("price {price} is below min price {min_price}")]
+    PriceBelowMin { price: Decimal, min_price: Decimal },
+
+    #[error("price {price} is above max price {max_price}")]
+    PriceAboveMax { price: Decimal, max_price: Decimal },
+
+    #[error("invalid price bounds")]
+    InvalidPriceBounds,
+
+    #[error("TWAP checkpoints don't cover window of {0} seconds")]
+    TwapWindowNotCovered(u32),
+
+    #[error("price history account {0} is not provided")]
+    PriceHistoryAccountMissing(Pubkey),
+
+    #[error("{0} is not authorized to write price")]
+    UnauthorizedWriter(Pubkey),
+
+    #[error("writer {0} is already authorized")]
+    WriterAlreadyAuthorized(Pubkey),
+
+    #[error("no free writer slot, {0} max")]
+    TooManyWriters(usize),
+
+    #[error("writer {0} is not found")]
+    WriterNotFound(Pubkey),
+
+    #[error("preceding instruction is not a valid Ed25519 signed price")]
+    InvalidSignedPrice,
+
+    #[error("signed price nonce {nonce} is not greater than last nonce {last_nonce}")]
+    SignedPriceNonceUsed { nonce: u64, last_nonce: u64 },
+
+    #[error("price timestamp {timestamp} is more than {max_future_sec} sec ahead of clock {now}")]
+    TimestampInFuture {
+        timestamp: i64,
+        now: i64,
+        max_future_sec: u32,
+    },
+
+    #[error("signed price generation {generation} is not writer generation {writer_generation}")]
+    SignedPriceGenerationMismatch {
+        generation: u32,
+        writer_generation: u32,
+    },
+
     // NaN
     #[error("system program error: {0}")]
     SystemProgram(#[from] RemoteError<SystemError>),
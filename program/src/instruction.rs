@@ -3,6 +3,7 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use texture_common::macros::Instruction;
 use texture_common::math::Decimal;
 
+use crate::state::composite_feed::CompositeLegParams;
 use crate::state::price_feed::PriceFeedParams;
 
 #[derive(Instruction, BorshSerialize, BorshDeserialize, Debug)]
@@ -34,8 +35,12 @@ pub enum PriceProxyInstruction {
             docs = [
                 "When source is Pyth - pass feed address. Get Price Feed ID in hex from https://pyth.network/developers/price-feed-ids#solana-mainnet-beta. After use cli::get_feed_id_from_hex to convert to pubkey.",
                 "When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet.",
-                "When source is StakePool - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.",
+                "When source is SwitchboardOnDemand - pass pull feed address.",
+                "When source is StakePool or StakePoolNetOfFee - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.",
+                "When source is Marinade - pass Marinade state address.",
+                "When source is SanctumSpl - pass Sanctum SPL stake pool address.",
                 "When source is Superlendy - pass reserve address.",
+                "When source is Chainlink - pass store feed (transmissions) account address.",
                 "When source is OffChain - pass `authority`.",
             ],
         ),
@@ -45,8 +50,12 @@ pub enum PriceProxyInstruction {
             docs = [
                 "When source is Pyth - pass feed address. Get Price Feed ID in hex from https://pyth.network/developers/price-feed-ids#solana-mainnet-beta. After use cli::get_feed_id_from_hex to convert to pubkey.",
                 "When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet.",
-                "When source is StakePool - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.",
+                "When source is SwitchboardOnDemand - pass pull feed address.",
+                "When source is StakePool or StakePoolNetOfFee - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.",
+                "When source is Marinade - pass Marinade state address.",
+                "When source is SanctumSpl - pass Sanctum SPL stake pool address.",
                 "When source is Superlendy - pass reserve address.",
+                "When source is Chainlink - pass store feed (transmissions) account address.",
                 "When source is OffChain - pass `authority`.",
                 "For Transform feed type only. If type is Direct - pass source_address here.",
             ],
@@ -67,7 +76,7 @@ pub enum PriceProxyInstruction {
         account(
             name = "authority",
             flags(signer),
-            docs = ["Price-feed update authority."],
+            docs = ["Writer of Price-feed, see AddWriter."],
         )
     )]
     WritePrice {
@@ -77,6 +86,16 @@ pub enum PriceProxyInstruction {
     },
     /// Update price for Pyth, Switchboard Price-feeds
     ///
+    /// When Price-feed has reference source, its source account must be passed as the first of
+    /// remaining accounts. The price is rejected when it diverges from the reference price.
+    ///
+    /// When Price-feed has fallback source, its source account can be passed as the next of
+    /// remaining accounts. It is used only when the primary source is stale for longer than
+    /// `fallback_after_sec`, any other error of the primary source fails the update. For Pyth
+    /// primary source the feed id can be passed instead of a price update which can't be posted.
+    ///
+    /// When Price-feed has PriceHistory, it must be passed among remaining accounts too.
+    ///
     #[doc = ix_docs::update_price!()]
     #[accounts(
         account(
@@ -91,8 +110,13 @@ pub enum PriceProxyInstruction {
             docs = [
                 "When source is Pyth - pass PriceUpdate acc address, created by Pyth` PostUpdate ix.",
                 "When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet.",
-                "When source is StakePool - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.",
+                "When source is SwitchboardOnDemand - pass pull feed address.",
+                "When source is StakePool or StakePoolNetOfFee - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.",
+                "When source is Marinade - pass Marinade state address.",
+                "When source is SanctumSpl - pass Sanctum SPL stake pool address.",
                 "When source is Superlendy - pass reserve address.",
+                "When source is Chainlink - pass store feed (transmissions) account address.",
+                "When feed type is Composite or Median - pass CompositeFeed address. Source accounts of its legs follow as remaining accounts in order of legs.",
             ],
         ),
         account(
@@ -101,9 +125,13 @@ pub enum PriceProxyInstruction {
             docs = [
                 "When source is Pyth - pass PriceUpdate acc address, created by Pyth` PostUpdate ix.",
                 "When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet.",
-                "When source is StakePool - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.",
+                "When source is SwitchboardOnDemand - pass pull feed address.",
+                "When source is StakePool or StakePoolNetOfFee - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.",
+                "When source is Marinade - pass Marinade state address.",
+                "When source is SanctumSpl - pass Sanctum SPL stake pool address.",
                 "When source is Superlendy - pass reserve address.",
-                "For Transform feed type only. If type is Direct - pass source_address here.",
+                "When source is Chainlink - pass store feed (transmissions) account address.",
+                "For Transform feed type only. If type is Direct, Composite or Median - pass source_address here.",
             ],
         ),
     )]
@@ -132,8 +160,12 @@ pub enum PriceProxyInstruction {
             docs = [
                 "When source is Pyth - pass feed address. Get Price Feed ID in hex from https://pyth.network/developers/price-feed-ids#solana-mainnet-beta. After use cli::get_feed_id_from_hex to convert to pubkey.",
                 "When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet.",
-                "When source is StakePool - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.",
+                "When source is SwitchboardOnDemand - pass pull feed address.",
+                "When source is StakePool or StakePoolNetOfFee - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.",
+                "When source is Marinade - pass Marinade state address.",
+                "When source is SanctumSpl - pass Sanctum SPL stake pool address.",
                 "When source is Superlendy - pass reserve address.",
+                "When source is Chainlink - pass store feed (transmissions) account address.",
                 "When source is OffChain - pass `authority`.",
             ],
         ),
@@ -143,8 +175,12 @@ pub enum PriceProxyInstruction {
             docs = [
                 "When source is Pyth - pass feed address. Get Price Feed ID in hex from https://pyth.network/developers/price-feed-ids#solana-mainnet-beta. After use cli::get_feed_id_from_hex to convert to pubkey.",
                 "When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet.",
-                "When source is StakePool - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.",
+                "When source is SwitchboardOnDemand - pass pull feed address.",
+                "When source is StakePool or StakePoolNetOfFee - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.",
+                "When source is Marinade - pass Marinade state address.",
+                "When source is SanctumSpl - pass Sanctum SPL stake pool address.",
                 "When source is Superlendy - pass reserve address.",
+                "When source is Chainlink - pass store feed (transmissions) account address.",
                 "When source is OffChain - pass `authority`.",
             "For Transform feed type only. If type is Direct - pass source_address here.",
             ],
@@ -178,4 +214,336 @@ pub enum PriceProxyInstruction {
         ),
     )]
     Version,
+    /// Create CompositeFeed account
+    ///
+    #[doc = ix_docs::create_composite_feed!()]
+    #[accounts(
+        account(
+            name = "composite_feed",
+            flags(writable, signer),
+            checks(owner = "system"),
+            docs = ["CompositeFeed account to create."]
+        ),
+        account(
+            name = "authority",
+            flags(writable, signer),
+            docs = ["CompositeFeed authority. Will fund account."],
+        ),
+        program(id = "system", docs = ["System program."])
+    )]
+    CreateCompositeFeed {
+        /// Legs applied in order. From 1 to `MAX_COMPOSITE_LEGS`.
+        legs: Vec<CompositeLegParams>,
+        /// Minimum number of fresh legs for Median feed. Not greater than number of legs,
+        /// 0 - all legs. Not used by Composite feed.
+        quorum: u8,
+    },
+    /// Replace legs of CompositeFeed account
+    ///
+    #[doc = ix_docs::alter_composite_feed!()]
+    #[accounts(
+        account(
+            name = "composite_feed",
+            flags(writable),
+            checks(owner = "self"),
+            docs = ["CompositeFeed account to alter."]
+        ),
+        account(
+            name = "authority",
+            flags(signer),
+            docs = ["CompositeFeed authority."],
+        ),
+    )]
+    AlterCompositeFeed {
+        /// Legs applied in order. From 1 to `MAX_COMPOSITE_LEGS`.
+        legs: Vec<CompositeLegParams>,
+        /// Minimum number of fresh legs for Median feed. Not greater than number of legs,
+        /// 0 - all legs. Not used by Composite feed.
+        quorum: u8,
+    },
+    /// Delete CompositeFeed account
+    ///
+    #[doc = ix_docs::delete_composite_feed!()]
+    #[accounts(
+        account(
+            name = "composite_feed",
+            flags(writable),
+            checks(owner = "self"),
+            docs = ["CompositeFeed account to delete."]
+        ),
+        account(
+            name = "authority",
+            flags(signer),
+            docs = ["CompositeFeed authority."],
+        ),
+    )]
+    DeleteCompositeFeed,
+    /// Allow the next WritePrice or UpdatePrice of Price-feed with price within `max_deviation_bps`
+    /// of `price` to bypass the deviation circuit breaker. Used when the price really moved more
+    /// than `max_deviation_bps`. Other prices are still checked against the previous price.
+    ///
+    #[doc = ix_docs::override_circuit_breaker!()]
+    #[accounts(
+        account(
+            name = "price_feed",
+            flags(writable),
+            checks(owner = "self"),
+            docs = ["Price-feed account to override circuit breaker of."]
+        ),
+        account(
+            name = "authority",
+            flags(signer),
+            docs = ["Price-feed update authority."],
+        ),
+    )]
+    OverrideCircuitBreaker {
+        /// Price approved by the authority
+        price: Decimal,
+    },
+    /// Grow Price-feed account created by previous program version to the current size.
+    /// New fields are zeroed. Zero disables most of the new checks, but `max_future_sec` zero
+    /// means the default limit of `DEFAULT_MAX_FUTURE_SEC` for WritePrice timestamps.
+    ///
+    #[doc = ix_docs::migrate_price_feed!()]
+    #[accounts(
+        account(
+            name = "price_feed",
+            flags(writable),
+            checks(owner = "self"),
+            docs = ["Price-feed account to migrate."]
+        ),
+        account(
+            name = "authority",
+            flags(writable, signer),
+            docs = ["Price-feed update authority. Will fund account."],
+        ),
+        program(id = "system", docs = ["System program."])
+    )]
+    MigratePriceFeed,
+    /// Get time-weighted average price of Price-feed. Borsh serialized `Decimal` is returned
+    /// with `set_return_data`.
+    ///
+    /// Average is taken from the newest TWAP checkpoint at least `window_sec` old, so the actual
+    /// window can be up to `TWAP_CHECKPOINT_INTERVAL_SEC` longer.
+    ///
+    #[doc = ix_docs::get_twap!()]
+    #[accounts(
+        account(
+            name = "price_feed",
+            checks(owner = "self"),
+            docs = ["Price-feed account to get TWAP of."]
+        ),
+    )]
+    GetTwap {
+        /// TWAP window in secs
+        window_sec: u32,
+    },
+    /// Create PriceHistory account and link it to Price-feed. Once linked, the account must be
+    /// passed among the accounts of every WritePrice and UpdatePrice of the Price-feed, at any
+    /// position after the required ones.
+    ///
+    #[doc = ix_docs::create_price_history!()]
+    #[accounts(
+        account(
+            name = "price_history",
+            flags(writable, signer),
+            checks(owner = "system"),
+            docs = ["PriceHistory account to create."]
+        ),
+        account(
+            name = "price_feed",
+            flags(writable),
+            checks(owner = "self"),
+            docs = ["Price-feed to record prices of."]
+        ),
+        account(
+            name = "authority",
+            flags(writable, signer),
+            docs = ["Price-feed update authority. Will fund account."],
+        ),
+        program(id = "system", docs = ["System program."])
+    )]
+    CreatePriceHistory,
+    /// Delete PriceHistory account and unlink it from Price-feed
+    ///
+    #[doc = ix_docs::delete_price_history!()]
+    #[accounts(
+        account(
+            name = "price_history",
+            flags(writable),
+            checks(owner = "self"),
+            docs = ["PriceHistory account to delete."]
+        ),
+        account(
+            name = "price_feed",
+            flags(writable),
+            checks(owner = "self"),
+            docs = ["Price-feed the history belongs to."]
+        ),
+        account(
+            name = "authority",
+            flags(writable, signer),
+            docs = ["Price-feed update authority. Will receive rent."],
+        ),
+    )]
+    DeletePriceHistory,
+    /// Create Price-feed account at PDA. Without `seed` the address is derived from
+    /// `(symbol, quote_symbol, authority)`, see `pda::find_price_feed_address`. With `seed` it is
+    /// derived from `(seed, authority)`, see `pda::find_price_feed_address_with_seed`.
+    ///
+    #[doc = ix_docs::create_price_feed_pda!()]
+    #[accounts(
+        account(
+            name = "price_feed",
+            flags(writable),
+            checks(owner = "system"),
+            docs = ["Price-feed PDA to create."]
+        ),
+        account(
+            name = "authority",
+            flags(writable, signer),
+            docs = ["Price-feed update authority. Will fund account."],
+        ),
+        account(
+            name = "source_address",
+            flags(writable),
+            docs = ["The same as `source_address` of CreatePriceFeed."],
+        ),
+        account(
+            name = "transform_source_address",
+            flags(writable),
+            docs = ["The same as `transform_source_address` of CreatePriceFeed."],
+        ),
+        program(id = "system", docs = ["System program."])
+    )]
+    CreatePriceFeedPda {
+        params: PriceFeedParams,
+        /// Caller's seed, up to 32 bytes. When not set the address is derived from symbol.
+        seed: Option<Vec<u8>>,
+    },
+    /// Propose new update authority of Price-feed. It takes effect once the new authority signs
+    /// AcceptAuthority. Proposing the current authority cancels the pending transfer.
+    ///
+    #[doc = ix_docs::propose_authority!()]
+    #[accounts(
+        account(
+            name = "price_feed",
+            flags(writable),
+            checks(owner = "self"),
+            docs = ["Price-feed account to transfer."]
+        ),
+        account(
+            name = "authority",
+            flags(signer),
+            docs = ["Price-feed update authority."],
+        ),
+        account(
+            name = "new_authority",
+            docs = ["Proposed update authority."],
+        ),
+    )]
+    ProposeAuthority,
+    /// Accept update authority of Price-feed proposed by ProposeAuthority
+    ///
+    #[doc = ix_docs::accept_authority!()]
+    #[accounts(
+        account(
+            name = "price_feed",
+            flags(writable),
+            checks(owner = "self"),
+            docs = ["Price-feed account to accept."]
+        ),
+        account(
+            name = "new_authority",
+            flags(signer),
+            docs = ["Pending update authority of Price-feed."],
+        ),
+    )]
+    AcceptAuthority,
+    /// Authorize writer to WritePrice into OffChain Price-feed. Once any writer is authorized,
+    /// `source_address` can't write price anymore.
+    ///
+    #[doc = ix_docs::add_writer!()]
+    #[accounts(
+        account(
+            name = "price_feed",
+            flags(writable),
+            checks(owner = "self"),
+            docs = ["Price-feed account to add writer to."]
+        ),
+        account(
+            name = "authority",
+            flags(signer),
+            docs = ["Price-feed update authority."],
+        ),
+        account(
+            name = "writer",
+            docs = ["Writer to authorize."],
+        ),
+    )]
+    AddWriter,
+    /// Revoke writer of OffChain Price-feed. `source_address` doesn't write price again when
+    /// the last writer is removed, it can be authorized as a writer instead.
+    ///
+    #[doc = ix_docs::remove_writer!()]
+    #[accounts(
+        account(
+            name = "price_feed",
+            flags(writable),
+            checks(owner = "self"),
+            docs = ["Price-feed account to remove writer from."]
+        ),
+        account(
+            name = "authority",
+            flags(signer),
+            docs = ["Price-feed update authority."],
+        ),
+        account(
+            name = "writer",
+            docs = ["Writer to revoke."],
+        ),
+    )]
+    RemoveWriter,
+    /// Write price of off-chain Price-feed signed by one of its writers. Ed25519 program
+    /// instruction verifying the signature of `SignedPriceMessage` must go right before this
+    /// instruction, see `signed_price::new_ed25519_instruction`. Anyone can submit it.
+    ///
+    #[doc = ix_docs::write_signed_price!()]
+    #[accounts(
+        account(
+            name = "price_feed",
+            flags(writable),
+            checks(owner = "self"),
+            docs = ["Price-feed account for update."]
+        ),
+        account(
+            name = "instructions_sysvar",
+            docs = ["Instructions sysvar."],
+        ),
+    )]
+    WriteSignedPrice,
+    /// Write price with its confidence interval for off-chain Price-feed. `WritePrice` writes
+    /// zero confidence.
+    ///
+    #[doc = ix_docs::write_price_with_confidence!()]
+    #[accounts(
+        account(
+            name = "price_feed",
+            flags(writable),
+            checks(owner = "self"),
+            docs = ["Price-feed account for update."]
+        ),
+        account(
+            name = "authority",
+            flags(signer),
+            docs = ["Writer of Price-feed, see AddWriter."],
+        )
+    )]
+    WritePriceWithConfidence {
+        price: Decimal,
+        /// UTC unix-timestamp of price
+        price_timestamp: i64,
+        /// Confidence interval of price
+        confidence: Decimal,
+    },
 }
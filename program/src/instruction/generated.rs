@@ -11,14 +11,22 @@ pub struct CreatePriceFeed {
     pub authority: solana_program::pubkey::Pubkey,
     ///When source is Pyth - pass feed address. Get Price Feed ID in hex from https://pyth.network/developers/price-feed-ids#solana-mainnet-beta. After use cli::get_feed_id_from_hex to convert to pubkey.
     ///When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet.
-    ///When source is StakePool - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.
+    ///When source is SwitchboardOnDemand - pass pull feed address.
+    ///When source is StakePool or StakePoolNetOfFee - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.
+    ///When source is Marinade - pass Marinade state address.
+    ///When source is SanctumSpl - pass Sanctum SPL stake pool address.
     ///When source is Superlendy - pass reserve address.
+    ///When source is Chainlink - pass store feed (transmissions) account address.
     ///When source is OffChain - pass `authority`.
     pub source_address: solana_program::pubkey::Pubkey,
     ///When source is Pyth - pass feed address. Get Price Feed ID in hex from https://pyth.network/developers/price-feed-ids#solana-mainnet-beta. After use cli::get_feed_id_from_hex to convert to pubkey.
     ///When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet.
-    ///When source is StakePool - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.
+    ///When source is SwitchboardOnDemand - pass pull feed address.
+    ///When source is StakePool or StakePoolNetOfFee - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.
+    ///When source is Marinade - pass Marinade state address.
+    ///When source is SanctumSpl - pass Sanctum SPL stake pool address.
     ///When source is Superlendy - pass reserve address.
+    ///When source is Chainlink - pass store feed (transmissions) account address.
     ///When source is OffChain - pass `authority`.
     ///For Transform feed type only. If type is Direct - pass source_address here.
     pub transform_source_address: solana_program::pubkey::Pubkey,
@@ -79,7 +87,7 @@ pub struct WritePrice {
     pub program_id: solana_program::pubkey::Pubkey,
     ///Price-feed account for update.
     pub price_feed: solana_program::pubkey::Pubkey,
-    ///Price-feed update authority.
+    ///Writer of Price-feed, see AddWriter.
     pub authority: solana_program::pubkey::Pubkey,
     pub price: Decimal,
     /// UTC unix-timestamp of price
@@ -126,14 +134,23 @@ pub struct UpdatePrice {
     pub price_feed: solana_program::pubkey::Pubkey,
     ///When source is Pyth - pass PriceUpdate acc address, created by Pyth` PostUpdate ix.
     ///When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet.
-    ///When source is StakePool - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.
+    ///When source is SwitchboardOnDemand - pass pull feed address.
+    ///When source is StakePool or StakePoolNetOfFee - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.
+    ///When source is Marinade - pass Marinade state address.
+    ///When source is SanctumSpl - pass Sanctum SPL stake pool address.
     ///When source is Superlendy - pass reserve address.
+    ///When source is Chainlink - pass store feed (transmissions) account address.
+    ///When feed type is Composite or Median - pass CompositeFeed address. Source accounts of its legs follow as remaining accounts in order of legs.
     pub source_address: solana_program::pubkey::Pubkey,
     ///When source is Pyth - pass PriceUpdate acc address, created by Pyth` PostUpdate ix.
     ///When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet.
-    ///When source is StakePool - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.
+    ///When source is SwitchboardOnDemand - pass pull feed address.
+    ///When source is StakePool or StakePoolNetOfFee - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.
+    ///When source is Marinade - pass Marinade state address.
+    ///When source is SanctumSpl - pass Sanctum SPL stake pool address.
     ///When source is Superlendy - pass reserve address.
-    ///For Transform feed type only. If type is Direct - pass source_address here.
+    ///When source is Chainlink - pass store feed (transmissions) account address.
+    ///For Transform feed type only. If type is Direct, Composite or Median - pass source_address here.
     pub transform_source_address: solana_program::pubkey::Pubkey,
     /// Maximum age of price in secs
     pub maximum_age_sec: u64,
@@ -187,14 +204,22 @@ pub struct AlterPriceFeed {
     pub authority: solana_program::pubkey::Pubkey,
     ///When source is Pyth - pass feed address. Get Price Feed ID in hex from https://pyth.network/developers/price-feed-ids#solana-mainnet-beta. After use cli::get_feed_id_from_hex to convert to pubkey.
     ///When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet.
-    ///When source is StakePool - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.
+    ///When source is SwitchboardOnDemand - pass pull feed address.
+    ///When source is StakePool or StakePoolNetOfFee - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.
+    ///When source is Marinade - pass Marinade state address.
+    ///When source is SanctumSpl - pass Sanctum SPL stake pool address.
     ///When source is Superlendy - pass reserve address.
+    ///When source is Chainlink - pass store feed (transmissions) account address.
     ///When source is OffChain - pass `authority`.
     pub source_address: solana_program::pubkey::Pubkey,
     ///When source is Pyth - pass feed address. Get Price Feed ID in hex from https://pyth.network/developers/price-feed-ids#solana-mainnet-beta. After use cli::get_feed_id_from_hex to convert to pubkey.
     ///When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet.
-    ///When source is StakePool - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.
+    ///When source is SwitchboardOnDemand - pass pull feed address.
+    ///When source is StakePool or StakePoolNetOfFee - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.
+    ///When source is Marinade - pass Marinade state address.
+    ///When source is SanctumSpl - pass Sanctum SPL stake pool address.
     ///When source is Superlendy - pass reserve address.
+    ///When source is Chainlink - pass store feed (transmissions) account address.
     ///When source is OffChain - pass `authority`.
     ///For Transform feed type only. If type is Direct - pass source_address here.
     pub transform_source_address: solana_program::pubkey::Pubkey,
@@ -310,413 +335,2847 @@ impl Version {
         )
     }
 }
-/// [PriceProxyInstruction::CreatePriceFeed] instruction account indexes helper
-#[derive(Debug, PartialEq)]
-pub struct CreatePriceFeedAccountIndexes {
-    pub price_feed: usize,
-    pub authority: usize,
-    pub source_address: usize,
-    pub transform_source_address: usize,
-    pub system_program: usize,
-}
-impl CreatePriceFeedAccountIndexes {
-    pub const COUNT: usize = 5usize;
-    pub const PRICE_FEED: usize = 0usize;
-    pub const AUTHORITY: usize = 1usize;
-    pub const SOURCE_ADDRESS: usize = 2usize;
-    pub const TRANSFORM_SOURCE_ADDRESS: usize = 3usize;
-    pub const SYSTEM_PROGRAM: usize = 4usize;
-    pub fn new_direct_order() -> Self {
-        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
-        Self {
-            price_feed: iter.next().unwrap(),
-            authority: iter.next().unwrap(),
-            source_address: iter.next().unwrap(),
-            transform_source_address: iter.next().unwrap(),
-            system_program: iter.next().unwrap(),
-        }
-    }
-    pub fn try_from_indexes<'a>(
-        indexes: impl IntoIterator<Item = &'a u8>,
-    ) -> Result<Self, usize> {
-        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
-        let mut idx = 0_usize;
-        Ok(Self {
-            price_feed: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            authority: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            source_address: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            transform_source_address: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            system_program: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-        })
-    }
-}
-impl<'a> TryFrom<&'a [u8]> for CreatePriceFeedAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
-    }
+///[PriceProxyInstruction::CreateCompositeFeed] Builder struct
+pub struct CreateCompositeFeed {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///CompositeFeed account to create.
+    pub composite_feed: solana_program::pubkey::Pubkey,
+    ///CompositeFeed authority. Will fund account.
+    pub authority: solana_program::pubkey::Pubkey,
+    /// Legs applied in order. From 1 to `MAX_COMPOSITE_LEGS`.
+    pub legs: Vec<CompositeLegParams>,
+    /// Minimum number of fresh legs for Median feed. Not greater than number of legs.
+    /// Not used by Composite feed.
+    pub quorum: u8,
 }
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for CreatePriceFeedAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
+impl CreateCompositeFeed {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            composite_feed,
+            authority,
+            legs,
+            quorum,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(composite_feed, true),
+            ]);
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(authority, true)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    solana_program::system_program::ID,
+                    false,
+                ),
+            ]);
+        let ix = PriceProxyInstruction::CreateCompositeFeed {
+            legs,
+            quorum,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
 }
-impl<const N: usize> TryFrom<[u8; N]> for CreatePriceFeedAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
-    }
+///[PriceProxyInstruction::AlterCompositeFeed] Builder struct
+pub struct AlterCompositeFeed {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///CompositeFeed account to alter.
+    pub composite_feed: solana_program::pubkey::Pubkey,
+    ///CompositeFeed authority.
+    pub authority: solana_program::pubkey::Pubkey,
+    /// Legs applied in order. From 1 to `MAX_COMPOSITE_LEGS`.
+    pub legs: Vec<CompositeLegParams>,
+    /// Minimum number of fresh legs for Median feed. Not greater than number of legs.
+    /// Not used by Composite feed.
+    pub quorum: u8,
 }
-impl TryFrom<Vec<u8>> for CreatePriceFeedAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
+impl AlterCompositeFeed {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            composite_feed,
+            authority,
+            legs,
+            quorum,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(composite_feed, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(authority, true),
+            ]);
+        let ix = PriceProxyInstruction::AlterCompositeFeed {
+            legs,
+            quorum,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
 }
-/// [PriceProxyInstruction::WritePrice] instruction account indexes helper
-#[derive(Debug, PartialEq)]
-pub struct WritePriceAccountIndexes {
-    pub price_feed: usize,
-    pub authority: usize,
-}
-impl WritePriceAccountIndexes {
-    pub const COUNT: usize = 2usize;
-    pub const PRICE_FEED: usize = 0usize;
-    pub const AUTHORITY: usize = 1usize;
-    pub fn new_direct_order() -> Self {
-        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
-        Self {
-            price_feed: iter.next().unwrap(),
-            authority: iter.next().unwrap(),
-        }
-    }
-    pub fn try_from_indexes<'a>(
-        indexes: impl IntoIterator<Item = &'a u8>,
-    ) -> Result<Self, usize> {
-        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
-        let mut idx = 0_usize;
-        Ok(Self {
-            price_feed: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            authority: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-        })
-    }
+///[PriceProxyInstruction::DeleteCompositeFeed] Builder struct
+pub struct DeleteCompositeFeed {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///CompositeFeed account to delete.
+    pub composite_feed: solana_program::pubkey::Pubkey,
+    ///CompositeFeed authority.
+    pub authority: solana_program::pubkey::Pubkey,
 }
-impl<'a> TryFrom<&'a [u8]> for WritePriceAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
+impl DeleteCompositeFeed {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            composite_feed,
+            authority,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(composite_feed, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(authority, true),
+            ]);
+        let ix = PriceProxyInstruction::DeleteCompositeFeed {
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
 }
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for WritePriceAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
-    }
+///[PriceProxyInstruction::OverrideCircuitBreaker] Builder struct
+pub struct OverrideCircuitBreaker {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Price-feed account to override circuit breaker of.
+    pub price_feed: solana_program::pubkey::Pubkey,
+    ///Price-feed update authority.
+    pub authority: solana_program::pubkey::Pubkey,
+    /// Price approved by the authority
+    pub price: Decimal,
 }
-impl<const N: usize> TryFrom<[u8; N]> for WritePriceAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
+impl OverrideCircuitBreaker {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            price_feed,
+            authority,
+            price,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(price_feed, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(authority, true),
+            ]);
+        let ix = PriceProxyInstruction::OverrideCircuitBreaker {
+            price,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
 }
-impl TryFrom<Vec<u8>> for WritePriceAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
-    }
+///[PriceProxyInstruction::MigratePriceFeed] Builder struct
+pub struct MigratePriceFeed {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Price-feed account to migrate.
+    pub price_feed: solana_program::pubkey::Pubkey,
+    ///Price-feed update authority. Will fund account.
+    pub authority: solana_program::pubkey::Pubkey,
 }
-/// [PriceProxyInstruction::UpdatePrice] instruction account indexes helper
-#[derive(Debug, PartialEq)]
+impl MigratePriceFeed {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            price_feed,
+            authority,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(price_feed, false)]);
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(authority, true)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    solana_program::system_program::ID,
+                    false,
+                ),
+            ]);
+        let ix = PriceProxyInstruction::MigratePriceFeed {
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[PriceProxyInstruction::GetTwap] Builder struct
+pub struct GetTwap {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Price-feed account to get TWAP of.
+    pub price_feed: solana_program::pubkey::Pubkey,
+    /// TWAP window in secs
+    pub window_sec: u32,
+}
+impl GetTwap {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            price_feed,
+            window_sec,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    price_feed,
+                    false,
+                ),
+            ]);
+        let ix = PriceProxyInstruction::GetTwap {
+            window_sec,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[PriceProxyInstruction::CreatePriceHistory] Builder struct
+pub struct CreatePriceHistory {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///PriceHistory account to create.
+    pub price_history: solana_program::pubkey::Pubkey,
+    ///Price-feed to record prices of.
+    pub price_feed: solana_program::pubkey::Pubkey,
+    ///Price-feed update authority. Will fund account.
+    pub authority: solana_program::pubkey::Pubkey,
+}
+impl CreatePriceHistory {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            price_history,
+            price_feed,
+            authority,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(price_history, true),
+            ]);
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(price_feed, false)]);
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(authority, true)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    solana_program::system_program::ID,
+                    false,
+                ),
+            ]);
+        let ix = PriceProxyInstruction::CreatePriceHistory {
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[PriceProxyInstruction::DeletePriceHistory] Builder struct
+pub struct DeletePriceHistory {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///PriceHistory account to delete.
+    pub price_history: solana_program::pubkey::Pubkey,
+    ///Price-feed the history belongs to.
+    pub price_feed: solana_program::pubkey::Pubkey,
+    ///PriceHistory authority. Will receive rent.
+    pub authority: solana_program::pubkey::Pubkey,
+}
+impl DeletePriceHistory {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            price_history,
+            price_feed,
+            authority,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(price_history, false),
+            ]);
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(price_feed, false)]);
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(authority, true)]);
+        let ix = PriceProxyInstruction::DeletePriceHistory {
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[PriceProxyInstruction::CreatePriceFeedPda] Builder struct
+pub struct CreatePriceFeedPda {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Price-feed PDA to create.
+    pub price_feed: solana_program::pubkey::Pubkey,
+    ///Price-feed update authority. Will fund account.
+    pub authority: solana_program::pubkey::Pubkey,
+    ///The same as `source_address` of CreatePriceFeed.
+    pub source_address: solana_program::pubkey::Pubkey,
+    ///The same as `transform_source_address` of CreatePriceFeed.
+    pub transform_source_address: solana_program::pubkey::Pubkey,
+    pub params: PriceFeedParams,
+    /// Caller's seed, up to 32 bytes. When not set the address is derived from symbol.
+    pub seed: Option<Vec<u8>>,
+}
+impl CreatePriceFeedPda {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            price_feed,
+            authority,
+            source_address,
+            transform_source_address,
+            params,
+            seed,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(price_feed, false)]);
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(authority, true)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(source_address, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    transform_source_address,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    solana_program::system_program::ID,
+                    false,
+                ),
+            ]);
+        let ix = PriceProxyInstruction::CreatePriceFeedPda {
+            params,
+            seed,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[PriceProxyInstruction::ProposeAuthority] Builder struct
+pub struct ProposeAuthority {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Price-feed account to transfer.
+    pub price_feed: solana_program::pubkey::Pubkey,
+    ///Price-feed update authority.
+    pub authority: solana_program::pubkey::Pubkey,
+    ///Proposed update authority.
+    pub new_authority: solana_program::pubkey::Pubkey,
+}
+impl ProposeAuthority {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            price_feed,
+            authority,
+            new_authority,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(price_feed, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(authority, true),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    new_authority,
+                    false,
+                ),
+            ]);
+        let ix = PriceProxyInstruction::ProposeAuthority {
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[PriceProxyInstruction::AcceptAuthority] Builder struct
+pub struct AcceptAuthority {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Price-feed account to accept.
+    pub price_feed: solana_program::pubkey::Pubkey,
+    ///Pending update authority of Price-feed.
+    pub new_authority: solana_program::pubkey::Pubkey,
+}
+impl AcceptAuthority {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            price_feed,
+            new_authority,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(price_feed, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    new_authority,
+                    true,
+                ),
+            ]);
+        let ix = PriceProxyInstruction::AcceptAuthority {
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[PriceProxyInstruction::AddWriter] Builder struct
+pub struct AddWriter {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Price-feed account to add writer to.
+    pub price_feed: solana_program::pubkey::Pubkey,
+    ///Price-feed update authority.
+    pub authority: solana_program::pubkey::Pubkey,
+    ///Writer to authorize.
+    pub writer: solana_program::pubkey::Pubkey,
+}
+impl AddWriter {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            price_feed,
+            authority,
+            writer,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(price_feed, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(authority, true),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(writer, false),
+            ]);
+        let ix = PriceProxyInstruction::AddWriter {
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[PriceProxyInstruction::RemoveWriter] Builder struct
+pub struct RemoveWriter {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Price-feed account to remove writer from.
+    pub price_feed: solana_program::pubkey::Pubkey,
+    ///Price-feed update authority.
+    pub authority: solana_program::pubkey::Pubkey,
+    ///Writer to revoke.
+    pub writer: solana_program::pubkey::Pubkey,
+}
+impl RemoveWriter {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            price_feed,
+            authority,
+            writer,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(price_feed, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(authority, true),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(writer, false),
+            ]);
+        let ix = PriceProxyInstruction::RemoveWriter {
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[PriceProxyInstruction::WriteSignedPrice] Builder struct
+pub struct WriteSignedPrice {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Price-feed account for update.
+    pub price_feed: solana_program::pubkey::Pubkey,
+    ///Instructions sysvar.
+    pub instructions_sysvar: solana_program::pubkey::Pubkey,
+}
+impl WriteSignedPrice {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            price_feed,
+            instructions_sysvar,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(price_feed, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    instructions_sysvar,
+                    false,
+                ),
+            ]);
+        let ix = PriceProxyInstruction::WriteSignedPrice {
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[PriceProxyInstruction::WritePriceWithConfidence] Builder struct
+pub struct WritePriceWithConfidence {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Price-feed account for update.
+    pub price_feed: solana_program::pubkey::Pubkey,
+    ///Writer of Price-feed, see AddWriter.
+    pub authority: solana_program::pubkey::Pubkey,
+    pub price: Decimal,
+    /// UTC unix-timestamp of price
+    pub price_timestamp: i64,
+    /// Confidence interval of price
+    pub confidence: Decimal,
+}
+impl WritePriceWithConfidence {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            price_feed,
+            authority,
+            price,
+            price_timestamp,
+            confidence,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(price_feed, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(authority, true),
+            ]);
+        let ix = PriceProxyInstruction::WritePriceWithConfidence {
+            price,
+            price_timestamp,
+            confidence,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+/// [PriceProxyInstruction::CreatePriceFeed] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct CreatePriceFeedAccountIndexes {
+    pub price_feed: usize,
+    pub authority: usize,
+    pub source_address: usize,
+    pub transform_source_address: usize,
+    pub system_program: usize,
+}
+impl CreatePriceFeedAccountIndexes {
+    pub const COUNT: usize = 5usize;
+    pub const PRICE_FEED: usize = 0usize;
+    pub const AUTHORITY: usize = 1usize;
+    pub const SOURCE_ADDRESS: usize = 2usize;
+    pub const TRANSFORM_SOURCE_ADDRESS: usize = 3usize;
+    pub const SYSTEM_PROGRAM: usize = 4usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            price_feed: iter.next().unwrap(),
+            authority: iter.next().unwrap(),
+            source_address: iter.next().unwrap(),
+            transform_source_address: iter.next().unwrap(),
+            system_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            price_feed: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            source_address: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            transform_source_address: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            system_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for CreatePriceFeedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for CreatePriceFeedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for CreatePriceFeedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for CreatePriceFeedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [PriceProxyInstruction::WritePrice] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct WritePriceAccountIndexes {
+    pub price_feed: usize,
+    pub authority: usize,
+}
+impl WritePriceAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const PRICE_FEED: usize = 0usize;
+    pub const AUTHORITY: usize = 1usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            price_feed: iter.next().unwrap(),
+            authority: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            price_feed: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for WritePriceAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for WritePriceAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for WritePriceAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for WritePriceAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [PriceProxyInstruction::UpdatePrice] instruction account indexes helper
+#[derive(Debug, PartialEq)]
 pub struct UpdatePriceAccountIndexes {
     pub price_feed: usize,
-    pub source_address: usize,
-    pub transform_source_address: usize,
+    pub source_address: usize,
+    pub transform_source_address: usize,
+}
+impl UpdatePriceAccountIndexes {
+    pub const COUNT: usize = 3usize;
+    pub const PRICE_FEED: usize = 0usize;
+    pub const SOURCE_ADDRESS: usize = 1usize;
+    pub const TRANSFORM_SOURCE_ADDRESS: usize = 2usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            price_feed: iter.next().unwrap(),
+            source_address: iter.next().unwrap(),
+            transform_source_address: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            price_feed: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            source_address: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            transform_source_address: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for UpdatePriceAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for UpdatePriceAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for UpdatePriceAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for UpdatePriceAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [PriceProxyInstruction::AlterPriceFeed] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct AlterPriceFeedAccountIndexes {
+    pub price_feed: usize,
+    pub authority: usize,
+    pub source_address: usize,
+    pub transform_source_address: usize,
+}
+impl AlterPriceFeedAccountIndexes {
+    pub const COUNT: usize = 4usize;
+    pub const PRICE_FEED: usize = 0usize;
+    pub const AUTHORITY: usize = 1usize;
+    pub const SOURCE_ADDRESS: usize = 2usize;
+    pub const TRANSFORM_SOURCE_ADDRESS: usize = 3usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            price_feed: iter.next().unwrap(),
+            authority: iter.next().unwrap(),
+            source_address: iter.next().unwrap(),
+            transform_source_address: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            price_feed: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            source_address: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            transform_source_address: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for AlterPriceFeedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for AlterPriceFeedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for AlterPriceFeedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for AlterPriceFeedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [PriceProxyInstruction::DeletePriceFeed] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct DeletePriceFeedAccountIndexes {
+    pub price_feed: usize,
+    pub authority: usize,
+}
+impl DeletePriceFeedAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const PRICE_FEED: usize = 0usize;
+    pub const AUTHORITY: usize = 1usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            price_feed: iter.next().unwrap(),
+            authority: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            price_feed: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for DeletePriceFeedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for DeletePriceFeedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for DeletePriceFeedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for DeletePriceFeedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [PriceProxyInstruction::Version] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct VersionAccountIndexes {
+    pub system_program: usize,
+}
+impl VersionAccountIndexes {
+    pub const COUNT: usize = 1usize;
+    pub const SYSTEM_PROGRAM: usize = 0usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            system_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            system_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for VersionAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for VersionAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for VersionAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for VersionAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [PriceProxyInstruction::CreateCompositeFeed] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct CreateCompositeFeedAccountIndexes {
+    pub composite_feed: usize,
+    pub authority: usize,
+    pub system_program: usize,
+}
+impl CreateCompositeFeedAccountIndexes {
+    pub const COUNT: usize = 3usize;
+    pub const COMPOSITE_FEED: usize = 0usize;
+    pub const AUTHORITY: usize = 1usize;
+    pub const SYSTEM_PROGRAM: usize = 2usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            composite_feed: iter.next().unwrap(),
+            authority: iter.next().unwrap(),
+            system_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            composite_feed: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            system_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for CreateCompositeFeedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for CreateCompositeFeedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for CreateCompositeFeedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for CreateCompositeFeedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [PriceProxyInstruction::AlterCompositeFeed] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct AlterCompositeFeedAccountIndexes {
+    pub composite_feed: usize,
+    pub authority: usize,
+}
+impl AlterCompositeFeedAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const COMPOSITE_FEED: usize = 0usize;
+    pub const AUTHORITY: usize = 1usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            composite_feed: iter.next().unwrap(),
+            authority: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            composite_feed: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for AlterCompositeFeedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for AlterCompositeFeedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for AlterCompositeFeedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for AlterCompositeFeedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [PriceProxyInstruction::DeleteCompositeFeed] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct DeleteCompositeFeedAccountIndexes {
+    pub composite_feed: usize,
+    pub authority: usize,
+}
+impl DeleteCompositeFeedAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const COMPOSITE_FEED: usize = 0usize;
+    pub const AUTHORITY: usize = 1usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            composite_feed: iter.next().unwrap(),
+            authority: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            composite_feed: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for DeleteCompositeFeedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for DeleteCompositeFeedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for DeleteCompositeFeedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for DeleteCompositeFeedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [PriceProxyInstruction::OverrideCircuitBreaker] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct OverrideCircuitBreakerAccountIndexes {
+    pub price_feed: usize,
+    pub authority: usize,
+}
+impl OverrideCircuitBreakerAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const PRICE_FEED: usize = 0usize;
+    pub const AUTHORITY: usize = 1usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            price_feed: iter.next().unwrap(),
+            authority: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            price_feed: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for OverrideCircuitBreakerAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for OverrideCircuitBreakerAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for OverrideCircuitBreakerAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for OverrideCircuitBreakerAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [PriceProxyInstruction::MigratePriceFeed] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct MigratePriceFeedAccountIndexes {
+    pub price_feed: usize,
+    pub authority: usize,
+    pub system_program: usize,
+}
+impl MigratePriceFeedAccountIndexes {
+    pub const COUNT: usize = 3usize;
+    pub const PRICE_FEED: usize = 0usize;
+    pub const AUTHORITY: usize = 1usize;
+    pub const SYSTEM_PROGRAM: usize = 2usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            price_feed: iter.next().unwrap(),
+            authority: iter.next().unwrap(),
+            system_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            price_feed: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            system_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for MigratePriceFeedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for MigratePriceFeedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for MigratePriceFeedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for MigratePriceFeedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [PriceProxyInstruction::GetTwap] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct GetTwapAccountIndexes {
+    pub price_feed: usize,
+}
+impl GetTwapAccountIndexes {
+    pub const COUNT: usize = 1usize;
+    pub const PRICE_FEED: usize = 0usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            price_feed: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            price_feed: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for GetTwapAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for GetTwapAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for GetTwapAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for GetTwapAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [PriceProxyInstruction::CreatePriceHistory] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct CreatePriceHistoryAccountIndexes {
+    pub price_history: usize,
+    pub price_feed: usize,
+    pub authority: usize,
+    pub system_program: usize,
+}
+impl CreatePriceHistoryAccountIndexes {
+    pub const COUNT: usize = 4usize;
+    pub const PRICE_HISTORY: usize = 0usize;
+    pub const PRICE_FEED: usize = 1usize;
+    pub const AUTHORITY: usize = 2usize;
+    pub const SYSTEM_PROGRAM: usize = 3usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            price_history: iter.next().unwrap(),
+            price_feed: iter.next().unwrap(),
+            authority: iter.next().unwrap(),
+            system_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            price_history: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            price_feed: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            system_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for CreatePriceHistoryAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for CreatePriceHistoryAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for CreatePriceHistoryAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for CreatePriceHistoryAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [PriceProxyInstruction::DeletePriceHistory] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct DeletePriceHistoryAccountIndexes {
+    pub price_history: usize,
+    pub price_feed: usize,
+    pub authority: usize,
+}
+impl DeletePriceHistoryAccountIndexes {
+    pub const COUNT: usize = 3usize;
+    pub const PRICE_HISTORY: usize = 0usize;
+    pub const PRICE_FEED: usize = 1usize;
+    pub const AUTHORITY: usize = 2usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            price_history: iter.next().unwrap(),
+            price_feed: iter.next().unwrap(),
+            authority: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            price_history: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            price_feed: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for DeletePriceHistoryAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for DeletePriceHistoryAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for DeletePriceHistoryAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for DeletePriceHistoryAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [PriceProxyInstruction::CreatePriceFeedPda] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct CreatePriceFeedPdaAccountIndexes {
+    pub price_feed: usize,
+    pub authority: usize,
+    pub source_address: usize,
+    pub transform_source_address: usize,
+    pub system_program: usize,
+}
+impl CreatePriceFeedPdaAccountIndexes {
+    pub const COUNT: usize = 5usize;
+    pub const PRICE_FEED: usize = 0usize;
+    pub const AUTHORITY: usize = 1usize;
+    pub const SOURCE_ADDRESS: usize = 2usize;
+    pub const TRANSFORM_SOURCE_ADDRESS: usize = 3usize;
+    pub const SYSTEM_PROGRAM: usize = 4usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            price_feed: iter.next().unwrap(),
+            authority: iter.next().unwrap(),
+            source_address: iter.next().unwrap(),
+            transform_source_address: iter.next().unwrap(),
+            system_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            price_feed: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            source_address: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            transform_source_address: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            system_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for CreatePriceFeedPdaAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for CreatePriceFeedPdaAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for CreatePriceFeedPdaAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for CreatePriceFeedPdaAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [PriceProxyInstruction::ProposeAuthority] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct ProposeAuthorityAccountIndexes {
+    pub price_feed: usize,
+    pub authority: usize,
+    pub new_authority: usize,
+}
+impl ProposeAuthorityAccountIndexes {
+    pub const COUNT: usize = 3usize;
+    pub const PRICE_FEED: usize = 0usize;
+    pub const AUTHORITY: usize = 1usize;
+    pub const NEW_AUTHORITY: usize = 2usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            price_feed: iter.next().unwrap(),
+            authority: iter.next().unwrap(),
+            new_authority: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            price_feed: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            new_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for ProposeAuthorityAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for ProposeAuthorityAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for ProposeAuthorityAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for ProposeAuthorityAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [PriceProxyInstruction::AcceptAuthority] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct AcceptAuthorityAccountIndexes {
+    pub price_feed: usize,
+    pub new_authority: usize,
+}
+impl AcceptAuthorityAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const PRICE_FEED: usize = 0usize;
+    pub const NEW_AUTHORITY: usize = 1usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            price_feed: iter.next().unwrap(),
+            new_authority: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            price_feed: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            new_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for AcceptAuthorityAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for AcceptAuthorityAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for AcceptAuthorityAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for AcceptAuthorityAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [PriceProxyInstruction::AddWriter] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct AddWriterAccountIndexes {
+    pub price_feed: usize,
+    pub authority: usize,
+    pub writer: usize,
+}
+impl AddWriterAccountIndexes {
+    pub const COUNT: usize = 3usize;
+    pub const PRICE_FEED: usize = 0usize;
+    pub const AUTHORITY: usize = 1usize;
+    pub const WRITER: usize = 2usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            price_feed: iter.next().unwrap(),
+            authority: iter.next().unwrap(),
+            writer: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            price_feed: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            writer: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for AddWriterAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for AddWriterAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for AddWriterAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for AddWriterAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [PriceProxyInstruction::RemoveWriter] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct RemoveWriterAccountIndexes {
+    pub price_feed: usize,
+    pub authority: usize,
+    pub writer: usize,
+}
+impl RemoveWriterAccountIndexes {
+    pub const COUNT: usize = 3usize;
+    pub const PRICE_FEED: usize = 0usize;
+    pub const AUTHORITY: usize = 1usize;
+    pub const WRITER: usize = 2usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            price_feed: iter.next().unwrap(),
+            authority: iter.next().unwrap(),
+            writer: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            price_feed: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            writer: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for RemoveWriterAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for RemoveWriterAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for RemoveWriterAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for RemoveWriterAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [PriceProxyInstruction::WriteSignedPrice] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct WriteSignedPriceAccountIndexes {
+    pub price_feed: usize,
+    pub instructions_sysvar: usize,
+}
+impl WriteSignedPriceAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const PRICE_FEED: usize = 0usize;
+    pub const INSTRUCTIONS_SYSVAR: usize = 1usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            price_feed: iter.next().unwrap(),
+            instructions_sysvar: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            price_feed: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            instructions_sysvar: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for WriteSignedPriceAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for WriteSignedPriceAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for WriteSignedPriceAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for WriteSignedPriceAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [PriceProxyInstruction::WritePriceWithConfidence] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct WritePriceWithConfidenceAccountIndexes {
+    pub price_feed: usize,
+    pub authority: usize,
+}
+impl WritePriceWithConfidenceAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const PRICE_FEED: usize = 0usize;
+    pub const AUTHORITY: usize = 1usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            price_feed: iter.next().unwrap(),
+            authority: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            price_feed: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for WritePriceWithConfidenceAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for WritePriceWithConfidenceAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for WritePriceWithConfidenceAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for WritePriceWithConfidenceAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+///[PriceProxyInstruction::CreatePriceFeed] instruction account infos helper
+#[derive(Debug)]
+pub struct CreatePriceFeedAccounts<'a, 'i> {
+    ///Price-feed account to create.
+    pub price_feed: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Price-feed update authority. Will fund account.
+    pub authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///When source is Pyth - pass feed address. Get Price Feed ID in hex from https://pyth.network/developers/price-feed-ids#solana-mainnet-beta. After use cli::get_feed_id_from_hex to convert to pubkey.
+    ///When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet.
+    ///When source is SwitchboardOnDemand - pass pull feed address.
+    ///When source is StakePool or StakePoolNetOfFee - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.
+    ///When source is Marinade - pass Marinade state address.
+    ///When source is SanctumSpl - pass Sanctum SPL stake pool address.
+    ///When source is Superlendy - pass reserve address.
+    ///When source is Chainlink - pass store feed (transmissions) account address.
+    ///When source is OffChain - pass `authority`.
+    pub source_address: &'a solana_program::account_info::AccountInfo<'i>,
+    ///When source is Pyth - pass feed address. Get Price Feed ID in hex from https://pyth.network/developers/price-feed-ids#solana-mainnet-beta. After use cli::get_feed_id_from_hex to convert to pubkey.
+    ///When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet.
+    ///When source is SwitchboardOnDemand - pass pull feed address.
+    ///When source is StakePool or StakePoolNetOfFee - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.
+    ///When source is Marinade - pass Marinade state address.
+    ///When source is SanctumSpl - pass Sanctum SPL stake pool address.
+    ///When source is Superlendy - pass reserve address.
+    ///When source is Chainlink - pass store feed (transmissions) account address.
+    ///When source is OffChain - pass `authority`.
+    ///For Transform feed type only. If type is Direct - pass source_address here.
+    pub transform_source_address: &'a solana_program::account_info::AccountInfo<'i>,
+    ///System program.
+    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> CreatePriceFeedAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let price_feed = texture_common::utils::next_account_info(iter)?;
+        let authority = texture_common::utils::next_account_info(iter)?;
+        let source_address = texture_common::utils::next_account_info(iter)?;
+        let transform_source_address = texture_common::utils::next_account_info(iter)?;
+        let system_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !price_feed.is_writable {
+            solana_program::msg!(concat!(stringify!(price_feed), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*price_feed.key).into());
+        }
+        if !price_feed.is_signer {
+            return Err(texture_common::error::MissingSignature(*price_feed.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            price_feed.owner,
+            &solana_program::system_program::ID,
+            concat!(stringify!(price_feed), " owner"),
+        )?;
+        if !authority.is_writable {
+            solana_program::msg!(concat!(stringify!(authority), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*authority.key).into());
+        }
+        if !authority.is_signer {
+            return Err(texture_common::error::MissingSignature(*authority.key).into());
+        }
+        if !source_address.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(source_address), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*source_address.key).into(),
+            );
+        }
+        if !transform_source_address.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(transform_source_address), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*transform_source_address.key)
+                    .into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            system_program.key,
+            &solana_program::system_program::ID,
+            stringify!(system_program),
+        )?;
+        Ok(Self {
+            price_feed,
+            authority,
+            source_address,
+            transform_source_address,
+            system_program,
+        })
+    }
+}
+///[PriceProxyInstruction::WritePrice] instruction account infos helper
+#[derive(Debug)]
+pub struct WritePriceAccounts<'a, 'i> {
+    ///Price-feed account for update.
+    pub price_feed: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Writer of Price-feed, see AddWriter.
+    pub authority: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> WritePriceAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let price_feed = texture_common::utils::next_account_info(iter)?;
+        let authority = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !price_feed.is_writable {
+            solana_program::msg!(concat!(stringify!(price_feed), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*price_feed.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            price_feed.owner,
+            &__self_program_id__,
+            concat!(stringify!(price_feed), " owner"),
+        )?;
+        if !authority.is_signer {
+            return Err(texture_common::error::MissingSignature(*authority.key).into());
+        }
+        Ok(Self { price_feed, authority })
+    }
+}
+///[PriceProxyInstruction::UpdatePrice] instruction account infos helper
+#[derive(Debug)]
+pub struct UpdatePriceAccounts<'a, 'i> {
+    ///Price-feed account to update.
+    pub price_feed: &'a solana_program::account_info::AccountInfo<'i>,
+    ///When source is Pyth - pass PriceUpdate acc address, created by Pyth` PostUpdate ix.
+    ///When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet.
+    ///When source is SwitchboardOnDemand - pass pull feed address.
+    ///When source is StakePool or StakePoolNetOfFee - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.
+    ///When source is Marinade - pass Marinade state address.
+    ///When source is SanctumSpl - pass Sanctum SPL stake pool address.
+    ///When source is Superlendy - pass reserve address.
+    ///When source is Chainlink - pass store feed (transmissions) account address.
+    ///When feed type is Composite or Median - pass CompositeFeed address. Source accounts of its legs follow as remaining accounts in order of legs.
+    pub source_address: &'a solana_program::account_info::AccountInfo<'i>,
+    ///When source is Pyth - pass PriceUpdate acc address, created by Pyth` PostUpdate ix.
+    ///When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet.
+    ///When source is SwitchboardOnDemand - pass pull feed address.
+    ///When source is StakePool or StakePoolNetOfFee - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.
+    ///When source is Marinade - pass Marinade state address.
+    ///When source is SanctumSpl - pass Sanctum SPL stake pool address.
+    ///When source is Superlendy - pass reserve address.
+    ///When source is Chainlink - pass store feed (transmissions) account address.
+    ///For Transform feed type only. If type is Direct, Composite or Median - pass source_address here.
+    pub transform_source_address: &'a solana_program::account_info::AccountInfo<'i>,
 }
-impl UpdatePriceAccountIndexes {
-    pub const COUNT: usize = 3usize;
-    pub const PRICE_FEED: usize = 0usize;
-    pub const SOURCE_ADDRESS: usize = 1usize;
-    pub const TRANSFORM_SOURCE_ADDRESS: usize = 2usize;
-    pub fn new_direct_order() -> Self {
-        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
-        Self {
-            price_feed: iter.next().unwrap(),
-            source_address: iter.next().unwrap(),
-            transform_source_address: iter.next().unwrap(),
+impl<'a, 'i> UpdatePriceAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let price_feed = texture_common::utils::next_account_info(iter)?;
+        let source_address = texture_common::utils::next_account_info(iter)?;
+        let transform_source_address = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !price_feed.is_writable {
+            solana_program::msg!(concat!(stringify!(price_feed), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*price_feed.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            price_feed.owner,
+            &__self_program_id__,
+            concat!(stringify!(price_feed), " owner"),
+        )?;
+        if !source_address.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(source_address), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*source_address.key).into(),
+            );
         }
+        if !transform_source_address.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(transform_source_address), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*transform_source_address.key)
+                    .into(),
+            );
+        }
+        Ok(Self {
+            price_feed,
+            source_address,
+            transform_source_address,
+        })
     }
-    pub fn try_from_indexes<'a>(
-        indexes: impl IntoIterator<Item = &'a u8>,
-    ) -> Result<Self, usize> {
-        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
-        let mut idx = 0_usize;
+}
+///[PriceProxyInstruction::AlterPriceFeed] instruction account infos helper
+#[derive(Debug)]
+pub struct AlterPriceFeedAccounts<'a, 'i> {
+    ///Price-feed account to create.
+    pub price_feed: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Price-feed update authority.
+    pub authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///When source is Pyth - pass feed address. Get Price Feed ID in hex from https://pyth.network/developers/price-feed-ids#solana-mainnet-beta. After use cli::get_feed_id_from_hex to convert to pubkey.
+    ///When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet.
+    ///When source is SwitchboardOnDemand - pass pull feed address.
+    ///When source is StakePool or StakePoolNetOfFee - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.
+    ///When source is Marinade - pass Marinade state address.
+    ///When source is SanctumSpl - pass Sanctum SPL stake pool address.
+    ///When source is Superlendy - pass reserve address.
+    ///When source is Chainlink - pass store feed (transmissions) account address.
+    ///When source is OffChain - pass `authority`.
+    pub source_address: &'a solana_program::account_info::AccountInfo<'i>,
+    ///When source is Pyth - pass feed address. Get Price Feed ID in hex from https://pyth.network/developers/price-feed-ids#solana-mainnet-beta. After use cli::get_feed_id_from_hex to convert to pubkey.
+    ///When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet.
+    ///When source is SwitchboardOnDemand - pass pull feed address.
+    ///When source is StakePool or StakePoolNetOfFee - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.
+    ///When source is Marinade - pass Marinade state address.
+    ///When source is SanctumSpl - pass Sanctum SPL stake pool address.
+    ///When source is Superlendy - pass reserve address.
+    ///When source is Chainlink - pass store feed (transmissions) account address.
+    ///When source is OffChain - pass `authority`.
+    ///For Transform feed type only. If type is Direct - pass source_address here.
+    pub transform_source_address: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> AlterPriceFeedAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let price_feed = texture_common::utils::next_account_info(iter)?;
+        let authority = texture_common::utils::next_account_info(iter)?;
+        let source_address = texture_common::utils::next_account_info(iter)?;
+        let transform_source_address = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !price_feed.is_writable {
+            solana_program::msg!(concat!(stringify!(price_feed), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*price_feed.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            price_feed.owner,
+            &__self_program_id__,
+            concat!(stringify!(price_feed), " owner"),
+        )?;
+        if !authority.is_signer {
+            return Err(texture_common::error::MissingSignature(*authority.key).into());
+        }
+        if !source_address.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(source_address), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*source_address.key).into(),
+            );
+        }
+        if !transform_source_address.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(transform_source_address), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*transform_source_address.key)
+                    .into(),
+            );
+        }
+        Ok(Self {
+            price_feed,
+            authority,
+            source_address,
+            transform_source_address,
+        })
+    }
+}
+///[PriceProxyInstruction::DeletePriceFeed] instruction account infos helper
+#[derive(Debug)]
+pub struct DeletePriceFeedAccounts<'a, 'i> {
+    ///Price-feed account to delete.
+    pub price_feed: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Price-feed update authority.
+    pub authority: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> DeletePriceFeedAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let price_feed = texture_common::utils::next_account_info(iter)?;
+        let authority = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !price_feed.is_writable {
+            solana_program::msg!(concat!(stringify!(price_feed), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*price_feed.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            price_feed.owner,
+            &__self_program_id__,
+            concat!(stringify!(price_feed), " owner"),
+        )?;
+        if !authority.is_signer {
+            return Err(texture_common::error::MissingSignature(*authority.key).into());
+        }
+        Ok(Self { price_feed, authority })
+    }
+}
+///[PriceProxyInstruction::Version] instruction account infos helper
+#[derive(Debug)]
+pub struct VersionAccounts<'a, 'i> {
+    ///System Program.
+    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> VersionAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let system_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            system_program.key,
+            &solana_program::system_program::ID,
+            stringify!(system_program),
+        )?;
+        Ok(Self { system_program })
+    }
+}
+///[PriceProxyInstruction::CreateCompositeFeed] instruction account infos helper
+#[derive(Debug)]
+pub struct CreateCompositeFeedAccounts<'a, 'i> {
+    ///CompositeFeed account to create.
+    pub composite_feed: &'a solana_program::account_info::AccountInfo<'i>,
+    ///CompositeFeed authority. Will fund account.
+    pub authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///System program.
+    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> CreateCompositeFeedAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let composite_feed = texture_common::utils::next_account_info(iter)?;
+        let authority = texture_common::utils::next_account_info(iter)?;
+        let system_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !composite_feed.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(composite_feed), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*composite_feed.key).into(),
+            );
+        }
+        if !composite_feed.is_signer {
+            return Err(
+                texture_common::error::MissingSignature(*composite_feed.key).into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            composite_feed.owner,
+            &solana_program::system_program::ID,
+            concat!(stringify!(composite_feed), " owner"),
+        )?;
+        if !authority.is_writable {
+            solana_program::msg!(concat!(stringify!(authority), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*authority.key).into());
+        }
+        if !authority.is_signer {
+            return Err(texture_common::error::MissingSignature(*authority.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            system_program.key,
+            &solana_program::system_program::ID,
+            stringify!(system_program),
+        )?;
         Ok(Self {
-            price_feed: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            source_address: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            transform_source_address: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
+            composite_feed,
+            authority,
+            system_program,
         })
     }
 }
-impl<'a> TryFrom<&'a [u8]> for UpdatePriceAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
-    }
-}
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for UpdatePriceAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
-    }
-}
-impl<const N: usize> TryFrom<[u8; N]> for UpdatePriceAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
-    }
-}
-impl TryFrom<Vec<u8>> for UpdatePriceAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
-    }
-}
-/// [PriceProxyInstruction::AlterPriceFeed] instruction account indexes helper
-#[derive(Debug, PartialEq)]
-pub struct AlterPriceFeedAccountIndexes {
-    pub price_feed: usize,
-    pub authority: usize,
-    pub source_address: usize,
-    pub transform_source_address: usize,
+///[PriceProxyInstruction::AlterCompositeFeed] instruction account infos helper
+#[derive(Debug)]
+pub struct AlterCompositeFeedAccounts<'a, 'i> {
+    ///CompositeFeed account to alter.
+    pub composite_feed: &'a solana_program::account_info::AccountInfo<'i>,
+    ///CompositeFeed authority.
+    pub authority: &'a solana_program::account_info::AccountInfo<'i>,
 }
-impl AlterPriceFeedAccountIndexes {
-    pub const COUNT: usize = 4usize;
-    pub const PRICE_FEED: usize = 0usize;
-    pub const AUTHORITY: usize = 1usize;
-    pub const SOURCE_ADDRESS: usize = 2usize;
-    pub const TRANSFORM_SOURCE_ADDRESS: usize = 3usize;
-    pub fn new_direct_order() -> Self {
-        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
-        Self {
-            price_feed: iter.next().unwrap(),
-            authority: iter.next().unwrap(),
-            source_address: iter.next().unwrap(),
-            transform_source_address: iter.next().unwrap(),
+impl<'a, 'i> AlterCompositeFeedAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let composite_feed = texture_common::utils::next_account_info(iter)?;
+        let authority = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !composite_feed.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(composite_feed), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*composite_feed.key).into(),
+            );
         }
-    }
-    pub fn try_from_indexes<'a>(
-        indexes: impl IntoIterator<Item = &'a u8>,
-    ) -> Result<Self, usize> {
-        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
-        let mut idx = 0_usize;
-        Ok(Self {
-            price_feed: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            authority: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            source_address: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            transform_source_address: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-        })
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            composite_feed.owner,
+            &__self_program_id__,
+            concat!(stringify!(composite_feed), " owner"),
+        )?;
+        if !authority.is_signer {
+            return Err(texture_common::error::MissingSignature(*authority.key).into());
+        }
+        Ok(Self { composite_feed, authority })
     }
 }
-impl<'a> TryFrom<&'a [u8]> for AlterPriceFeedAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
-    }
+///[PriceProxyInstruction::DeleteCompositeFeed] instruction account infos helper
+#[derive(Debug)]
+pub struct DeleteCompositeFeedAccounts<'a, 'i> {
+    ///CompositeFeed account to delete.
+    pub composite_feed: &'a solana_program::account_info::AccountInfo<'i>,
+    ///CompositeFeed authority.
+    pub authority: &'a solana_program::account_info::AccountInfo<'i>,
 }
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for AlterPriceFeedAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
+impl<'a, 'i> DeleteCompositeFeedAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let composite_feed = texture_common::utils::next_account_info(iter)?;
+        let authority = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !composite_feed.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(composite_feed), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*composite_feed.key).into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            composite_feed.owner,
+            &__self_program_id__,
+            concat!(stringify!(composite_feed), " owner"),
+        )?;
+        if !authority.is_signer {
+            return Err(texture_common::error::MissingSignature(*authority.key).into());
+        }
+        Ok(Self { composite_feed, authority })
     }
 }
-impl<const N: usize> TryFrom<[u8; N]> for AlterPriceFeedAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
-    }
+///[PriceProxyInstruction::OverrideCircuitBreaker] instruction account infos helper
+#[derive(Debug)]
+pub struct OverrideCircuitBreakerAccounts<'a, 'i> {
+    ///Price-feed account to override circuit breaker of.
+    pub price_feed: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Price-feed update authority.
+    pub authority: &'a solana_program::account_info::AccountInfo<'i>,
 }
-impl TryFrom<Vec<u8>> for AlterPriceFeedAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
+impl<'a, 'i> OverrideCircuitBreakerAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let price_feed = texture_common::utils::next_account_info(iter)?;
+        let authority = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !price_feed.is_writable {
+            solana_program::msg!(concat!(stringify!(price_feed), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*price_feed.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            price_feed.owner,
+            &__self_program_id__,
+            concat!(stringify!(price_feed), " owner"),
+        )?;
+        if !authority.is_signer {
+            return Err(texture_common::error::MissingSignature(*authority.key).into());
+        }
+        Ok(Self { price_feed, authority })
     }
 }
-/// [PriceProxyInstruction::DeletePriceFeed] instruction account indexes helper
-#[derive(Debug, PartialEq)]
-pub struct DeletePriceFeedAccountIndexes {
-    pub price_feed: usize,
-    pub authority: usize,
+///[PriceProxyInstruction::MigratePriceFeed] instruction account infos helper
+#[derive(Debug)]
+pub struct MigratePriceFeedAccounts<'a, 'i> {
+    ///Price-feed account to migrate.
+    pub price_feed: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Price-feed update authority. Will fund account.
+    pub authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///System program.
+    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
 }
-impl DeletePriceFeedAccountIndexes {
-    pub const COUNT: usize = 2usize;
-    pub const PRICE_FEED: usize = 0usize;
-    pub const AUTHORITY: usize = 1usize;
-    pub fn new_direct_order() -> Self {
-        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
-        Self {
-            price_feed: iter.next().unwrap(),
-            authority: iter.next().unwrap(),
+impl<'a, 'i> MigratePriceFeedAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let price_feed = texture_common::utils::next_account_info(iter)?;
+        let authority = texture_common::utils::next_account_info(iter)?;
+        let system_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !price_feed.is_writable {
+            solana_program::msg!(concat!(stringify!(price_feed), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*price_feed.key).into());
         }
-    }
-    pub fn try_from_indexes<'a>(
-        indexes: impl IntoIterator<Item = &'a u8>,
-    ) -> Result<Self, usize> {
-        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
-        let mut idx = 0_usize;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            price_feed.owner,
+            &__self_program_id__,
+            concat!(stringify!(price_feed), " owner"),
+        )?;
+        if !authority.is_writable {
+            solana_program::msg!(concat!(stringify!(authority), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*authority.key).into());
+        }
+        if !authority.is_signer {
+            return Err(texture_common::error::MissingSignature(*authority.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            system_program.key,
+            &solana_program::system_program::ID,
+            stringify!(system_program),
+        )?;
         Ok(Self {
-            price_feed: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            authority: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
+            price_feed,
+            authority,
+            system_program,
         })
     }
 }
-impl<'a> TryFrom<&'a [u8]> for DeletePriceFeedAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
-    }
-}
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for DeletePriceFeedAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
-    }
-}
-impl<const N: usize> TryFrom<[u8; N]> for DeletePriceFeedAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
-    }
+///[PriceProxyInstruction::GetTwap] instruction account infos helper
+#[derive(Debug)]
+pub struct GetTwapAccounts<'a, 'i> {
+    ///Price-feed account to get TWAP of.
+    pub price_feed: &'a solana_program::account_info::AccountInfo<'i>,
 }
-impl TryFrom<Vec<u8>> for DeletePriceFeedAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
+impl<'a, 'i> GetTwapAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let price_feed = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            price_feed.owner,
+            &__self_program_id__,
+            concat!(stringify!(price_feed), " owner"),
+        )?;
+        Ok(Self { price_feed })
     }
 }
-/// [PriceProxyInstruction::Version] instruction account indexes helper
-#[derive(Debug, PartialEq)]
-pub struct VersionAccountIndexes {
-    pub system_program: usize,
+///[PriceProxyInstruction::CreatePriceHistory] instruction account infos helper
+#[derive(Debug)]
+pub struct CreatePriceHistoryAccounts<'a, 'i> {
+    ///PriceHistory account to create.
+    pub price_history: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Price-feed to record prices of.
+    pub price_feed: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Price-feed update authority. Will fund account.
+    pub authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///System program.
+    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
 }
-impl VersionAccountIndexes {
-    pub const COUNT: usize = 1usize;
-    pub const SYSTEM_PROGRAM: usize = 0usize;
-    pub fn new_direct_order() -> Self {
-        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
-        Self {
-            system_program: iter.next().unwrap(),
+impl<'a, 'i> CreatePriceHistoryAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let price_history = texture_common::utils::next_account_info(iter)?;
+        let price_feed = texture_common::utils::next_account_info(iter)?;
+        let authority = texture_common::utils::next_account_info(iter)?;
+        let system_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !price_history.is_writable {
+            solana_program::msg!(concat!(stringify!(price_history), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*price_history.key).into());
         }
-    }
-    pub fn try_from_indexes<'a>(
-        indexes: impl IntoIterator<Item = &'a u8>,
-    ) -> Result<Self, usize> {
-        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
-        let mut idx = 0_usize;
+        if !price_history.is_signer {
+            return Err(
+                texture_common::error::MissingSignature(*price_history.key).into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            price_history.owner,
+            &solana_program::system_program::ID,
+            concat!(stringify!(price_history), " owner"),
+        )?;
+        if !price_feed.is_writable {
+            solana_program::msg!(concat!(stringify!(price_feed), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*price_feed.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            price_feed.owner,
+            &__self_program_id__,
+            concat!(stringify!(price_feed), " owner"),
+        )?;
+        if !authority.is_writable {
+            solana_program::msg!(concat!(stringify!(authority), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*authority.key).into());
+        }
+        if !authority.is_signer {
+            return Err(texture_common::error::MissingSignature(*authority.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            system_program.key,
+            &solana_program::system_program::ID,
+            stringify!(system_program),
+        )?;
         Ok(Self {
-            system_program: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
+            price_history,
+            price_feed,
+            authority,
+            system_program,
         })
     }
 }
-impl<'a> TryFrom<&'a [u8]> for VersionAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
-    }
-}
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for VersionAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
-    }
-}
-impl<const N: usize> TryFrom<[u8; N]> for VersionAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
-    }
+///[PriceProxyInstruction::DeletePriceHistory] instruction account infos helper
+#[derive(Debug)]
+pub struct DeletePriceHistoryAccounts<'a, 'i> {
+    ///PriceHistory account to delete.
+    pub price_history: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Price-feed the history belongs to.
+    pub price_feed: &'a solana_program::account_info::AccountInfo<'i>,
+    ///PriceHistory authority. Will receive rent.
+    pub authority: &'a solana_program::account_info::AccountInfo<'i>,
 }
-impl TryFrom<Vec<u8>> for VersionAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
+impl<'a, 'i> DeletePriceHistoryAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let price_history = texture_common::utils::next_account_info(iter)?;
+        let price_feed = texture_common::utils::next_account_info(iter)?;
+        let authority = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !price_history.is_writable {
+            solana_program::msg!(concat!(stringify!(price_history), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*price_history.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            price_history.owner,
+            &__self_program_id__,
+            concat!(stringify!(price_history), " owner"),
+        )?;
+        if !price_feed.is_writable {
+            solana_program::msg!(concat!(stringify!(price_feed), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*price_feed.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            price_feed.owner,
+            &__self_program_id__,
+            concat!(stringify!(price_feed), " owner"),
+        )?;
+        if !authority.is_writable {
+            solana_program::msg!(concat!(stringify!(authority), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*authority.key).into());
+        }
+        if !authority.is_signer {
+            return Err(texture_common::error::MissingSignature(*authority.key).into());
+        }
+        Ok(Self {
+            price_history,
+            price_feed,
+            authority,
+        })
     }
 }
-///[PriceProxyInstruction::CreatePriceFeed] instruction account infos helper
+///[PriceProxyInstruction::CreatePriceFeedPda] instruction account infos helper
 #[derive(Debug)]
-pub struct CreatePriceFeedAccounts<'a, 'i> {
-    ///Price-feed account to create.
+pub struct CreatePriceFeedPdaAccounts<'a, 'i> {
+    ///Price-feed PDA to create.
     pub price_feed: &'a solana_program::account_info::AccountInfo<'i>,
     ///Price-feed update authority. Will fund account.
     pub authority: &'a solana_program::account_info::AccountInfo<'i>,
-    ///When source is Pyth - pass feed address. Get Price Feed ID in hex from https://pyth.network/developers/price-feed-ids#solana-mainnet-beta. After use cli::get_feed_id_from_hex to convert to pubkey.
-    ///When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet.
-    ///When source is StakePool - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.
-    ///When source is Superlendy - pass reserve address.
-    ///When source is OffChain - pass `authority`.
+    ///The same as `source_address` of CreatePriceFeed.
     pub source_address: &'a solana_program::account_info::AccountInfo<'i>,
-    ///When source is Pyth - pass feed address. Get Price Feed ID in hex from https://pyth.network/developers/price-feed-ids#solana-mainnet-beta. After use cli::get_feed_id_from_hex to convert to pubkey.
-    ///When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet.
-    ///When source is StakePool - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.
-    ///When source is Superlendy - pass reserve address.
-    ///When source is OffChain - pass `authority`.
-    ///For Transform feed type only. If type is Direct - pass source_address here.
+    ///The same as `transform_source_address` of CreatePriceFeed.
     pub transform_source_address: &'a solana_program::account_info::AccountInfo<'i>,
     ///System program.
     pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
 }
-impl<'a, 'i> CreatePriceFeedAccounts<'a, 'i> {
+impl<'a, 'i> CreatePriceFeedPdaAccounts<'a, 'i> {
     pub fn from_iter<I>(
         iter: &mut I,
         program_id: &solana_program::pubkey::Pubkey,
@@ -740,9 +3199,6 @@ impl<'a, 'i> CreatePriceFeedAccounts<'a, 'i> {
             solana_program::msg!(concat!(stringify!(price_feed), " is not writable"));
             return Err(texture_common::error::InvalidAccount(*price_feed.key).into());
         }
-        if !price_feed.is_signer {
-            return Err(texture_common::error::MissingSignature(*price_feed.key).into());
-        }
         #[allow(clippy::needless_borrow)]
         texture_common::utils::verify_key(
             price_feed.owner,
@@ -782,21 +3238,69 @@ impl<'a, 'i> CreatePriceFeedAccounts<'a, 'i> {
         Ok(Self {
             price_feed,
             authority,
-            source_address,
-            transform_source_address,
-            system_program,
+            source_address,
+            transform_source_address,
+            system_program,
+        })
+    }
+}
+///[PriceProxyInstruction::ProposeAuthority] instruction account infos helper
+#[derive(Debug)]
+pub struct ProposeAuthorityAccounts<'a, 'i> {
+    ///Price-feed account to transfer.
+    pub price_feed: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Price-feed update authority.
+    pub authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Proposed update authority.
+    pub new_authority: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> ProposeAuthorityAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let price_feed = texture_common::utils::next_account_info(iter)?;
+        let authority = texture_common::utils::next_account_info(iter)?;
+        let new_authority = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !price_feed.is_writable {
+            solana_program::msg!(concat!(stringify!(price_feed), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*price_feed.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            price_feed.owner,
+            &__self_program_id__,
+            concat!(stringify!(price_feed), " owner"),
+        )?;
+        if !authority.is_signer {
+            return Err(texture_common::error::MissingSignature(*authority.key).into());
+        }
+        Ok(Self {
+            price_feed,
+            authority,
+            new_authority,
         })
     }
 }
-///[PriceProxyInstruction::WritePrice] instruction account infos helper
+///[PriceProxyInstruction::AcceptAuthority] instruction account infos helper
 #[derive(Debug)]
-pub struct WritePriceAccounts<'a, 'i> {
-    ///Price-feed account for update.
+pub struct AcceptAuthorityAccounts<'a, 'i> {
+    ///Price-feed account to accept.
     pub price_feed: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Price-feed update authority.
-    pub authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Pending update authority of Price-feed.
+    pub new_authority: &'a solana_program::account_info::AccountInfo<'i>,
 }
-impl<'a, 'i> WritePriceAccounts<'a, 'i> {
+impl<'a, 'i> AcceptAuthorityAccounts<'a, 'i> {
     pub fn from_iter<I>(
         iter: &mut I,
         program_id: &solana_program::pubkey::Pubkey,
@@ -806,7 +3310,7 @@ impl<'a, 'i> WritePriceAccounts<'a, 'i> {
     {
         let __self_program_id__ = program_id;
         let price_feed = texture_common::utils::next_account_info(iter)?;
-        let authority = texture_common::utils::next_account_info(iter)?;
+        let new_authority = texture_common::utils::next_account_info(iter)?;
         #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
         texture_common::utils::verify_key(
             __self_program_id__,
@@ -823,30 +3327,25 @@ impl<'a, 'i> WritePriceAccounts<'a, 'i> {
             &__self_program_id__,
             concat!(stringify!(price_feed), " owner"),
         )?;
-        if !authority.is_signer {
-            return Err(texture_common::error::MissingSignature(*authority.key).into());
+        if !new_authority.is_signer {
+            return Err(
+                texture_common::error::MissingSignature(*new_authority.key).into(),
+            );
         }
-        Ok(Self { price_feed, authority })
+        Ok(Self { price_feed, new_authority })
     }
 }
-///[PriceProxyInstruction::UpdatePrice] instruction account infos helper
+///[PriceProxyInstruction::AddWriter] instruction account infos helper
 #[derive(Debug)]
-pub struct UpdatePriceAccounts<'a, 'i> {
-    ///Price-feed account to update.
+pub struct AddWriterAccounts<'a, 'i> {
+    ///Price-feed account to add writer to.
     pub price_feed: &'a solana_program::account_info::AccountInfo<'i>,
-    ///When source is Pyth - pass PriceUpdate acc address, created by Pyth` PostUpdate ix.
-    ///When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet.
-    ///When source is StakePool - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.
-    ///When source is Superlendy - pass reserve address.
-    pub source_address: &'a solana_program::account_info::AccountInfo<'i>,
-    ///When source is Pyth - pass PriceUpdate acc address, created by Pyth` PostUpdate ix.
-    ///When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet.
-    ///When source is StakePool - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.
-    ///When source is Superlendy - pass reserve address.
-    ///For Transform feed type only. If type is Direct - pass source_address here.
-    pub transform_source_address: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Price-feed update authority.
+    pub authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Writer to authorize.
+    pub writer: &'a solana_program::account_info::AccountInfo<'i>,
 }
-impl<'a, 'i> UpdatePriceAccounts<'a, 'i> {
+impl<'a, 'i> AddWriterAccounts<'a, 'i> {
     pub fn from_iter<I>(
         iter: &mut I,
         program_id: &solana_program::pubkey::Pubkey,
@@ -856,8 +3355,8 @@ impl<'a, 'i> UpdatePriceAccounts<'a, 'i> {
     {
         let __self_program_id__ = program_id;
         let price_feed = texture_common::utils::next_account_info(iter)?;
-        let source_address = texture_common::utils::next_account_info(iter)?;
-        let transform_source_address = texture_common::utils::next_account_info(iter)?;
+        let authority = texture_common::utils::next_account_info(iter)?;
+        let writer = texture_common::utils::next_account_info(iter)?;
         #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
         texture_common::utils::verify_key(
             __self_program_id__,
@@ -874,52 +3373,27 @@ impl<'a, 'i> UpdatePriceAccounts<'a, 'i> {
             &__self_program_id__,
             concat!(stringify!(price_feed), " owner"),
         )?;
-        if !source_address.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(source_address), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*source_address.key).into(),
-            );
-        }
-        if !transform_source_address.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(transform_source_address), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*transform_source_address.key)
-                    .into(),
-            );
+        if !authority.is_signer {
+            return Err(texture_common::error::MissingSignature(*authority.key).into());
         }
         Ok(Self {
             price_feed,
-            source_address,
-            transform_source_address,
+            authority,
+            writer,
         })
     }
 }
-///[PriceProxyInstruction::AlterPriceFeed] instruction account infos helper
+///[PriceProxyInstruction::RemoveWriter] instruction account infos helper
 #[derive(Debug)]
-pub struct AlterPriceFeedAccounts<'a, 'i> {
-    ///Price-feed account to create.
+pub struct RemoveWriterAccounts<'a, 'i> {
+    ///Price-feed account to remove writer from.
     pub price_feed: &'a solana_program::account_info::AccountInfo<'i>,
     ///Price-feed update authority.
     pub authority: &'a solana_program::account_info::AccountInfo<'i>,
-    ///When source is Pyth - pass feed address. Get Price Feed ID in hex from https://pyth.network/developers/price-feed-ids#solana-mainnet-beta. After use cli::get_feed_id_from_hex to convert to pubkey.
-    ///When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet.
-    ///When source is StakePool - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.
-    ///When source is Superlendy - pass reserve address.
-    ///When source is OffChain - pass `authority`.
-    pub source_address: &'a solana_program::account_info::AccountInfo<'i>,
-    ///When source is Pyth - pass feed address. Get Price Feed ID in hex from https://pyth.network/developers/price-feed-ids#solana-mainnet-beta. After use cli::get_feed_id_from_hex to convert to pubkey.
-    ///When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet.
-    ///When source is StakePool - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.
-    ///When source is Superlendy - pass reserve address.
-    ///When source is OffChain - pass `authority`.
-    ///For Transform feed type only. If type is Direct - pass source_address here.
-    pub transform_source_address: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Writer to revoke.
+    pub writer: &'a solana_program::account_info::AccountInfo<'i>,
 }
-impl<'a, 'i> AlterPriceFeedAccounts<'a, 'i> {
+impl<'a, 'i> RemoveWriterAccounts<'a, 'i> {
     pub fn from_iter<I>(
         iter: &mut I,
         program_id: &solana_program::pubkey::Pubkey,
@@ -930,8 +3404,7 @@ impl<'a, 'i> AlterPriceFeedAccounts<'a, 'i> {
         let __self_program_id__ = program_id;
         let price_feed = texture_common::utils::next_account_info(iter)?;
         let authority = texture_common::utils::next_account_info(iter)?;
-        let source_address = texture_common::utils::next_account_info(iter)?;
-        let transform_source_address = texture_common::utils::next_account_info(iter)?;
+        let writer = texture_common::utils::next_account_info(iter)?;
         #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
         texture_common::utils::verify_key(
             __self_program_id__,
@@ -951,40 +3424,22 @@ impl<'a, 'i> AlterPriceFeedAccounts<'a, 'i> {
         if !authority.is_signer {
             return Err(texture_common::error::MissingSignature(*authority.key).into());
         }
-        if !source_address.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(source_address), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*source_address.key).into(),
-            );
-        }
-        if !transform_source_address.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(transform_source_address), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*transform_source_address.key)
-                    .into(),
-            );
-        }
         Ok(Self {
             price_feed,
             authority,
-            source_address,
-            transform_source_address,
+            writer,
         })
     }
 }
-///[PriceProxyInstruction::DeletePriceFeed] instruction account infos helper
+///[PriceProxyInstruction::WriteSignedPrice] instruction account infos helper
 #[derive(Debug)]
-pub struct DeletePriceFeedAccounts<'a, 'i> {
-    ///Price-feed account to delete.
+pub struct WriteSignedPriceAccounts<'a, 'i> {
+    ///Price-feed account for update.
     pub price_feed: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Price-feed update authority.
-    pub authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Instructions sysvar.
+    pub instructions_sysvar: &'a solana_program::account_info::AccountInfo<'i>,
 }
-impl<'a, 'i> DeletePriceFeedAccounts<'a, 'i> {
+impl<'a, 'i> WriteSignedPriceAccounts<'a, 'i> {
     pub fn from_iter<I>(
         iter: &mut I,
         program_id: &solana_program::pubkey::Pubkey,
@@ -994,7 +3449,7 @@ impl<'a, 'i> DeletePriceFeedAccounts<'a, 'i> {
     {
         let __self_program_id__ = program_id;
         let price_feed = texture_common::utils::next_account_info(iter)?;
-        let authority = texture_common::utils::next_account_info(iter)?;
+        let instructions_sysvar = texture_common::utils::next_account_info(iter)?;
         #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
         texture_common::utils::verify_key(
             __self_program_id__,
@@ -1011,19 +3466,18 @@ impl<'a, 'i> DeletePriceFeedAccounts<'a, 'i> {
             &__self_program_id__,
             concat!(stringify!(price_feed), " owner"),
         )?;
-        if !authority.is_signer {
-            return Err(texture_common::error::MissingSignature(*authority.key).into());
-        }
-        Ok(Self { price_feed, authority })
+        Ok(Self { price_feed, instructions_sysvar })
     }
 }
-///[PriceProxyInstruction::Version] instruction account infos helper
+///[PriceProxyInstruction::WritePriceWithConfidence] instruction account infos helper
 #[derive(Debug)]
-pub struct VersionAccounts<'a, 'i> {
-    ///System Program.
-    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+pub struct WritePriceWithConfidenceAccounts<'a, 'i> {
+    ///Price-feed account for update.
+    pub price_feed: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Writer of Price-feed, see AddWriter.
+    pub authority: &'a solana_program::account_info::AccountInfo<'i>,
 }
-impl<'a, 'i> VersionAccounts<'a, 'i> {
+impl<'a, 'i> WritePriceWithConfidenceAccounts<'a, 'i> {
     pub fn from_iter<I>(
         iter: &mut I,
         program_id: &solana_program::pubkey::Pubkey,
@@ -1032,20 +3486,28 @@ impl<'a, 'i> VersionAccounts<'a, 'i> {
         I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
     {
         let __self_program_id__ = program_id;
-        let system_program = texture_common::utils::next_account_info(iter)?;
+        let price_feed = texture_common::utils::next_account_info(iter)?;
+        let authority = texture_common::utils::next_account_info(iter)?;
         #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
         texture_common::utils::verify_key(
             __self_program_id__,
             &crate::ID,
             "self_program_id",
         )?;
+        if !price_feed.is_writable {
+            solana_program::msg!(concat!(stringify!(price_feed), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*price_feed.key).into());
+        }
         #[allow(clippy::needless_borrow)]
         texture_common::utils::verify_key(
-            system_program.key,
-            &solana_program::system_program::ID,
-            stringify!(system_program),
+            price_feed.owner,
+            &__self_program_id__,
+            concat!(stringify!(price_feed), " owner"),
         )?;
-        Ok(Self { system_program })
+        if !authority.is_signer {
+            return Err(texture_common::error::MissingSignature(*authority.key).into());
+        }
+        Ok(Self { price_feed, authority })
     }
 }
 pub(crate) mod ix_docs {
@@ -1059,18 +3521,24 @@ pub(crate) mod ix_docs {
             "When source is Pyth - pass feed address. Get Price Feed ID in hex from https://pyth.network/developers/price-feed-ids#solana-mainnet-beta. After use cli::get_feed_id_from_hex to convert to pubkey.",
             "\n",
             "When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet.",
-            "\n",
-            "When source is StakePool - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.",
-            "\n", "When source is Superlendy - pass reserve address.", "\n",
-            "When source is OffChain - pass `authority`.", "\n", " ", "\n", "<b><i>",
-            "3", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "\n", "When source is SwitchboardOnDemand - pass pull feed address.", "\n",
+            "When source is StakePool or StakePoolNetOfFee - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.",
+            "\n", "When source is Marinade - pass Marinade state address.", "\n",
+            "When source is SanctumSpl - pass Sanctum SPL stake pool address.", "\n",
+            "When source is Superlendy - pass reserve address.", "\n",
+            "When source is Chainlink - pass store feed (transmissions) account address.",
+            "\n", "When source is OffChain - pass `authority`.", "\n", " ", "\n",
+            "<b><i>", "3", "</i></b>. <b>", "\\[writable\\]", "</b> ",
             "When source is Pyth - pass feed address. Get Price Feed ID in hex from https://pyth.network/developers/price-feed-ids#solana-mainnet-beta. After use cli::get_feed_id_from_hex to convert to pubkey.",
             "\n",
             "When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet.",
-            "\n",
-            "When source is StakePool - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.",
-            "\n", "When source is Superlendy - pass reserve address.", "\n",
-            "When source is OffChain - pass `authority`.", "\n",
+            "\n", "When source is SwitchboardOnDemand - pass pull feed address.", "\n",
+            "When source is StakePool or StakePoolNetOfFee - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.",
+            "\n", "When source is Marinade - pass Marinade state address.", "\n",
+            "When source is SanctumSpl - pass Sanctum SPL stake pool address.", "\n",
+            "When source is Superlendy - pass reserve address.", "\n",
+            "When source is Chainlink - pass store feed (transmissions) account address.",
+            "\n", "When source is OffChain - pass `authority`.", "\n",
             "For Transform feed type only. If type is Direct - pass source_address here.",
             "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>", "\\[\\]", "</b> ",
             "System program.", "\n", "\n", " ## Usage", "\n", " ",
@@ -1090,7 +3558,7 @@ pub(crate) mod ix_docs {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
             "\\[writable\\]", "</b> ", "Price-feed account for update.", "\n", " ", "\n",
             "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ",
-            "Price-feed update authority.", "\n", "\n", " ## Usage", "\n", " ",
+            "Writer of Price-feed, see AddWriter.", "\n", "\n", " ## Usage", "\n", " ",
             "For create instruction use builder struct [WritePrice]", " ",
             "(method [into_instruction][WritePrice::into_instruction]).", " ", "\n\n",
             " ",
@@ -1109,17 +3577,26 @@ pub(crate) mod ix_docs {
             "When source is Pyth - pass PriceUpdate acc address, created by Pyth` PostUpdate ix.",
             "\n",
             "When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet.",
+            "\n", "When source is SwitchboardOnDemand - pass pull feed address.", "\n",
+            "When source is StakePool or StakePoolNetOfFee - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.",
+            "\n", "When source is Marinade - pass Marinade state address.", "\n",
+            "When source is SanctumSpl - pass Sanctum SPL stake pool address.", "\n",
+            "When source is Superlendy - pass reserve address.", "\n",
+            "When source is Chainlink - pass store feed (transmissions) account address.",
             "\n",
-            "When source is StakePool - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.",
-            "\n", "When source is Superlendy - pass reserve address.", "\n", " ", "\n",
-            "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "When feed type is Composite or Median - pass CompositeFeed address. Source accounts of its legs follow as remaining accounts in order of legs.",
+            "\n", " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
             "When source is Pyth - pass PriceUpdate acc address, created by Pyth` PostUpdate ix.",
             "\n",
             "When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet.",
+            "\n", "When source is SwitchboardOnDemand - pass pull feed address.", "\n",
+            "When source is StakePool or StakePoolNetOfFee - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.",
+            "\n", "When source is Marinade - pass Marinade state address.", "\n",
+            "When source is SanctumSpl - pass Sanctum SPL stake pool address.", "\n",
+            "When source is Superlendy - pass reserve address.", "\n",
+            "When source is Chainlink - pass store feed (transmissions) account address.",
             "\n",
-            "When source is StakePool - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.",
-            "\n", "When source is Superlendy - pass reserve address.", "\n",
-            "For Transform feed type only. If type is Direct - pass source_address here.",
+            "For Transform feed type only. If type is Direct, Composite or Median - pass source_address here.",
             "\n", "\n", " ## Usage", "\n", " ",
             "For create instruction use builder struct [UpdatePrice]", " ",
             "(method [into_instruction][UpdatePrice::into_instruction]).", " ", "\n\n",
@@ -1141,18 +3618,24 @@ pub(crate) mod ix_docs {
             "When source is Pyth - pass feed address. Get Price Feed ID in hex from https://pyth.network/developers/price-feed-ids#solana-mainnet-beta. After use cli::get_feed_id_from_hex to convert to pubkey.",
             "\n",
             "When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet.",
-            "\n",
-            "When source is StakePool - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.",
-            "\n", "When source is Superlendy - pass reserve address.", "\n",
-            "When source is OffChain - pass `authority`.", "\n", " ", "\n", "<b><i>",
-            "3", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "\n", "When source is SwitchboardOnDemand - pass pull feed address.", "\n",
+            "When source is StakePool or StakePoolNetOfFee - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.",
+            "\n", "When source is Marinade - pass Marinade state address.", "\n",
+            "When source is SanctumSpl - pass Sanctum SPL stake pool address.", "\n",
+            "When source is Superlendy - pass reserve address.", "\n",
+            "When source is Chainlink - pass store feed (transmissions) account address.",
+            "\n", "When source is OffChain - pass `authority`.", "\n", " ", "\n",
+            "<b><i>", "3", "</i></b>. <b>", "\\[writable\\]", "</b> ",
             "When source is Pyth - pass feed address. Get Price Feed ID in hex from https://pyth.network/developers/price-feed-ids#solana-mainnet-beta. After use cli::get_feed_id_from_hex to convert to pubkey.",
             "\n",
             "When source is Switchboard - pass feed address. Get from https://app.switchboard.xyz/solana/mainnet.",
-            "\n",
-            "When source is StakePool - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.",
-            "\n", "When source is Superlendy - pass reserve address.", "\n",
-            "When source is OffChain - pass `authority`.", "\n",
+            "\n", "When source is SwitchboardOnDemand - pass pull feed address.", "\n",
+            "When source is StakePool or StakePoolNetOfFee - pass feed address. Get from sanctum_lst_list.pool https://github.com/igneous-labs/sanctum-lst-list/blob/master/sanctum-lst-list.toml.",
+            "\n", "When source is Marinade - pass Marinade state address.", "\n",
+            "When source is SanctumSpl - pass Sanctum SPL stake pool address.", "\n",
+            "When source is Superlendy - pass reserve address.", "\n",
+            "When source is Chainlink - pass store feed (transmissions) account address.",
+            "\n", "When source is OffChain - pass `authority`.", "\n",
             "For Transform feed type only. If type is Direct - pass source_address here.",
             "\n", "\n", " ## Usage", "\n", " ",
             "For create instruction use builder struct [AlterPriceFeed]", " ",
@@ -1195,4 +3678,277 @@ pub(crate) mod ix_docs {
         };
     }
     pub(crate) use version;
+    macro_rules! create_composite_feed {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable, signer\\]", "</b> ", "CompositeFeed account to create.", "\n",
+            " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "CompositeFeed authority. Will fund account.", "\n", " ", "\n", "<b><i>",
+            "2", "</i></b>. <b>", "\\[\\]", "</b> ", "System program.", "\n", "\n",
+            " ## Usage", "\n", " ",
+            "For create instruction use builder struct [CreateCompositeFeed]", " ",
+            "(method [into_instruction][CreateCompositeFeed::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [CreateCompositeFeedAccounts]",
+            " ", "(method [from_iter][CreateCompositeFeedAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [CreateCompositeFeedAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use create_composite_feed;
+    macro_rules! alter_composite_feed {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "CompositeFeed account to alter.", "\n", " ",
+            "\n", "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "CompositeFeed authority.", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [AlterCompositeFeed]", " ",
+            "(method [into_instruction][AlterCompositeFeed::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [AlterCompositeFeedAccounts]",
+            " ", "(method [from_iter][AlterCompositeFeedAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [AlterCompositeFeedAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use alter_composite_feed;
+    macro_rules! delete_composite_feed {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "CompositeFeed account to delete.", "\n", " ",
+            "\n", "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "CompositeFeed authority.", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [DeleteCompositeFeed]", " ",
+            "(method [into_instruction][DeleteCompositeFeed::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [DeleteCompositeFeedAccounts]",
+            " ", "(method [from_iter][DeleteCompositeFeedAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [DeleteCompositeFeedAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use delete_composite_feed;
+    macro_rules! override_circuit_breaker {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ",
+            "Price-feed account to override circuit breaker of.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Price-feed update authority.", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [OverrideCircuitBreaker]", " ",
+            "(method [into_instruction][OverrideCircuitBreaker::into_instruction]).",
+            " ", "\n\n", " ",
+            "For parse accounts infos from processor use struct [OverrideCircuitBreakerAccounts]",
+            " ", "(method [from_iter][OverrideCircuitBreakerAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [OverrideCircuitBreakerAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use override_circuit_breaker;
+    macro_rules! migrate_price_feed {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Price-feed account to migrate.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Price-feed update authority. Will fund account.", "\n", " ", "\n", "<b><i>",
+            "2", "</i></b>. <b>", "\\[\\]", "</b> ", "System program.", "\n", "\n",
+            " ## Usage", "\n", " ",
+            "For create instruction use builder struct [MigratePriceFeed]", " ",
+            "(method [into_instruction][MigratePriceFeed::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [MigratePriceFeedAccounts]",
+            " ", "(method [from_iter][MigratePriceFeedAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [MigratePriceFeedAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use migrate_price_feed;
+    macro_rules! get_twap {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[\\]", "</b> ", "Price-feed account to get TWAP of.", "\n", "\n",
+            " ## Usage", "\n", " ",
+            "For create instruction use builder struct [GetTwap]", " ",
+            "(method [into_instruction][GetTwap::into_instruction]).", " ", "\n\n", " ",
+            "For parse accounts infos from processor use struct [GetTwapAccounts]", " ",
+            "(method [from_iter][GetTwapAccounts::from_iter]).", " ", "\n\n", " ",
+            "For work with account indexes use struct [GetTwapAccountIndexes].", "\n", }
+        };
+    }
+    pub(crate) use get_twap;
+    macro_rules! create_price_history {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable, signer\\]", "</b> ", "PriceHistory account to create.", "\n",
+            " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Price-feed to record prices of.", "\n", " ", "\n", "<b><i>", "2",
+            "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Price-feed update authority. Will fund account.", "\n", " ", "\n", "<b><i>",
+            "3", "</i></b>. <b>", "\\[\\]", "</b> ", "System program.", "\n", "\n",
+            " ## Usage", "\n", " ",
+            "For create instruction use builder struct [CreatePriceHistory]", " ",
+            "(method [into_instruction][CreatePriceHistory::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [CreatePriceHistoryAccounts]",
+            " ", "(method [from_iter][CreatePriceHistoryAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [CreatePriceHistoryAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use create_price_history;
+    macro_rules! delete_price_history {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "PriceHistory account to delete.", "\n", " ",
+            "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Price-feed the history belongs to.", "\n", " ", "\n", "<b><i>", "2",
+            "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "PriceHistory authority. Will receive rent.", "\n", "\n", " ## Usage", "\n",
+            " ", "For create instruction use builder struct [DeletePriceHistory]", " ",
+            "(method [into_instruction][DeletePriceHistory::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [DeletePriceHistoryAccounts]",
+            " ", "(method [from_iter][DeletePriceHistoryAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [DeletePriceHistoryAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use delete_price_history;
+    macro_rules! create_price_feed_pda {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Price-feed PDA to create.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Price-feed update authority. Will fund account.", "\n", " ", "\n", "<b><i>",
+            "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "The same as `source_address` of CreatePriceFeed.", "\n", " ", "\n",
+            "<b><i>", "3", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "The same as `transform_source_address` of CreatePriceFeed.", "\n", " ",
+            "\n", "<b><i>", "4", "</i></b>. <b>", "\\[\\]", "</b> ", "System program.",
+            "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [CreatePriceFeedPda]", " ",
+            "(method [into_instruction][CreatePriceFeedPda::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [CreatePriceFeedPdaAccounts]",
+            " ", "(method [from_iter][CreatePriceFeedPdaAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [CreatePriceFeedPdaAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use create_price_feed_pda;
+    macro_rules! propose_authority {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Price-feed account to transfer.", "\n", " ",
+            "\n", "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Price-feed update authority.", "\n", " ", "\n", "<b><i>", "2",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "Proposed update authority.", "\n", "\n",
+            " ## Usage", "\n", " ",
+            "For create instruction use builder struct [ProposeAuthority]", " ",
+            "(method [into_instruction][ProposeAuthority::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [ProposeAuthorityAccounts]",
+            " ", "(method [from_iter][ProposeAuthorityAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [ProposeAuthorityAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use propose_authority;
+    macro_rules! accept_authority {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Price-feed account to accept.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Pending update authority of Price-feed.", "\n", "\n", " ## Usage", "\n",
+            " ", "For create instruction use builder struct [AcceptAuthority]", " ",
+            "(method [into_instruction][AcceptAuthority::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [AcceptAuthorityAccounts]",
+            " ", "(method [from_iter][AcceptAuthorityAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [AcceptAuthorityAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use accept_authority;
+    macro_rules! add_writer {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Price-feed account to add writer to.", "\n", " ",
+            "\n", "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Price-feed update authority.", "\n", " ", "\n", "<b><i>", "2",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "Writer to authorize.", "\n", "\n",
+            " ## Usage", "\n", " ",
+            "For create instruction use builder struct [AddWriter]", " ",
+            "(method [into_instruction][AddWriter::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [AddWriterAccounts]",
+            " ", "(method [from_iter][AddWriterAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [AddWriterAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use add_writer;
+    macro_rules! remove_writer {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Price-feed account to remove writer from.", "\n", " ",
+            "\n", "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Price-feed update authority.", "\n", " ", "\n", "<b><i>", "2",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "Writer to revoke.", "\n", "\n",
+            " ## Usage", "\n", " ",
+            "For create instruction use builder struct [RemoveWriter]", " ",
+            "(method [into_instruction][RemoveWriter::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [RemoveWriterAccounts]",
+            " ", "(method [from_iter][RemoveWriterAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [RemoveWriterAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use remove_writer;
+    macro_rules! write_signed_price {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Price-feed account for update.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Instructions sysvar.", "\n", "\n", " ## Usage", "\n",
+            " ", "For create instruction use builder struct [WriteSignedPrice]", " ",
+            "(method [into_instruction][WriteSignedPrice::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [WriteSignedPriceAccounts]",
+            " ", "(method [from_iter][WriteSignedPriceAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [WriteSignedPriceAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use write_signed_price;
+    macro_rules! write_price_with_confidence {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Price-feed account for update.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Writer of Price-feed, see AddWriter.", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [WritePriceWithConfidence]", " ",
+            "(method [into_instruction][WritePriceWithConfidence::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [WritePriceWithConfidenceAccounts]",
+            " ", "(method [from_iter][WritePriceWithConfidenceAccounts::from_iter]).", " ", "\n\n",
+            " ", "For work with account indexes use struct [WritePriceWithConfidenceAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use write_price_with_confidence;
 }
@@ -1,7 +1,9 @@
 pub mod error;
 pub mod instruction;
+pub mod pda;
 #[cfg(feature = "with-processor")]
 pub mod processor;
+pub mod signed_price;
 pub mod state;
 
 #[cfg(not(feature = "no-entrypoint"))]
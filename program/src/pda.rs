@@ -0,0 +1,77 @@
+use solana_program::pubkey::{Pubkey, MAX_SEED_LEN};
+
+use crate::state::price_feed::{QuoteSymbol, SYMBOL_MAX_SIZE};
+use crate::state::utils::str_to_array;
+
+/// Seed prefix of Price-feed derived from its symbol, quote symbol and authority.
+pub const PRICE_FEED_SEED: &[u8] = b"price-feed";
+
+/// Seed prefix of Price-feed derived from caller's seed and authority.
+pub const PRICE_FEED_CUSTOM_SEED: &[u8] = b"price-feed-seed";
+
+/// Maximum length of caller's seed of Price-feed address.
+pub const PRICE_FEED_SEED_MAX_LEN: usize = MAX_SEED_LEN;
+
+/// Seeds (without bump) of Price-feed address derived from `(symbol, quote_symbol, authority)`.
+pub fn price_feed_seeds<'a>(
+    symbol: &'a [u8; SYMBOL_MAX_SIZE],
+    quote_symbol: &'a [u8; 1],
+    authority: &'a Pubkey,
+) -> [&'a [u8]; 4] {
+    [PRICE_FEED_SEED, symbol, quote_symbol, authority.as_ref()]
+}
+
+/// Seeds (without bump) of Price-feed address derived from caller's `seed` and authority.
+pub fn price_feed_custom_seeds<'a>(seed: &'a [u8], authority: &'a Pubkey) -> [&'a [u8]; 3] {
+    [PRICE_FEED_CUSTOM_SEED, authority.as_ref(), seed]
+}
+
+/// Finds address and bump of Price-feed created by `CreatePriceFeedPda` without seed.
+pub fn find_price_feed_address(
+    symbol: &str,
+    quote_symbol: QuoteSymbol,
+    authority: &Pubkey,
+) -> (Pubkey, u8) {
+    find_price_feed_address_by_symbol(&str_to_array(symbol), quote_symbol, authority)
+}
+
+/// Same as `find_price_feed_address` with the symbol as stored in `PriceFeedParams`.
+pub fn find_price_feed_address_by_symbol(
+    symbol: &[u8; SYMBOL_MAX_SIZE],
+    quote_symbol: QuoteSymbol,
+    authority: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &price_feed_seeds(symbol, &[quote_symbol.into()], authority),
+        &crate::ID,
+    )
+}
+
+/// Finds address and bump of Price-feed created by `CreatePriceFeedPda` with `seed`.
+/// `seed` must not be longer than `PRICE_FEED_SEED_MAX_LEN`.
+pub fn find_price_feed_address_with_seed(seed: &[u8], authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&price_feed_custom_seeds(seed, authority), &crate::ID)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn addresses_differ() {
+        let authority = Pubkey::new_unique();
+        let (sol_usd, _) = find_price_feed_address("SOL", QuoteSymbol::USD, &authority);
+        let (sol_sol, _) = find_price_feed_address("SOL", QuoteSymbol::SOL, &authority);
+        let (sol_usd_other, _) =
+            find_price_feed_address("SOL", QuoteSymbol::USD, &Pubkey::new_unique());
+        let (with_seed, _) = find_price_feed_address_with_seed(b"SOL", &authority);
+
+        assert_ne!(sol_usd, sol_sol);
+        assert_ne!(sol_usd, sol_usd_other);
+        assert_ne!(sol_usd, with_seed);
+        assert_eq!(
+            find_price_feed_address("SOL", QuoteSymbol::USD, &authority).0,
+            sol_usd
+        );
+    }
+}
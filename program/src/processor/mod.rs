@@ -2,35 +2,59 @@ mod utils;
 
 use anchor_lang::AccountDeserialize;
 use borsh::BorshDeserialize;
+use pyth_solana_receiver_sdk::error::GetPriceError;
 use pyth_solana_receiver_sdk::price_update::{PriceUpdateV2, VerificationLevel};
+use pyth_solana_receiver_sdk::PYTH_PUSH_ORACLE_ID;
 use solana_program::account_info::AccountInfo;
 use solana_program::borsh1::try_from_slice_unchecked;
 use solana_program::clock::Clock;
 use solana_program::entrypoint::ProgramResult;
 use solana_program::msg;
+use solana_program::program::{invoke, set_return_data};
 use solana_program::program_error::ProgramError;
-use solana_program::pubkey::Pubkey;
+use solana_program::pubkey::{Pubkey, PubkeyError};
 use solana_program::rent::Rent;
-use solana_program::sysvar::Sysvar;
+use solana_program::system_instruction;
+use solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use solana_program::sysvar::{self, Sysvar};
 use super_lendy::state::reserve::Reserve;
-use switchboard_solana::{AggregatorAccountData, Key};
+use switchboard_solana::{AggregatorAccountData, Key, SWITCHBOARD_PROGRAM_ID};
 
 use texture_common::account::PodAccount;
-use texture_common::math::{CheckedDiv, CheckedMul, Decimal};
+use texture_common::math::{CheckedAdd, CheckedDiv, CheckedMul, Decimal};
 use texture_common::remote::system::SystemProgram;
 use texture_common::utils::verify_key;
 
-use crate::error::PriceProxyError;
 use crate::error::PriceProxyError::OperationCanNotBePerformed;
+use crate::error::{PriceProxyError, SerializeError};
 use crate::instruction::{
-    AlterPriceFeedAccounts, CreatePriceFeedAccounts, DeletePriceFeedAccounts,
-    PriceProxyInstruction, UpdatePriceAccounts, WritePriceAccounts,
+    AcceptAuthorityAccounts, AddWriterAccounts, AlterCompositeFeedAccounts, AlterPriceFeedAccounts,
+    CreateCompositeFeedAccounts, CreatePriceFeedAccounts, CreatePriceFeedPdaAccounts,
+    CreatePriceHistoryAccounts, DeleteCompositeFeedAccounts, DeletePriceFeedAccounts,
+    DeletePriceHistoryAccounts, GetTwapAccounts, MigratePriceFeedAccounts,
+    OverrideCircuitBreakerAccounts, PriceProxyInstruction, ProposeAuthorityAccounts,
+    RemoveWriterAccounts, UpdatePriceAccounts, WritePriceAccounts, WriteSignedPriceAccounts,
 };
-use crate::processor::utils::transfer_lamports;
+use crate::pda::{price_feed_custom_seeds, price_feed_seeds, PRICE_FEED_SEED_MAX_LEN};
+use crate::processor::utils::{create_pda_account, transfer_lamports};
+use crate::signed_price::{parse_ed25519_instruction, SignedPriceMessage};
+use crate::state::chainlink::{Transmissions, CHAINLINK_STORE_PROGRAM_ID};
+use crate::state::composite_feed::{CompositeFeed, CompositeLegParams, MAX_COMPOSITE_LEGS};
+use crate::state::marinade::{State as MarinadeState, MARINADE_PROGRAM_ID};
 use crate::state::price_feed::{
-    FeedType, PriceFeed, PriceFeedParams, PriceFeedSource, WormholeVerificationLevel,
+    price_bound_bits, ActiveSource, FeedType, PriceFeed, PriceFeedParams, PriceFeedSource,
+    TransformOp, WormholeVerificationLevel, MAX_WRITERS,
+};
+use crate::state::price_history::{PriceHistory, PriceObservation};
+use crate::state::stake_pool::{
+    StakePool, SANCTUM_SPL_MULTI_STAKE_POOL_PROGRAM_ID, SANCTUM_SPL_STAKE_POOL_PROGRAM_ID,
+    SPL_STAKE_POOL_PROGRAM_ID,
+};
+use crate::state::switchboard_on_demand::{
+    PullFeedAccountData, PRECISION, SWITCHBOARD_ON_DEMAND_PROGRAM_ID,
 };
-use crate::state::stake_pool::StakePool;
 use crate::PriceProxyResult;
 
 pub struct Processor<'a, 'b> {
@@ -52,12 +76,39 @@ impl<'a, 'b> Processor<'a, 'b> {
             PriceProxyInstruction::WritePrice {
                 price,
                 price_timestamp,
-            } => self.write_price(price, price_timestamp),
+            } => self.write_price(price, price_timestamp, None),
             PriceProxyInstruction::UpdatePrice { maximum_age_sec } => {
                 self.update_price(maximum_age_sec)
             }
             PriceProxyInstruction::AlterPriceFeed { params } => self.alter_price_feed(params),
             PriceProxyInstruction::DeletePriceFeed {} => self.delete_price_feed(),
+            PriceProxyInstruction::CreateCompositeFeed { legs, quorum } => {
+                self.create_composite_feed(legs, quorum)
+            }
+            PriceProxyInstruction::AlterCompositeFeed { legs, quorum } => {
+                self.alter_composite_feed(legs, quorum)
+            }
+            PriceProxyInstruction::DeleteCompositeFeed {} => self.delete_composite_feed(),
+            PriceProxyInstruction::OverrideCircuitBreaker { price } => {
+                self.override_circuit_breaker(price)
+            }
+            PriceProxyInstruction::MigratePriceFeed {} => self.migrate_price_feed(),
+            PriceProxyInstruction::GetTwap { window_sec } => self.get_twap(window_sec),
+            PriceProxyInstruction::CreatePriceHistory {} => self.create_price_history(),
+            PriceProxyInstruction::DeletePriceHistory {} => self.delete_price_history(),
+            PriceProxyInstruction::CreatePriceFeedPda { params, seed } => {
+                self.create_price_feed_pda(params, seed)
+            }
+            PriceProxyInstruction::ProposeAuthority {} => self.propose_authority(),
+            PriceProxyInstruction::AcceptAuthority {} => self.accept_authority(),
+            PriceProxyInstruction::AddWriter {} => self.add_writer(),
+            PriceProxyInstruction::RemoveWriter {} => self.remove_writer(),
+            PriceProxyInstruction::WriteSignedPrice {} => self.write_signed_price(),
+            PriceProxyInstruction::WritePriceWithConfidence {
+                price,
+                price_timestamp,
+                confidence,
+            } => self.write_price(price, price_timestamp, Some(confidence)),
             PriceProxyInstruction::Version => self.version(),
         }
     }
@@ -74,6 +125,9 @@ impl<'a, 'b> Processor<'a, 'b> {
             system_program,
         } = CreatePriceFeedAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
 
+        verify_price_bounds_params(&params)?;
+        verify_writer_quorum_params(&params)?;
+
         let rent = Rent::get().expect("No Rent");
 
         SystemProgram::new(system_program)
@@ -100,7 +154,76 @@ impl<'a, 'b> Processor<'a, 'b> {
         Ok(())
     }
 
-    fn write_price(&self, price: Decimal, price_timestamp: i64) -> Result<(), PriceProxyError> {
+    #[inline(never)]
+    pub(super) fn create_price_feed_pda(
+        self,
+        params: PriceFeedParams,
+        seed: Option<Vec<u8>>,
+    ) -> PriceProxyResult<()> {
+        msg!("create_price_feed_pda ix: {:?}, seed {:?}", params, seed);
+
+        let CreatePriceFeedPdaAccounts {
+            price_feed,
+            authority,
+            source_address,
+            transform_source_address,
+            system_program,
+        } = CreatePriceFeedPdaAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+
+        verify_price_bounds_params(&params)?;
+        verify_writer_quorum_params(&params)?;
+
+        let quote_symbol: [u8; 1] = [params.quote_symbol.into()];
+        let seeds: Vec<&[u8]> = match &seed {
+            Some(seed) => {
+                if seed.len() > PRICE_FEED_SEED_MAX_LEN {
+                    msg!("seed must be up to {} bytes", PRICE_FEED_SEED_MAX_LEN);
+                    return Err(PubkeyError::MaxSeedLengthExceeded.into());
+                }
+                price_feed_custom_seeds(seed, authority.key).to_vec()
+            }
+            None => price_feed_seeds(&params.symbol, &quote_symbol, authority.key).to_vec(),
+        };
+
+        let (expected_price_feed, bump) = Pubkey::find_program_address(&seeds, self.program_id);
+        verify_key(price_feed.key, &expected_price_feed, "price feed PDA")?;
+
+        let bump = [bump];
+        let mut signer_seeds = seeds;
+        signer_seeds.push(&bump);
+
+        let rent = Rent::get().expect("No Rent");
+
+        create_pda_account(
+            authority,
+            price_feed,
+            system_program,
+            PriceFeed::SIZE,
+            self.program_id,
+            &rent,
+            &signer_seeds,
+        )?;
+
+        let mut price_feed_data = price_feed.data.borrow_mut();
+        PriceFeed::init_bytes(
+            price_feed_data.as_mut(),
+            (
+                params,
+                *authority.key,
+                *source_address.key,
+                *transform_source_address.key,
+            ),
+        )?;
+
+        Ok(())
+    }
+
+    fn write_price(
+        &self,
+        price: Decimal,
+        price_timestamp: i64,
+        confidence: Option<Decimal>,
+    ) -> Result<(), PriceProxyError> {
         msg!("write_price ix: {}", price);
 
         let WritePriceAccounts {
@@ -113,20 +236,144 @@ impl<'a, 'b> Processor<'a, 'b> {
 
         verify_source(price_feed.source(), PriceFeedSource::OffChain)?;
 
-        verify_key(
+        if !price_feed.is_writer(authority_info.key) {
+            msg!("{} is not authorized to write price", authority_info.key);
+            return Err(PriceProxyError::UnauthorizedWriter(*authority_info.key));
+        }
+
+        self.apply_written_price(
+            price_feed_info.key,
+            price_feed,
             authority_info.key,
-            &price_feed.source_address,
-            "source authority",
+            price,
+            price_timestamp,
+            confidence,
+        )
+    }
+
+    fn write_signed_price(&self) -> PriceProxyResult<()> {
+        msg!("write_signed_price ix");
+
+        let WriteSignedPriceAccounts {
+            price_feed: price_feed_info,
+            instructions_sysvar,
+        } = WriteSignedPriceAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+
+        verify_key(
+            instructions_sysvar.key,
+            &sysvar::instructions::ID,
+            "instructions sysvar",
+        )?;
+
+        // Ed25519 program has verified the signature of the preceding instruction
+        let current_index = load_current_index_checked(instructions_sysvar)
+            .map_err(|_| PriceProxyError::InvalidSignedPrice)?;
+        let ed25519_ix = current_index
+            .checked_sub(1)
+            .and_then(|index| load_instruction_at_checked(index as usize, instructions_sysvar).ok())
+            .ok_or(PriceProxyError::InvalidSignedPrice)?;
+        let (signer, message) =
+            parse_ed25519_instruction(&ed25519_ix).ok_or(PriceProxyError::InvalidSignedPrice)?;
+        let message = SignedPriceMessage::try_from_bytes(message)
+            .ok_or(PriceProxyError::InvalidSignedPrice)?;
+
+        msg!("signed price {} by {}", message.price, signer);
+
+        verify_key(
+            price_feed_info.key,
+            &message.price_feed,
+            "signed price feed",
         )?;
 
+        let mut price_feed_data = price_feed_info.data.borrow_mut();
+        let price_feed = PriceFeed::try_from_bytes_mut(&mut price_feed_data)?;
+
+        verify_source(price_feed.source(), PriceFeedSource::OffChain)?;
+
+        // Nonce is tracked per writer slot, so signed prices require authorized writer
+        let Some(index) = price_feed.writer_index(&signer) else {
+            msg!("{} is not authorized to write signed price", signer);
+            return Err(PriceProxyError::UnauthorizedWriter(signer));
+        };
+
+        // Slot is reused by other writers and by the same writer added again, generation
+        // tells their signed prices apart
+        let submission = &mut price_feed.writer_submissions[index];
+        if message.generation != submission.generation {
+            return Err(PriceProxyError::SignedPriceGenerationMismatch {
+                generation: message.generation,
+                writer_generation: submission.generation,
+            });
+        }
+        if message.nonce <= submission.signed_price_nonce {
+            return Err(PriceProxyError::SignedPriceNonceUsed {
+                nonce: message.nonce,
+                last_nonce: submission.signed_price_nonce,
+            });
+        }
+        submission.signed_price_nonce = message.nonce;
+
+        self.apply_written_price(
+            price_feed_info.key,
+            price_feed,
+            &signer,
+            message.price,
+            message.price_timestamp,
+            None,
+        )
+    }
+
+    /// Checks and sets price written by authorized off-chain writer. Price of multi-writer feed
+    /// is the median of fresh writer submissions and stays unchanged until quorum is reached.
+    fn apply_written_price(
+        &self,
+        price_feed_key: &Pubkey,
+        price_feed: &mut PriceFeed,
+        writer: &Pubkey,
+        price: Decimal,
+        price_timestamp: i64,
+        confidence: Option<Decimal>,
+    ) -> PriceProxyResult<()> {
+        let clock = Clock::get().expect("clock");
+        verify_not_future(price_feed, price_timestamp, clock.unix_timestamp)?;
+
+        let (price, price_timestamp, confidence) = if price_feed.is_multi_writer() {
+            match submit_writer_price(
+                price_feed,
+                writer,
+                price,
+                price_timestamp,
+                clock.unix_timestamp,
+            )? {
+                Some(median) => (median.price, median.timestamp, Some(median.confidence)),
+                None => return Ok(()),
+            }
+        } else {
+            (price, price_timestamp, confidence)
+        };
+
         // NOTE: Strictly less so that we can update the timestamp
         // for the current price by simply setting the price and timestamp from account data
         if price_timestamp < price_feed.update_timestamp {
             return Err(PriceProxyError::TimestampIsNotRecent);
         }
 
-        let clock = Clock::get().expect("clock");
+        if price <= Decimal::ZERO {
+            msg!("Price {} must be positive", price);
+            return Err(PriceProxyError::NonPositivePrice(price));
+        }
+        verify_price_bounds(price_feed, price)?;
+        verify_deviation(price_feed, price, price_timestamp)?;
+
+        let confidence = confidence.unwrap_or(Decimal::ZERO);
+        update_ema(price_feed, price, confidence, price_timestamp)?;
+        price_feed.try_accumulate_twap(price_timestamp)?;
+
         price_feed.try_set_price(price, price_timestamp, clock.slot)?;
+        price_feed.try_set_confidence(confidence)?;
+        price_feed.set_active_source(ActiveSource::Primary);
+
+        self.append_price_history(price_feed_key, price_feed)?;
 
         Ok(())
     }
@@ -134,11 +381,12 @@ impl<'a, 'b> Processor<'a, 'b> {
     fn update_price(&self, maximum_age_sec: u64) -> Result<(), PriceProxyError> {
         msg!("update_price ix");
 
+        let mut accounts = self.accounts.iter();
         let UpdatePriceAccounts {
             price_feed,
             source_address,
             transform_source_address,
-        } = UpdatePriceAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+        } = UpdatePriceAccounts::from_iter(&mut accounts, self.program_id)?;
 
         let mut price_feed_data = price_feed.data.borrow_mut();
         let unpacked_price_feed = PriceFeed::try_from_bytes_mut(&mut price_feed_data)?;
@@ -150,31 +398,148 @@ impl<'a, 'b> Processor<'a, 'b> {
             return Err(PriceProxyError::OperationCanNotBePerformed);
         }
 
+        // Reference source account goes first of remaining accounts
+        let reference_address = if unpacked_price_feed.has_reference() {
+            Some(
+                accounts
+                    .next()
+                    .ok_or(PriceProxyError::ReferenceAccountMissing)?,
+            )
+        } else {
+            None
+        };
+
+        let clock = Clock::get().expect("clock");
+
         // Update
-        let (price, update_ts) = {
-            let (price, update_ts) = get_price_from_source_no_older_than(
+        let (
+            SourcePrice {
+                price,
+                confidence,
+                timestamp: update_ts,
+                ema,
+            },
+            active_source,
+        ) = match unpacked_price_feed.feed_type() {
+            FeedType::Direct => get_price_with_fallback(
                 unpacked_price_feed,
                 source_address,
+                &mut accounts,
                 maximum_age_sec,
-                FeedType::Direct,
-            )?;
-            if unpacked_price_feed.feed_type() == FeedType::Transform {
-                let (second_price, second_update_ts) = get_price_from_source_no_older_than(
+                clock.unix_timestamp,
+            )?,
+            FeedType::Transform => {
+                let (source_price, active_source) = get_price_with_fallback(
+                    unpacked_price_feed,
+                    source_address,
+                    &mut accounts,
+                    maximum_age_sec,
+                    clock.unix_timestamp,
+                )?;
+                let second_source_price = get_price_from_source_no_older_than(
                     unpacked_price_feed,
                     transform_source_address,
                     maximum_age_sec,
                     FeedType::Transform,
                 )?;
-                (
-                    price.checked_mul(second_price)?,
-                    update_ts.min(second_update_ts),
-                )
-            } else {
-                (price, update_ts)
+                let price = match unpacked_price_feed.transform_op() {
+                    TransformOp::Multiply => source_price.checked_mul(second_source_price)?,
+                    TransformOp::Divide => source_price.checked_div(second_source_price)?,
+                };
+                (price, active_source)
+            }
+            FeedType::Composite | FeedType::Median => {
+                // Legs are passed as remaining accounts
+                verify_key(
+                    source_address.key,
+                    &unpacked_price_feed.source_address,
+                    "composite feed",
+                )?;
+                if source_address.owner != self.program_id {
+                    msg!("Composite feed must be owned by the program");
+                    return Err(PriceProxyError::OwnerMismatch);
+                }
+                let composite_feed_data = source_address.data.borrow();
+                let composite_feed = CompositeFeed::try_from_bytes(&composite_feed_data)?;
+
+                let price = if unpacked_price_feed.feed_type() == FeedType::Median {
+                    get_median_price(
+                        composite_feed,
+                        unpacked_price_feed.verification_level(),
+                        &mut accounts,
+                        maximum_age_sec,
+                    )?
+                } else {
+                    get_composite_price(
+                        composite_feed,
+                        unpacked_price_feed.verification_level(),
+                        &mut accounts,
+                        maximum_age_sec,
+                    )?
+                };
+                (price, ActiveSource::Primary)
             }
         };
-        let clock = Clock::get().expect("clock");
+        // Older price update of the source, which is still within `maximum_age_sec`,
+        // must not roll the price back. Switching back from the fallback is compared with
+        // the last primary price instead: fallback sources without own timestamp are stamped
+        // with the clock, which fresh primary price may not have reached yet.
+        let is_failback = unpacked_price_feed.active_source() == ActiveSource::Fallback
+            && active_source == ActiveSource::Primary;
+        let latest_timestamp = if is_failback {
+            unpacked_price_feed.primary_timestamp
+        } else {
+            unpacked_price_feed.update_timestamp
+        };
+        if update_ts < latest_timestamp {
+            msg!(
+                "Source price timestamp {} is older than price-feed timestamp {}",
+                update_ts,
+                latest_timestamp
+            );
+            return Err(PriceProxyError::TimestampIsNotRecent);
+        }
+        verify_confidence(price, confidence, unpacked_price_feed.max_confidence_bps)?;
+
+        if let Some(reference_address) = reference_address {
+            let SourcePrice {
+                price: reference_price,
+                ..
+            } = get_price_from_source(
+                unpacked_price_feed.reference_source(),
+                unpacked_price_feed.reference_source_address,
+                unpacked_price_feed.verification_level(),
+                reference_address,
+                maximum_age_sec,
+            )?;
+            verify_divergence(
+                price,
+                reference_price,
+                unpacked_price_feed.max_divergence_bps,
+            )?;
+        }
+
+        verify_price_bounds(unpacked_price_feed, price)?;
+        verify_deviation(unpacked_price_feed, price, update_ts)?;
+
+        match ema {
+            Some(SourceEma { price, confidence }) => {
+                unpacked_price_feed.try_set_ema(price, confidence)?
+            }
+            None => update_ema(unpacked_price_feed, price, confidence, update_ts)?,
+        }
+        unpacked_price_feed.try_accumulate_twap(update_ts)?;
+
+        if active_source == ActiveSource::Fallback
+            && unpacked_price_feed.active_source() == ActiveSource::Primary
+        {
+            unpacked_price_feed.primary_timestamp = unpacked_price_feed.update_timestamp;
+        }
         unpacked_price_feed.try_set_price(price, update_ts, clock.slot)?;
+        unpacked_price_feed.try_set_confidence(confidence)?;
+        unpacked_price_feed.set_active_source(active_source);
+
+        self.append_price_history(price_feed.key, unpacked_price_feed)?;
 
         Ok(())
     }
@@ -198,6 +563,9 @@ impl<'a, 'b> Processor<'a, 'b> {
             "authority",
         )?;
 
+        verify_price_bounds_params(&params)?;
+        verify_writer_quorum_params(&params)?;
+
         let PriceFeedParams {
             feed_type,
             symbol,
@@ -206,6 +574,22 @@ impl<'a, 'b> Processor<'a, 'b> {
             source,
             verification_level,
             transform_source,
+            max_confidence_bps,
+            transform_op,
+            fallback_source,
+            fallback_source_address,
+            fallback_after_sec,
+            reference_source,
+            reference_source_address,
+            max_divergence_bps,
+            max_deviation_bps,
+            deviation_window_sec,
+            min_price,
+            max_price,
+            ema_half_life_sec,
+            writer_quorum,
+            submission_window_sec,
+            max_future_sec,
         } = params;
 
         price_feed.feed_type = feed_type as u8;
@@ -217,6 +601,21 @@ impl<'a, 'b> Processor<'a, 'b> {
         price_feed.source_address = source_address.key();
         price_feed.transform_source_address = transform_source_address.key();
         price_feed.verification_level = verification_level as u8;
+        price_feed.max_confidence_bps = max_confidence_bps;
+        price_feed.transform_op = transform_op as u8;
+        price_feed.fallback_source_raw = fallback_source as u8;
+        price_feed.fallback_source_address = fallback_source_address;
+        price_feed.fallback_after_sec = fallback_after_sec;
+        price_feed.reference_source_raw = reference_source as u8;
+        price_feed.reference_source_address = reference_source_address;
+        price_feed.max_divergence_bps = max_divergence_bps;
+        price_feed.max_deviation_bps = max_deviation_bps;
+        price_feed.deviation_window_sec = deviation_window_sec;
+        price_feed.try_set_price_bounds(min_price, max_price)?;
+        price_feed.ema_half_life_sec = ema_half_life_sec;
+        price_feed.writer_quorum = writer_quorum;
+        price_feed.submission_window_sec = submission_window_sec;
+        price_feed.max_future_sec = max_future_sec;
 
         Ok(())
     }
@@ -249,166 +648,1499 @@ impl<'a, 'b> Processor<'a, 'b> {
     }
 
     #[inline(never)]
-    pub(super) fn version(&self) -> Result<(), PriceProxyError> {
-        msg!(
-            "PriceProxy contract {}",
-            env!("CARGO_PKG_VERSION").to_string()
-        );
-        Err(OperationCanNotBePerformed)
-    }
-}
+    fn create_composite_feed(
+        &self,
+        legs: Vec<CompositeLegParams>,
+        quorum: u8,
+    ) -> PriceProxyResult<()> {
+        msg!("create_composite_feed ix: {:?}, quorum {}", legs, quorum);
 
-pub(crate) fn get_price_from_source_no_older_than(
-    price_feed: &mut PriceFeed,
-    source_address: &AccountInfo<'_>,
-    maximum_age_sec: u64,
-    feed_type: FeedType,
-) -> Result<(Decimal, i64), PriceProxyError> {
-    let (source, expected_source_address) = if feed_type == FeedType::Direct {
-        (price_feed.source(), price_feed.source_address)
-    } else {
-        (
-            price_feed.transform_source(),
-            price_feed.transform_source_address,
-        )
-    };
+        let CreateCompositeFeedAccounts {
+            composite_feed,
+            authority,
+            system_program,
+        } = CreateCompositeFeedAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
 
-    let mut source_data: &[u8] = &source_address.data.try_borrow_mut().expect("borrow mut");
+        verify_composite_legs(&legs, quorum)?;
 
-    // Update
-    let (price, update_ts) = match source {
-        PriceFeedSource::Pyth => {
-            let price_update = PriceUpdateV2::try_deserialize_unchecked(&mut source_data)
-                .expect("deserialize Pyth source address");
+        let rent = Rent::get().expect("No Rent");
 
-            // Check that PriceFeed's source_address and PriceUpdateV2's feed_id are equal
-            verify_key(
-                &Pubkey::try_from_slice(price_update.price_message.feed_id.as_slice())
-                    .expect("pubkey from feedId"),
-                &expected_source_address,
-                "source address",
-            )?;
-            let pyth_price = match price_feed.verification_level() {
-                WormholeVerificationLevel::Full => price_update
-                    .get_price_no_older_than(
-                        &Clock::get().expect("clock"),
-                        maximum_age_sec,
-                        &price_update.price_message.feed_id,
-                    )
-                    .expect("get Pyth price"),
-                WormholeVerificationLevel::Partial => {
-                    // Partially checks the Wormhole guardian signatures.
-                    // 5 signatures seems like the best it can currently do.
-                    let num_signatures = 5;
+        SystemProgram::new(system_program)
+            .create_account(
+                authority,
+                composite_feed,
+                CompositeFeed::SIZE as u64,
+                rent.minimum_balance(CompositeFeed::SIZE),
+                self.program_id,
+            )
+            .call()?;
 
-                    price_update
-                        .get_price_no_older_than_with_custom_verification_level(
-                            &Clock::get().expect("clock"),
-                            maximum_age_sec,
-                            &price_update.price_message.feed_id,
-                            VerificationLevel::Partial { num_signatures },
-                        )
-                        .expect("get Pyth price")
-                }
-            };
+        let mut composite_feed_data = composite_feed.data.borrow_mut();
+        CompositeFeed::init_bytes(composite_feed_data.as_mut(), (*authority.key, legs, quorum))?;
 
-            if pyth_price.price < 0 || pyth_price.exponent > 0 || pyth_price.exponent < -255 {
-                msg!("Invalid Pyth price or exponent");
-                return Err(PriceProxyError::InvalidPriceOrExpo);
-            }
-            (
-                Decimal::from_i128_with_scale(
-                    pyth_price.price as i128,
-                    pyth_price.exponent.unsigned_abs(),
-                )?,
-                pyth_price.publish_time,
-            )
-        }
-        PriceFeedSource::Switchboard => {
-            // Check that PriceFeed's source_address and provided source_address are equal
-            verify_key(
-                source_address.key,
-                &expected_source_address,
-                "source address",
-            )?;
-            let data_feed = AggregatorAccountData::new_from_bytes(source_data)
-                .expect("deserialize Switchboard feed");
+        Ok(())
+    }
 
-            data_feed
-                .check_staleness(Clock::get().unwrap().unix_timestamp, maximum_age_sec as i64)
-                .expect("price is stale");
+    fn alter_composite_feed(
+        &self,
+        legs: Vec<CompositeLegParams>,
+        quorum: u8,
+    ) -> PriceProxyResult<()> {
+        msg!("alter_composite_feed ix: {:?}, quorum {}", legs, quorum);
 
-            let feed_result = data_feed.get_result().expect("get Switchboard price");
-            (
-                Decimal::from_i128_with_scale(feed_result.mantissa, feed_result.scale)?,
-                data_feed.latest_confirmed_round.round_open_timestamp,
-            )
-        }
-        PriceFeedSource::SuperLendy => {
-            // Check that PriceFeed's source_address and provided source_address are equal
-            verify_key(
-                source_address.key,
-                &expected_source_address,
-                "source address",
-            )?;
-            let reserve_data = Reserve::try_from_bytes(source_data)?;
+        let AlterCompositeFeedAccounts {
+            composite_feed: composite_feed_info,
+            authority: authority_info,
+        } = AlterCompositeFeedAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
 
-            let unix_tx = Clock::get().unwrap().unix_timestamp;
-            let staleness = (unix_tx - reserve_data.last_update.timestamp) as u64;
-            if staleness > maximum_age_sec {
-                msg!("Feed has not been updated in {} seconds!", staleness);
-                return Err(PriceProxyError::StaleFeed(staleness));
-            }
-            if reserve_data.last_update.stale > 0 {
-                msg!("Reserve is stale and needs to be refreshed prior to get LP market price from it");
-                return Err(PriceProxyError::StaleFeed(0));
-            }
+        let mut composite_feed_data = composite_feed_info.data.borrow_mut();
+        let composite_feed = CompositeFeed::try_from_bytes_mut(&mut composite_feed_data)?;
 
-            (
-                reserve_data.lp_market_price().expect("get lp market price"),
-                reserve_data.last_update.timestamp,
-            )
-        }
-        PriceFeedSource::StakePool => {
-            // Check that PriceFeed's source_address and provided source_address are equal
-            verify_key(
-                source_address.key,
-                &expected_source_address,
-                "source address",
-            )?;
+        verify_key(authority_info.key, &composite_feed.authority, "authority")?;
+        verify_composite_legs(&legs, quorum)?;
 
-            let data_feed = try_from_slice_unchecked::<StakePool>(source_data)?;
-            let feed_epoch = data_feed.last_update_epoch;
-            let clock = Clock::get().unwrap();
-            let curr_epoch = clock.epoch;
+        composite_feed.set_legs(&legs);
+        composite_feed.quorum = quorum;
 
-            if curr_epoch > feed_epoch {
-                let staleness = curr_epoch - feed_epoch;
-                if staleness > 1 {
-                    msg!("Feed has not been updated in {} epoch!", staleness);
-                    return Err(PriceProxyError::StaleFeed(staleness));
-                }
-            }
+        Ok(())
+    }
 
-            let lst_price =
-                Decimal::from_i128_with_scale(data_feed.total_lamports as i128, 0)?.checked_div(
-                    Decimal::from_i128_with_scale(data_feed.pool_token_supply as i128, 0)?,
-                )?;
+    fn delete_composite_feed(&self) -> PriceProxyResult<()> {
+        msg!("delete_composite_feed ix");
 
-            (lst_price, clock.unix_timestamp)
-        }
-        other => {
-            msg!(
-                "Price source must be Pyth, Switchboard, StakePool or SuperLendy only, given {}",
-                other.to_string()
-            );
-            return Err(PriceProxyError::InvalidSource {
-                current: other,
-                expected: PriceFeedSource::Unknown,
-            });
+        let DeleteCompositeFeedAccounts {
+            composite_feed: composite_feed_info,
+            authority: authority_info,
+        } = DeleteCompositeFeedAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+
+        let mut composite_feed_data = composite_feed_info.data.borrow_mut();
+        let composite_feed = CompositeFeed::try_from_bytes_mut(&mut composite_feed_data)?;
+
+        verify_key(authority_info.key, &composite_feed.authority, "authority")?;
+
+        let balance = {
+            let lamports_data = composite_feed_info.lamports.borrow();
+            **lamports_data
+        };
+
+        transfer_lamports(composite_feed_info, authority_info, balance)?;
+
+        Ok(())
+    }
+
+    fn override_circuit_breaker(&self, price: Decimal) -> PriceProxyResult<()> {
+        msg!("override_circuit_breaker ix: price {}", price);
+
+        let OverrideCircuitBreakerAccounts {
+            price_feed: price_feed_info,
+            authority: authority_info,
+        } = OverrideCircuitBreakerAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+
+        let mut price_feed_data = price_feed_info.data.borrow_mut();
+        let price_feed = PriceFeed::try_from_bytes_mut(&mut price_feed_data)?;
+
+        verify_key(
+            authority_info.key,
+            &price_feed.update_authority,
+            "authority",
+        )?;
+
+        if price <= Decimal::ZERO {
+            return Err(PriceProxyError::NonPositivePrice(price));
+        }
+        price_feed.try_set_deviation_override_price(Some(price))?;
+
+        Ok(())
+    }
+
+    fn propose_authority(&self) -> PriceProxyResult<()> {
+        msg!("propose_authority ix");
+
+        let ProposeAuthorityAccounts {
+            price_feed: price_feed_info,
+            authority: authority_info,
+            new_authority: new_authority_info,
+        } = ProposeAuthorityAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+
+        let mut price_feed_data = price_feed_info.data.borrow_mut();
+        let price_feed = PriceFeed::try_from_bytes_mut(&mut price_feed_data)?;
+
+        verify_key(
+            authority_info.key,
+            &price_feed.update_authority,
+            "authority",
+        )?;
+
+        price_feed.pending_authority = if new_authority_info.key == authority_info.key {
+            Pubkey::default()
+        } else {
+            *new_authority_info.key
+        };
+
+        Ok(())
+    }
+
+    fn accept_authority(&self) -> PriceProxyResult<()> {
+        msg!("accept_authority ix");
+
+        let AcceptAuthorityAccounts {
+            price_feed: price_feed_info,
+            new_authority: new_authority_info,
+        } = AcceptAuthorityAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+
+        let mut price_feed_data = price_feed_info.data.borrow_mut();
+        let price_feed = PriceFeed::try_from_bytes_mut(&mut price_feed_data)?;
+
+        if !price_feed.has_pending_authority() {
+            msg!("No authority transfer is pending");
+            return Err(OperationCanNotBePerformed);
+        }
+
+        verify_key(
+            new_authority_info.key,
+            &price_feed.pending_authority,
+            "pending authority",
+        )?;
+
+        price_feed.update_authority = price_feed.pending_authority;
+        price_feed.pending_authority = Pubkey::default();
+
+        Ok(())
+    }
+
+    fn add_writer(&self) -> PriceProxyResult<()> {
+        msg!("add_writer ix");
+
+        let AddWriterAccounts {
+            price_feed: price_feed_info,
+            authority: authority_info,
+            writer: writer_info,
+        } = AddWriterAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+
+        let mut price_feed_data = price_feed_info.data.borrow_mut();
+        let price_feed = PriceFeed::try_from_bytes_mut(&mut price_feed_data)?;
+
+        verify_key(
+            authority_info.key,
+            &price_feed.update_authority,
+            "authority",
+        )?;
+
+        verify_source(price_feed.source(), PriceFeedSource::OffChain)?;
+
+        if *writer_info.key == Pubkey::default() || price_feed.writers.contains(writer_info.key) {
+            msg!("Writer {} is already authorized", writer_info.key);
+            return Err(PriceProxyError::WriterAlreadyAuthorized(*writer_info.key));
+        }
+
+        if !price_feed.add_writer(*writer_info.key) {
+            msg!("No free writer slot, {} max", MAX_WRITERS);
+            return Err(PriceProxyError::TooManyWriters(MAX_WRITERS));
+        }
+
+        Ok(())
+    }
+
+    fn remove_writer(&self) -> PriceProxyResult<()> {
+        msg!("remove_writer ix");
+
+        let RemoveWriterAccounts {
+            price_feed: price_feed_info,
+            authority: authority_info,
+            writer: writer_info,
+        } = RemoveWriterAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+
+        let mut price_feed_data = price_feed_info.data.borrow_mut();
+        let price_feed = PriceFeed::try_from_bytes_mut(&mut price_feed_data)?;
+
+        verify_key(
+            authority_info.key,
+            &price_feed.update_authority,
+            "authority",
+        )?;
+
+        if *writer_info.key == Pubkey::default() || !price_feed.remove_writer(writer_info.key) {
+            msg!("Writer {} is not found", writer_info.key);
+            return Err(PriceProxyError::WriterNotFound(*writer_info.key));
+        }
+
+        Ok(())
+    }
+
+    fn migrate_price_feed(&self) -> PriceProxyResult<()> {
+        msg!("migrate_price_feed ix");
+
+        let MigratePriceFeedAccounts {
+            price_feed: price_feed_info,
+            authority: authority_info,
+            system_program,
+        } = MigratePriceFeedAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+
+        let data_len = price_feed_info.data_len();
+        if data_len > PriceFeed::SIZE {
+            msg!(
+                "Price-feed size {} is greater than current {}",
+                data_len,
+                PriceFeed::SIZE
+            );
+            return Err(PriceProxyError::InvalidRealloc);
+        }
+
+        if data_len < PriceFeed::SIZE {
+            let rent = Rent::get().expect("No Rent");
+            let lamports = rent
+                .minimum_balance(PriceFeed::SIZE)
+                .saturating_sub(price_feed_info.lamports());
+            if lamports > 0 {
+                invoke(
+                    &system_instruction::transfer(
+                        authority_info.key,
+                        price_feed_info.key,
+                        lamports,
+                    ),
+                    &[
+                        authority_info.clone(),
+                        price_feed_info.clone(),
+                        system_program.clone(),
+                    ],
+                )
+                .map_err(|err| {
+                    msg!("Rent top-up failed: {}", err);
+                    PriceProxyError::NotEnoughBalance
+                })?;
+            }
+
+            // New fields take place of zeroed padding and appended zeroed bytes
+            price_feed_info
+                .realloc(PriceFeed::SIZE, true)
+                .map_err(|_| PriceProxyError::InvalidRealloc)?;
+        }
+
+        let mut price_feed_data = price_feed_info.data.borrow_mut();
+        // Version of not migrated account differs, so it's cast without version check
+        let price_feed = bytemuck::try_from_bytes_mut::<PriceFeed>(&mut price_feed_data)
+            .map_err(SerializeError::from)?;
+        if price_feed.discriminator() != PriceFeed::DISCRIMINATOR {
+            return Err(PriceProxyError::InvalidAccountData);
+        }
+
+        verify_key(
+            authority_info.key,
+            &price_feed.update_authority,
+            "authority",
+        )?;
+
+        price_feed.version = PriceFeed::VERSION;
+
+        Ok(())
+    }
+
+    fn get_twap(&self, window_sec: u32) -> PriceProxyResult<()> {
+        msg!("get_twap ix: {}", window_sec);
+
+        let GetTwapAccounts {
+            price_feed: price_feed_info,
+        } = GetTwapAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+
+        let price_feed_data = price_feed_info.data.borrow();
+        let price_feed = PriceFeed::try_from_bytes(&price_feed_data)?;
+
+        let clock = Clock::get().expect("clock");
+        let twap = price_feed
+            .try_twap(clock.unix_timestamp, window_sec)?
+            .ok_or_else(|| {
+                msg!("TWAP checkpoints don't cover window of {} sec", window_sec);
+                PriceProxyError::TwapWindowNotCovered(window_sec)
+            })?;
+        msg!("TWAP: {}", twap);
+
+        set_return_data(&borsh::to_vec(&twap)?);
+
+        Ok(())
+    }
+
+    fn create_price_history(&self) -> PriceProxyResult<()> {
+        msg!("create_price_history ix");
+
+        let CreatePriceHistoryAccounts {
+            price_history,
+            price_feed: price_feed_info,
+            authority,
+            system_program,
+        } = CreatePriceHistoryAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+
+        let mut price_feed_data = price_feed_info.data.borrow_mut();
+        let price_feed = PriceFeed::try_from_bytes_mut(&mut price_feed_data)?;
+
+        verify_key(authority.key, &price_feed.update_authority, "authority")?;
+
+        if price_feed.has_price_history() {
+            msg!(
+                "Price-feed already has price history {}",
+                price_feed.price_history
+            );
+            return Err(OperationCanNotBePerformed);
+        }
+
+        let rent = Rent::get().expect("No Rent");
+
+        SystemProgram::new(system_program)
+            .create_account(
+                authority,
+                price_history,
+                PriceHistory::SIZE as u64,
+                rent.minimum_balance(PriceHistory::SIZE),
+                self.program_id,
+            )
+            .call()?;
+
+        let mut price_history_data = price_history.data.borrow_mut();
+        PriceHistory::init_bytes(
+            price_history_data.as_mut(),
+            (*price_feed_info.key, *authority.key),
+        )?;
+
+        price_feed.price_history = *price_history.key;
+
+        Ok(())
+    }
+
+    fn delete_price_history(&self) -> PriceProxyResult<()> {
+        msg!("delete_price_history ix");
+
+        let DeletePriceHistoryAccounts {
+            price_history: price_history_info,
+            price_feed: price_feed_info,
+            authority: authority_info,
+        } = DeletePriceHistoryAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+
+        {
+            let price_history_data = price_history_info.data.borrow();
+            let price_history = PriceHistory::try_from_bytes(&price_history_data)?;
+
+            verify_key(
+                price_feed_info.key,
+                &price_history.price_feed,
+                "price history price-feed",
+            )?;
+        }
+
+        let mut price_feed_data = price_feed_info.data.borrow_mut();
+        let price_feed = PriceFeed::try_from_bytes_mut(&mut price_feed_data)?;
+
+        // History follows the price-feed when its authority is transferred
+        verify_key(
+            authority_info.key,
+            &price_feed.update_authority,
+            "authority",
+        )?;
+
+        if price_feed.price_history == *price_history_info.key {
+            price_feed.price_history = Pubkey::default();
+        }
+
+        let balance = {
+            let lamports_data = price_history_info.lamports.borrow();
+            **lamports_data
+        };
+
+        transfer_lamports(price_history_info, authority_info, balance)?;
+
+        Ok(())
+    }
+
+    /// Appends the current price of `price_feed` to its price history. The history account is
+    /// looked up among all accounts of the instruction.
+    fn append_price_history(
+        &self,
+        price_feed_key: &Pubkey,
+        price_feed: &PriceFeed,
+    ) -> PriceProxyResult<()> {
+        if !price_feed.has_price_history() {
+            return Ok(());
+        }
+
+        let price_history_info = self
+            .accounts
+            .iter()
+            .find(|account| account.key == &price_feed.price_history)
+            .ok_or_else(|| {
+                msg!("Price history {} is not provided", price_feed.price_history);
+                PriceProxyError::PriceHistoryAccountMissing(price_feed.price_history)
+            })?;
+        if price_history_info.owner != self.program_id {
+            msg!("Price history must be owned by the program");
+            return Err(PriceProxyError::OwnerMismatch);
+        }
+
+        let mut price_history_data = price_history_info.data.borrow_mut();
+        let price_history = PriceHistory::try_from_bytes_mut(&mut price_history_data)?;
+        verify_key(
+            price_feed_key,
+            &price_history.price_feed,
+            "price history price-feed",
+        )?;
+
+        price_history.push(PriceObservation::from(price_feed));
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    pub(super) fn version(&self) -> Result<(), PriceProxyError> {
+        msg!(
+            "PriceProxy contract {}",
+            env!("CARGO_PKG_VERSION").to_string()
+        );
+        Err(OperationCanNotBePerformed)
+    }
+}
+
+/// Price read from one of the feed's sources.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct SourcePrice {
+    pub price: Decimal,
+    /// Confidence interval of `price`. Zero when the source doesn't provide one.
+    pub confidence: Decimal,
+    /// UTC unix-timestamp of `price`
+    pub timestamp: i64,
+    /// EMA provided by the source itself. Only Pyth provides one.
+    pub ema: Option<SourceEma>,
+}
+
+/// Exponentially-weighted moving average price and confidence provided by the source.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct SourceEma {
+    pub price: Decimal,
+    pub confidence: Decimal,
+}
+
+impl SourcePrice {
+    /// Product of two prices. Confidence is propagated to the first order:
+    /// `conf(a * b) = a * conf(b) + b * conf(a)`.
+    fn checked_mul(self, other: SourcePrice) -> Result<SourcePrice, PriceProxyError> {
+        let confidence = self
+            .price
+            .checked_mul(other.confidence)?
+            .checked_add(other.price.checked_mul(self.confidence)?)?;
+        Ok(SourcePrice {
+            price: self.price.checked_mul(other.price)?,
+            confidence,
+            timestamp: self.timestamp.min(other.timestamp),
+            ema: None,
+        })
+    }
+
+    /// Quotient of two prices. Confidence is propagated to the first order:
+    /// `conf(a / b) = conf(a) / b + a * conf(b) / b^2`.
+    fn checked_div(self, other: SourcePrice) -> Result<SourcePrice, PriceProxyError> {
+        let price = self.price.checked_div(other.price)?;
+        let confidence = self
+            .confidence
+            .checked_add(price.checked_mul(other.confidence)?)?
+            .checked_div(other.price)?;
+        Ok(SourcePrice {
+            price,
+            confidence,
+            timestamp: self.timestamp.min(other.timestamp),
+            ema: None,
+        })
+    }
+}
+
+pub(crate) fn get_price_from_source_no_older_than(
+    price_feed: &mut PriceFeed,
+    source_address: &AccountInfo<'_>,
+    maximum_age_sec: u64,
+    feed_type: FeedType,
+) -> Result<SourcePrice, PriceProxyError> {
+    let (source, expected_source_address) = if feed_type == FeedType::Direct {
+        (price_feed.source(), price_feed.source_address)
+    } else {
+        (
+            price_feed.transform_source(),
+            price_feed.transform_source_address,
+        )
+    };
+
+    if source == PriceFeedSource::OffChain {
+        msg!("OffChain price feed is updated with WritePrice");
+        return Err(PriceProxyError::InvalidSource {
+            current: source,
+            expected: PriceFeedSource::Unknown,
+        });
+    }
+
+    get_price_from_source(
+        source,
+        expected_source_address,
+        price_feed.verification_level(),
+        source_address,
+        maximum_age_sec,
+    )
+}
+
+/// Price of the feed's `source`. When it is stale and the feed has fallback source, the price is
+/// taken from the fallback source account which is the next of `remaining_accounts`.
+/// Any other error of the primary source fails the update: the caller chooses the accounts, so
+/// a junk primary account must not switch the feed to the fallback price.
+/// Pyth price update is posted by the caller, so there is none when Hermes is unavailable. Then
+/// the caller passes the Pyth feed id, i.e. the feed's `source_address`, as the primary account
+/// and the primary source is treated as stale.
+/// Both `maximum_age_sec` and the primary account are chosen by the caller too, so the fallback
+/// is used only when the primary source produced no price for `fallback_after_sec` by `now`:
+/// neither the feed's latest primary price nor the given primary account is that fresh.
+pub(crate) fn get_price_with_fallback<'a, 'b: 'a>(
+    price_feed: &mut PriceFeed,
+    source_address: &AccountInfo<'b>,
+    remaining_accounts: &mut impl Iterator<Item = &'a AccountInfo<'b>>,
+    maximum_age_sec: u64,
+    now: i64,
+) -> Result<(SourcePrice, ActiveSource), PriceProxyError> {
+    let primary_unavailable = price_feed.has_fallback()
+        && price_feed.source() == PriceFeedSource::Pyth
+        && *source_address.key == price_feed.source_address;
+    let primary_err = if primary_unavailable {
+        msg!("Pyth price update of the primary source is not provided");
+        PriceProxyError::PythPriceTooOld
+    } else {
+        match get_price_from_source_no_older_than(
+            price_feed,
+            source_address,
+            maximum_age_sec,
+            FeedType::Direct,
+        ) {
+            Ok(price) => return Ok((price, ActiveSource::Primary)),
+            Err(err) if price_feed.has_fallback() && is_stale_price_error(&err) => err,
+            Err(err) => return Err(err),
+        }
+    };
+
+    let fallback_after_sec = price_feed.fallback_after_sec();
+    let primary_age = now.saturating_sub(price_feed.last_primary_timestamp());
+    if primary_age <= fallback_after_sec as i64 {
+        msg!(
+            "Primary price of the feed is {} sec old, fallback is used after {} sec",
+            primary_age,
+            fallback_after_sec
+        );
+        return Err(primary_err);
+    }
+    if !primary_unavailable
+        && get_price_from_source_no_older_than(
+            price_feed,
+            source_address,
+            fallback_after_sec as u64,
+            FeedType::Direct,
+        )
+        .is_ok()
+    {
+        msg!(
+            "Primary source price is not older than {} sec, fallback is not used",
+            fallback_after_sec
+        );
+        return Err(primary_err);
+    }
+
+    let Some(fallback_address) = remaining_accounts.next() else {
+        msg!("Primary source is stale and fallback source account is not provided");
+        return Err(primary_err);
+    };
+    msg!("Primary source is stale: {}. Using fallback", primary_err);
+
+    let price = get_price_from_source(
+        price_feed.fallback_source(),
+        price_feed.fallback_source_address,
+        price_feed.verification_level(),
+        fallback_address,
+        maximum_age_sec,
+    )?;
+    Ok((price, ActiveSource::Fallback))
+}
+
+/// Price of `CompositeFeed`: starting from 1 each leg price is multiplied or divided in order.
+/// Leg source accounts are taken from `leg_accounts`.
+pub(crate) fn get_composite_price<'a, 'b: 'a>(
+    composite_feed: &CompositeFeed,
+    verification_level: WormholeVerificationLevel,
+    leg_accounts: &mut impl Iterator<Item = &'a AccountInfo<'b>>,
+    maximum_age_sec: u64,
+) -> Result<SourcePrice, PriceProxyError> {
+    let mut composite_price = SourcePrice {
+        price: Decimal::from_i128_with_scale(1, 0)?,
+        confidence: Decimal::ZERO,
+        timestamp: i64::MAX,
+        ema: None,
+    };
+    for (index, leg) in composite_feed.legs().iter().enumerate() {
+        let leg_account = leg_accounts.next().ok_or_else(|| {
+            msg!("Source account of leg {} is not provided", index);
+            PriceProxyError::CompositeLegAccountMissing(index as u8)
+        })?;
+        let leg_price = get_price_from_source(
+            leg.source(),
+            leg.address,
+            verification_level,
+            leg_account,
+            maximum_age_sec,
+        )?;
+        composite_price = match leg.op() {
+            TransformOp::Multiply => composite_price.checked_mul(leg_price)?,
+            TransformOp::Divide => composite_price.checked_div(leg_price)?,
+        };
+    }
+    Ok(composite_price)
+}
+
+/// Median of `CompositeFeed` legs' prices. Legs which are stale are skipped, at least
+/// `quorum` legs (all of them when `quorum` is 0) must be fresh. For even number of fresh legs
+/// price and confidence are averages of the two middle ones.
+///
+/// NOTE: Leg accounts are chosen by the caller, e.g. any older `PriceUpdateV2` of the same Pyth
+/// feed can be passed. So with `quorum` lower than number of legs anyone can drop legs of their
+/// choice down to `quorum` and pick which of the remaining prices becomes the median.
+pub(crate) fn get_median_price<'a, 'b: 'a>(
+    composite_feed: &CompositeFeed,
+    verification_level: WormholeVerificationLevel,
+    leg_accounts: &mut impl Iterator<Item = &'a AccountInfo<'b>>,
+    maximum_age_sec: u64,
+) -> Result<SourcePrice, PriceProxyError> {
+    let mut prices = Vec::with_capacity(composite_feed.legs().len());
+    for (index, leg) in composite_feed.legs().iter().enumerate() {
+        let leg_account = leg_accounts.next().ok_or_else(|| {
+            msg!("Source account of leg {} is not provided", index);
+            PriceProxyError::CompositeLegAccountMissing(index as u8)
+        })?;
+        match get_price_from_source(
+            leg.source(),
+            leg.address,
+            verification_level,
+            leg_account,
+            maximum_age_sec,
+        ) {
+            Ok(leg_price) => prices.push(leg_price),
+            Err(err) if is_stale_price_error(&err) => {
+                msg!("Leg {} is skipped: {}", index, err);
+            }
+            Err(err) => return Err(err),
+        }
+    }
+
+    let quorum = match composite_feed.quorum {
+        0 => composite_feed.legs().len() as u8,
+        quorum => quorum,
+    };
+    if prices.len() < quorum as usize {
+        msg!("Only {} legs are fresh, quorum is {}", prices.len(), quorum);
+        return Err(PriceProxyError::QuorumNotReached {
+            fresh: prices.len() as u8,
+            quorum,
+        });
+    }
+
+    median_price(prices)
+}
+
+/// Records `price` submitted by `writer` of multi-writer feed. Returns the median of submissions
+/// not older than `submission_window_sec` at `now` once there are at least `writer_quorum` of them.
+/// The median is stamped with the oldest of these submissions.
+fn submit_writer_price(
+    price_feed: &mut PriceFeed,
+    writer: &Pubkey,
+    price: Decimal,
+    price_timestamp: i64,
+    now: i64,
+) -> Result<Option<SourcePrice>, PriceProxyError> {
+    let index = price_feed.writer_index(writer).ok_or_else(|| {
+        msg!("{} has no submission slot", writer);
+        PriceProxyError::UnauthorizedWriter(*writer)
+    })?;
+
+    if price <= Decimal::ZERO {
+        msg!("Price {} must be positive", price);
+        return Err(PriceProxyError::NonPositivePrice(price));
+    }
+    verify_price_bounds(price_feed, price)?;
+
+    let submission = &mut price_feed.writer_submissions[index];
+    if price_timestamp < submission.timestamp {
+        msg!(
+            "Submission timestamp {} is older than previous {}",
+            price_timestamp,
+            submission.timestamp
+        );
+        return Err(PriceProxyError::TimestampIsNotRecent);
+    }
+    submission.price_raw = price.into_bits()?;
+    submission.timestamp = price_timestamp;
+
+    let window_sec = price_feed.submission_window_sec;
+    let mut prices = Vec::with_capacity(MAX_WRITERS);
+    for (writer, submission) in price_feed
+        .writers
+        .iter()
+        .zip(price_feed.writer_submissions.iter())
+    {
+        if *writer == Pubkey::default() || !submission.is_fresh(now, window_sec) {
+            continue;
+        }
+        prices.push(SourcePrice {
+            price: submission.try_price()?,
+            confidence: Decimal::ZERO,
+            timestamp: submission.timestamp,
+            ema: None,
+        });
+    }
+
+    if prices.len() < price_feed.writer_quorum as usize {
+        msg!(
+            "{} fresh submissions of {} required, price is not updated",
+            prices.len(),
+            price_feed.writer_quorum
+        );
+        return Ok(None);
+    }
+
+    // The median is only as recent as the oldest submission contributing to it
+    let timestamp = prices.iter().map(|price| price.timestamp).min();
+    let median = median_price(prices)?;
+    let timestamp = timestamp.unwrap_or(median.timestamp);
+    if timestamp < price_feed.update_timestamp {
+        msg!(
+            "Oldest fresh submission {} is older than current price {}, price is not updated",
+            timestamp,
+            price_feed.update_timestamp
+        );
+        return Ok(None);
+    }
+
+    Ok(Some(SourcePrice {
+        timestamp,
+        ..median
+    }))
+}
+
+fn median_price(mut prices: Vec<SourcePrice>) -> Result<SourcePrice, PriceProxyError> {
+    prices.sort_by(|a, b| a.price.cmp(&b.price));
+
+    let middle = prices.len() / 2;
+    if prices.len() % 2 == 1 {
+        return Ok(prices[middle]);
+    }
+
+    let (lower, upper) = (prices[middle - 1], prices[middle]);
+    let two = Decimal::from_i128_with_scale(2, 0)?;
+    Ok(SourcePrice {
+        price: lower.price.checked_add(upper.price)?.checked_div(two)?,
+        confidence: lower
+            .confidence
+            .checked_add(upper.confidence)?
+            .checked_div(two)?,
+        timestamp: lower.timestamp.min(upper.timestamp),
+        ema: None,
+    })
+}
+
+/// Errors which mean that source is valid but its price is too old.
+fn is_stale_price_error(err: &PriceProxyError) -> bool {
+    matches!(
+        err,
+        PriceProxyError::StaleFeed(_)
+            | PriceProxyError::PythPriceTooOld
+            | PriceProxyError::SwitchboardStale
+    )
+}
+
+fn get_price_from_source(
+    source: PriceFeedSource,
+    expected_source_address: Pubkey,
+    verification_level: WormholeVerificationLevel,
+    source_address: &AccountInfo<'_>,
+    maximum_age_sec: u64,
+) -> Result<SourcePrice, PriceProxyError> {
+    verify_source_owner(source, source_address)?;
+
+    let mut source_data: &[u8] = &source_address.data.try_borrow_mut().expect("borrow mut");
+
+    let mut ema = None;
+    // Update
+    let (price, confidence, update_ts) = match source {
+        PriceFeedSource::Pyth => {
+            let price_update = PriceUpdateV2::try_deserialize(&mut source_data).map_err(|err| {
+                msg!("Deserialize Pyth price update: {}", err);
+                PriceProxyError::PythDeserialize
+            })?;
+
+            // Check that PriceFeed's source_address and PriceUpdateV2's feed_id are equal
+            verify_key(
+                &Pubkey::new_from_array(price_update.price_message.feed_id),
+                &expected_source_address,
+                "source address",
+            )?;
+            let pyth_price = match verification_level {
+                WormholeVerificationLevel::Full => price_update
+                    .get_price_no_older_than(
+                        &Clock::get().expect("clock"),
+                        maximum_age_sec,
+                        &price_update.price_message.feed_id,
+                    )
+                    .map_err(pyth_price_error)?,
+                WormholeVerificationLevel::Partial => {
+                    // Partially checks the Wormhole guardian signatures.
+                    // 5 signatures seems like the best it can currently do.
+                    let num_signatures = 5;
+
+                    price_update
+                        .get_price_no_older_than_with_custom_verification_level(
+                            &Clock::get().expect("clock"),
+                            maximum_age_sec,
+                            &price_update.price_message.feed_id,
+                            VerificationLevel::Partial { num_signatures },
+                        )
+                        .map_err(pyth_price_error)?
+                }
+            };
+
+            if pyth_price.price < 0 || price_update.price_message.ema_price < 0 {
+                msg!("Invalid Pyth price or exponent");
+                return Err(PriceProxyError::InvalidPriceOrExpo);
+            }
+            ema = Some(SourceEma {
+                price: pyth_decimal(
+                    price_update.price_message.ema_price as i128,
+                    price_update.price_message.exponent,
+                )?,
+                confidence: pyth_decimal(
+                    price_update.price_message.ema_conf as i128,
+                    price_update.price_message.exponent,
+                )?,
+            });
+            (
+                pyth_decimal(pyth_price.price as i128, pyth_price.exponent)?,
+                pyth_decimal(pyth_price.conf as i128, pyth_price.exponent)?,
+                pyth_price.publish_time,
+            )
+        }
+        PriceFeedSource::Switchboard => {
+            // Check that PriceFeed's source_address and provided source_address are equal
+            verify_key(
+                source_address.key,
+                &expected_source_address,
+                "source address",
+            )?;
+            let data_feed = AggregatorAccountData::new_from_bytes(source_data).map_err(|err| {
+                msg!("Deserialize Switchboard feed: {}", err);
+                PriceProxyError::SwitchboardDeserialize
+            })?;
+
+            data_feed
+                .check_staleness(Clock::get().unwrap().unix_timestamp, maximum_age_sec as i64)
+                .map_err(|_| {
+                    msg!("Switchboard price is stale");
+                    PriceProxyError::SwitchboardStale
+                })?;
+
+            let feed_result = data_feed.get_result().map_err(|err| {
+                msg!("Get Switchboard price: {}", err);
+                PriceProxyError::SwitchboardPrice
+            })?;
+            let std_deviation = data_feed.latest_confirmed_round.std_deviation;
+            (
+                Decimal::from_i128_with_scale(feed_result.mantissa, feed_result.scale)?,
+                Decimal::from_i128_with_scale(std_deviation.mantissa, std_deviation.scale)?,
+                data_feed.latest_confirmed_round.round_open_timestamp,
+            )
+        }
+        PriceFeedSource::SuperLendy => {
+            // Check that PriceFeed's source_address and provided source_address are equal
+            verify_key(
+                source_address.key,
+                &expected_source_address,
+                "source address",
+            )?;
+            let reserve_data = Reserve::try_from_bytes(source_data)?;
+
+            let unix_tx = Clock::get().unwrap().unix_timestamp;
+            let staleness = (unix_tx - reserve_data.last_update.timestamp) as u64;
+            if staleness > maximum_age_sec {
+                msg!("Feed has not been updated in {} seconds!", staleness);
+                return Err(PriceProxyError::StaleFeed(staleness));
+            }
+            if reserve_data.last_update.stale > 0 {
+                msg!("Reserve is stale and needs to be refreshed prior to get LP market price from it");
+                return Err(PriceProxyError::StaleFeed(0));
+            }
+
+            (
+                reserve_data.lp_market_price().map_err(|err| {
+                    msg!("Get LP market price: {}", err);
+                    PriceProxyError::SuperLendyLpPrice
+                })?,
+                Decimal::ZERO,
+                reserve_data.last_update.timestamp,
+            )
+        }
+        PriceFeedSource::StakePool
+        | PriceFeedSource::StakePoolNetOfFee
+        | PriceFeedSource::SanctumSpl => {
+            // Check that PriceFeed's source_address and provided source_address are equal
+            verify_key(
+                source_address.key,
+                &expected_source_address,
+                "source address",
+            )?;
+
+            let data_feed = try_from_slice_unchecked::<StakePool>(source_data)?;
+            let clock = Clock::get().unwrap();
+            verify_epoch_staleness(&clock, data_feed.last_update_epoch)?;
+
+            let lst_price = if source == PriceFeedSource::StakePoolNetOfFee {
+                data_feed.lst_price_net_of_withdrawal_fee()?
+            } else {
+                data_feed.lst_price()?
+            };
+
+            (lst_price, Decimal::ZERO, clock.unix_timestamp)
+        }
+        PriceFeedSource::Marinade => {
+            // Check that PriceFeed's source_address and provided source_address are equal
+            verify_key(
+                source_address.key,
+                &expected_source_address,
+                "source address",
+            )?;
+
+            let state = MarinadeState::try_from_bytes(source_data).map_err(|err| {
+                msg!("Deserialize Marinade state: {}", err);
+                PriceProxyError::MarinadeDeserialize
+            })?;
+            let clock = Clock::get().unwrap();
+            verify_epoch_staleness(&clock, state.last_update_epoch())?;
+
+            (state.msol_price()?, Decimal::ZERO, clock.unix_timestamp)
+        }
+        PriceFeedSource::Chainlink => {
+            // Check that PriceFeed's source_address and provided source_address are equal
+            verify_key(
+                source_address.key,
+                &expected_source_address,
+                "source address",
+            )?;
+            let transmissions = Transmissions::try_from_bytes(source_data).map_err(|err| {
+                msg!("Deserialize Chainlink feed: {}", err);
+                PriceProxyError::ChainlinkDeserialize
+            })?;
+            let round = transmissions.latest.ok_or_else(|| {
+                msg!("Chainlink feed has no rounds");
+                PriceProxyError::ChainlinkNoRound
+            })?;
+
+            let round_ts = round.timestamp as i64;
+            let unix_tx = Clock::get().unwrap().unix_timestamp;
+            let staleness = unix_tx.saturating_sub(round_ts).max(0) as u64;
+            if staleness > maximum_age_sec {
+                msg!("Feed has not been updated in {} seconds!", staleness);
+                return Err(PriceProxyError::StaleFeed(staleness));
+            }
+
+            if round.answer < 0 {
+                msg!("Invalid Chainlink answer");
+                return Err(PriceProxyError::InvalidPriceOrExpo);
+            }
+            (
+                Decimal::from_i128_with_scale(round.answer, transmissions.header.decimals as u32)?,
+                Decimal::ZERO,
+                round_ts,
+            )
+        }
+        PriceFeedSource::SwitchboardOnDemand => {
+            // Check that PriceFeed's source_address and provided source_address are equal
+            verify_key(
+                source_address.key,
+                &expected_source_address,
+                "source address",
+            )?;
+            let pull_feed = PullFeedAccountData::try_from_bytes(source_data).map_err(|err| {
+                msg!("Deserialize Switchboard On-Demand feed: {}", err);
+                PriceProxyError::SwitchboardOnDemandDeserialize
+            })?;
+
+            let clock = Clock::get().unwrap();
+            let staleness = clock
+                .unix_timestamp
+                .saturating_sub(pull_feed.last_update_timestamp)
+                .max(0) as u64;
+            if staleness > maximum_age_sec {
+                msg!("Feed has not been updated in {} seconds!", staleness);
+                return Err(PriceProxyError::StaleFeed(staleness));
+            }
+
+            let result = pull_feed.result;
+            if pull_feed.is_stale_at_slot(clock.slot) {
+                msg!(
+                    "Switchboard On-Demand result of slot {} is older than {} slots",
+                    result.slot,
+                    pull_feed.max_staleness
+                );
+                return Err(PriceProxyError::SwitchboardStale);
+            }
+            if result.num_samples < pull_feed.min_samples() {
+                msg!("Not enough samples in Switchboard On-Demand result");
+                return Err(PriceProxyError::SwitchboardOnDemandNotEnoughSamples {
+                    samples: result.num_samples,
+                    min_samples: pull_feed.min_samples(),
+                });
+            }
+            if result.value < 0 {
+                msg!("Invalid Switchboard On-Demand price");
+                return Err(PriceProxyError::InvalidPriceOrExpo);
+            }
+            (
+                Decimal::from_i128_with_scale(result.value, PRECISION)?,
+                Decimal::from_i128_with_scale(result.std_dev, PRECISION)?,
+                pull_feed.last_update_timestamp,
+            )
+        }
+        PriceFeedSource::OffChain => {
+            // Leg of CompositeFeed which refers to OffChain PriceFeed of this program
+            verify_key(
+                source_address.key,
+                &expected_source_address,
+                "source address",
+            )?;
+            let price_feed = PriceFeed::try_from_bytes(source_data)?;
+            verify_source(price_feed.source(), PriceFeedSource::OffChain)?;
+
+            let unix_tx = Clock::get().unwrap().unix_timestamp;
+            let staleness = unix_tx.saturating_sub(price_feed.update_timestamp).max(0) as u64;
+            if staleness > maximum_age_sec {
+                msg!("Feed has not been updated in {} seconds!", staleness);
+                return Err(PriceProxyError::StaleFeed(staleness));
+            }
+
+            (
+                price_feed.try_price()?,
+                price_feed.try_confidence()?,
+                price_feed.update_timestamp,
+            )
+        }
+        other => {
+            msg!(
+                "Price source must be Pyth, Switchboard, Switchboard On-Demand, StakePool, Marinade, SanctumSpl, SuperLendy or Chainlink only, given {}",
+                other.to_string()
+            );
+            return Err(PriceProxyError::InvalidSource {
+                current: other,
+                expected: PriceFeedSource::Unknown,
+            });
+        }
+    };
+    Ok(SourcePrice {
+        price,
+        confidence,
+        timestamp: update_ts,
+        ema,
+    })
+}
+
+/// Programs which may own source account of given `source`. `None` for sources which are not
+/// read from on-chain accounts.
+fn source_owners(source: PriceFeedSource) -> Option<&'static [Pubkey]> {
+    let owners: &'static [Pubkey] = match source {
+        // Price updates posted by the receiver and sponsored push feeds share the layout
+        PriceFeedSource::Pyth => &[pyth_solana_receiver_sdk::ID, PYTH_PUSH_ORACLE_ID],
+        PriceFeedSource::Switchboard => &[SWITCHBOARD_PROGRAM_ID],
+        PriceFeedSource::SwitchboardOnDemand => &[SWITCHBOARD_ON_DEMAND_PROGRAM_ID],
+        PriceFeedSource::SuperLendy => &[super_lendy::ID],
+        PriceFeedSource::StakePool | PriceFeedSource::StakePoolNetOfFee => &[
+            SPL_STAKE_POOL_PROGRAM_ID,
+            SANCTUM_SPL_STAKE_POOL_PROGRAM_ID,
+            SANCTUM_SPL_MULTI_STAKE_POOL_PROGRAM_ID,
+        ],
+        PriceFeedSource::SanctumSpl => &[
+            SANCTUM_SPL_STAKE_POOL_PROGRAM_ID,
+            SANCTUM_SPL_MULTI_STAKE_POOL_PROGRAM_ID,
+        ],
+        PriceFeedSource::Marinade => &[MARINADE_PROGRAM_ID],
+        PriceFeedSource::Chainlink => &[CHAINLINK_STORE_PROGRAM_ID],
+        // OffChain PriceFeed of this program used as CompositeFeed leg
+        PriceFeedSource::OffChain => &[crate::ID],
+        _ => return None,
+    };
+    Some(owners)
+}
+
+/// Checks that `source_address` is owned by the program expected for `source` so that
+/// look-alike accounts created by other programs are not decoded.
+fn verify_source_owner(
+    source: PriceFeedSource,
+    source_address: &AccountInfo<'_>,
+) -> Result<(), PriceProxyError> {
+    match source_owners(source) {
+        Some(owners) if !owners.contains(source_address.owner) => {
+            msg!(
+                "{} source account {} is owned by {}",
+                source,
+                source_address.key,
+                source_address.owner
+            );
+            Err(PriceProxyError::SourceOwnerMismatch {
+                price_source: source,
+                owner: *source_address.owner,
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+fn pyth_price_error(err: GetPriceError) -> PriceProxyError {
+    msg!("Get Pyth price: {}", err);
+    match err {
+        GetPriceError::PriceTooOld => PriceProxyError::PythPriceTooOld,
+        GetPriceError::InsufficientVerificationLevel => PriceProxyError::PythVerificationLevel,
+        _ => PriceProxyError::InvalidAccountData,
+    }
+}
+
+fn verify_composite_legs(legs: &[CompositeLegParams], quorum: u8) -> Result<(), PriceProxyError> {
+    if legs.is_empty() || legs.len() > MAX_COMPOSITE_LEGS {
+        msg!(
+            "Composite feed must have from 1 to {} legs, given {}",
+            MAX_COMPOSITE_LEGS,
+            legs.len()
+        );
+        return Err(PriceProxyError::InvalidCompositeLegs(legs.len()));
+    }
+    if quorum as usize > legs.len() {
+        msg!(
+            "Quorum {} is greater than number of legs {}",
+            quorum,
+            legs.len()
+        );
+        return Err(PriceProxyError::InvalidQuorum {
+            quorum,
+            legs: legs.len() as u8,
+        });
+    }
+    Ok(())
+}
+
+/// Number of fractional digits `Decimal` holds
+const DECIMAL_SCALE: u32 = 18;
+
+/// Converts Pyth fixed point `value * 10^exponent` to `Decimal`. Exponent may be positive.
+pub(crate) fn pyth_decimal(value: i128, exponent: i32) -> Result<Decimal, PriceProxyError> {
+    if exponent < -255 {
+        msg!("Invalid Pyth price or exponent");
+        return Err(PriceProxyError::InvalidPriceOrExpo);
+    }
+    if exponent <= 0 {
+        let scale = exponent.unsigned_abs();
+        if scale > DECIMAL_SCALE {
+            // Digits beyond Decimal precision are truncated
+            let truncated = 10_i128
+                .checked_pow(scale - DECIMAL_SCALE)
+                .map_or(0, |divisor| value / divisor);
+            return Ok(Decimal::from_i128_with_scale(truncated, DECIMAL_SCALE)?);
+        }
+        return Ok(Decimal::from_i128_with_scale(value, scale)?);
+    }
+
+    let overflow = || {
+        msg!("Pyth value {} with exponent {} overflows", value, exponent);
+        PriceProxyError::PythExponentOverflow { value, exponent }
+    };
+    let scaled = 10_i128
+        .checked_pow(exponent.unsigned_abs())
+        .and_then(|factor| value.checked_mul(factor))
+        .ok_or_else(overflow)?;
+    Decimal::from_i128_with_scale(scaled, 0).map_err(|_| overflow())
+}
+
+/// LST exchange rates are updated once per epoch, so the source is fresh if it was updated
+/// in the current or previous epoch.
+fn verify_epoch_staleness(clock: &Clock, feed_epoch: u64) -> Result<(), PriceProxyError> {
+    if clock.epoch > feed_epoch {
+        let staleness = clock.epoch - feed_epoch;
+        if staleness > 1 {
+            msg!("Feed has not been updated in {} epoch!", staleness);
+            return Err(PriceProxyError::StaleFeed(staleness));
+        }
+    }
+    Ok(())
+}
+
+/// Whether `|price - base| / base > max_bps / 10_000`.
+fn differs_by_more_than(
+    price: Decimal,
+    base: Decimal,
+    max_bps: u16,
+) -> Result<bool, PriceProxyError> {
+    // price * 10_000 > base * (10_000 + max_bps) or price * 10_000 < base * (10_000 - max_bps)
+    let scaled_price = price.checked_mul(Decimal::from_i128_with_scale(10_000, 0)?)?;
+    let upper_bound =
+        base.checked_mul(Decimal::from_i128_with_scale(10_000 + max_bps as i128, 0)?)?;
+    let lower_bound = base.checked_mul(Decimal::from_i128_with_scale(
+        (10_000 - max_bps as i128).max(0),
+        0,
+    )?)?;
+    Ok(scaled_price > upper_bound || scaled_price < lower_bound)
+}
+
+/// EMA older than this number of half-lives has negligible weight and is replaced by the value.
+const MAX_EMA_HALF_LIVES: i64 = 32;
+
+/// Moves on-chain EMA of the feed towards `price` and `confidence`. Must be called before the
+/// feed's price and timestamp are updated.
+fn update_ema(
+    price_feed: &mut PriceFeed,
+    price: Decimal,
+    confidence: Decimal,
+    price_timestamp: i64,
+) -> Result<(), PriceProxyError> {
+    let elapsed = price_timestamp.saturating_sub(price_feed.update_timestamp);
+    let half_life_sec = price_feed.ema_half_life_sec;
+    let ema_price = next_ema(price_feed.try_ema_price()?, price, elapsed, half_life_sec)?;
+    let ema_confidence = next_ema(
+        price_feed.try_ema_confidence()?,
+        confidence,
+        elapsed,
+        half_life_sec,
+    )?;
+    price_feed.try_set_ema(ema_price, ema_confidence)?;
+    Ok(())
+}
+
+/// EMA after `elapsed` seconds of `value` with given half-life. The weight of `previous` halves
+/// every `half_life_sec` and decreases linearly in between. Zero `previous` (no EMA yet) or
+/// zero `half_life_sec` yields `value`.
+pub(crate) fn next_ema(
+    previous: Decimal,
+    value: Decimal,
+    elapsed: i64,
+    half_life_sec: u32,
+) -> Result<Decimal, PriceProxyError> {
+    if half_life_sec == 0 || previous == Decimal::ZERO {
+        return Ok(value);
+    }
+    if elapsed <= 0 {
+        return Ok(previous);
+    }
+
+    let half_life_sec = half_life_sec as i64;
+    let half_lives = elapsed / half_life_sec;
+    if half_lives >= MAX_EMA_HALF_LIVES {
+        return Ok(value);
+    }
+
+    // weight of previous = (2 * H - r) / (2 * H * 2^k), where elapsed = k * H + r
+    let remainder = elapsed % half_life_sec;
+    let numerator = (2 * half_life_sec - remainder) as i128;
+    let denominator = ((2 * half_life_sec) as i128) << half_lives;
+    let denominator_dec = Decimal::from_i128_with_scale(denominator, 0)?;
+    let previous_weight =
+        Decimal::from_i128_with_scale(numerator, 0)?.checked_div(denominator_dec)?;
+    let value_weight =
+        Decimal::from_i128_with_scale(denominator - numerator, 0)?.checked_div(denominator_dec)?;
+
+    Ok(previous
+        .checked_mul(previous_weight)?
+        .checked_add(value.checked_mul(value_weight)?)?)
+}
+
+/// Circuit breaker: rejects `price` moving from the feed's price more than `max_deviation_bps`
+/// within `deviation_window_sec`, unless the authority has approved the price within
+/// `max_deviation_bps` for this update.
+pub(crate) fn verify_deviation(
+    price_feed: &mut PriceFeed,
+    price: Decimal,
+    price_timestamp: i64,
+) -> Result<(), PriceProxyError> {
+    let max_deviation_bps = price_feed.max_deviation_bps;
+    if max_deviation_bps == 0 {
+        return Ok(());
+    }
+
+    if let Some(override_price) = price_feed.try_deviation_override_price()? {
+        if !differs_by_more_than(price, override_price, max_deviation_bps)? {
+            msg!(
+                "Deviation check is overridden by authority for price {}",
+                override_price
+            );
+            price_feed.try_set_deviation_override_price(None)?;
+            return Ok(());
         }
+    }
+
+    let previous_price = price_feed.try_price()?;
+    let elapsed = price_timestamp.saturating_sub(price_feed.update_timestamp);
+    if previous_price == Decimal::ZERO || elapsed > price_feed.deviation_window_sec as i64 {
+        return Ok(());
+    }
+
+    if differs_by_more_than(price, previous_price, max_deviation_bps)? {
+        msg!(
+            "Price {} deviates from previous price {} by more than {} bps within {} sec",
+            price,
+            previous_price,
+            max_deviation_bps,
+            price_feed.deviation_window_sec
+        );
+        return Err(PriceProxyError::PriceDeviationTooLarge {
+            price,
+            previous_price,
+            max_deviation_bps,
+        });
+    }
+    Ok(())
+}
+
+/// Verifies `params` price bounds fit into account, `min_price` is positive and not above `max_price`.
+pub(crate) fn verify_price_bounds_params(params: &PriceFeedParams) -> Result<(), PriceProxyError> {
+    price_bound_bits(params.min_price)?;
+    price_bound_bits(params.max_price)?;
+
+    let valid = match (params.min_price, params.max_price) {
+        (Some(min_price), Some(max_price)) => min_price > Decimal::ZERO && min_price <= max_price,
+        (Some(min_price), None) => min_price > Decimal::ZERO,
+        (None, Some(max_price)) => max_price > Decimal::ZERO,
+        (None, None) => true,
+    };
+    if !valid {
+        msg!(
+            "Invalid price bounds: min {:?}, max {:?}",
+            params.min_price,
+            params.max_price
+        );
+        return Err(PriceProxyError::InvalidPriceBounds);
+    }
+    Ok(())
+}
+
+/// Verifies `params` writer quorum is only set for OffChain feed, does not exceed `MAX_WRITERS`
+/// and has a submission window.
+pub(crate) fn verify_writer_quorum_params(params: &PriceFeedParams) -> Result<(), PriceProxyError> {
+    if params.writer_quorum == 0 {
+        return Ok(());
+    }
+    if params.writer_quorum as usize > MAX_WRITERS {
+        msg!(
+            "Writer quorum {} is greater than maximum number of writers {}",
+            params.writer_quorum,
+            MAX_WRITERS
+        );
+        return Err(PriceProxyError::InvalidQuorum {
+            quorum: params.writer_quorum,
+            legs: MAX_WRITERS as u8,
+        });
+    }
+    verify_source(params.source, PriceFeedSource::OffChain)?;
+    if params.submission_window_sec == 0 {
+        msg!("Writer quorum requires non-zero submission window");
+        return Err(PriceProxyError::OperationCanNotBePerformed);
+    }
+    Ok(())
+}
+
+/// Rejects written `price_timestamp` more than `max_future_sec` ahead of `now`. Such price would
+/// block every following price with `TimestampIsNotRecent` until the clock reaches it.
+pub(crate) fn verify_not_future(
+    price_feed: &PriceFeed,
+    price_timestamp: i64,
+    now: i64,
+) -> Result<(), PriceProxyError> {
+    let Some(max_future_sec) = price_feed.max_future_sec() else {
+        return Ok(());
     };
-    Ok((price, update_ts))
+    if price_timestamp <= now.saturating_add(max_future_sec as i64) {
+        return Ok(());
+    }
+
+    msg!(
+        "Price timestamp {} is more than {} sec ahead of clock {}",
+        price_timestamp,
+        max_future_sec,
+        now
+    );
+    Err(PriceProxyError::TimestampInFuture {
+        timestamp: price_timestamp,
+        now,
+        max_future_sec,
+    })
+}
+
+/// Rejects `price` outside of the feed's `min_price` and `max_price`.
+pub(crate) fn verify_price_bounds(
+    price_feed: &PriceFeed,
+    price: Decimal,
+) -> Result<(), PriceProxyError> {
+    if let Some(min_price) = price_feed.try_min_price()? {
+        if price < min_price {
+            msg!("Price {} is below min price {}", price, min_price);
+            return Err(PriceProxyError::PriceBelowMin { price, min_price });
+        }
+    }
+    if let Some(max_price) = price_feed.try_max_price()? {
+        if price > max_price {
+            msg!("Price {} is above max price {}", price, max_price);
+            return Err(PriceProxyError::PriceAboveMax { price, max_price });
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn verify_divergence(
+    price: Decimal,
+    reference_price: Decimal,
+    max_divergence_bps: u16,
+) -> Result<(), PriceProxyError> {
+    if differs_by_more_than(price, reference_price, max_divergence_bps)? {
+        msg!(
+            "Price {} diverges from reference price {} by more than {} bps",
+            price,
+            reference_price,
+            max_divergence_bps
+        );
+        return Err(PriceProxyError::PriceDivergence {
+            price,
+            reference_price,
+            max_divergence_bps,
+        });
+    }
+    Ok(())
+}
+
+/// Fails when `confidence / price` is above `max_confidence_bps`. Zero `max_confidence_bps`
+/// disables the check.
+pub(crate) fn verify_confidence(
+    price: Decimal,
+    confidence: Decimal,
+    max_confidence_bps: u16,
+) -> Result<(), PriceProxyError> {
+    if max_confidence_bps == 0 {
+        return Ok(());
+    }
+
+    // confidence / price > max_bps / 10_000  <=>  confidence * 10_000 > price * max_bps
+    let scaled_confidence = confidence.checked_mul(Decimal::from_i128_with_scale(10_000, 0)?)?;
+    let max_confidence = price.checked_mul(Decimal::from_i128_with_scale(
+        max_confidence_bps as i128,
+        0,
+    )?)?;
+    if scaled_confidence > max_confidence {
+        msg!(
+            "Confidence {} is too wide for price {}, max {} bps",
+            confidence,
+            price,
+            max_confidence_bps
+        );
+        return Err(PriceProxyError::ConfidenceTooWide {
+            price,
+            confidence,
+            max_confidence_bps,
+        });
+    }
+    Ok(())
 }
 
 pub(crate) fn verify_source(
@@ -438,3 +2170,336 @@ pub fn process_instruction(
             err.into()
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::state::price_feed::{DEFAULT_MAX_FUTURE_SEC, UNLIMITED_MAX_FUTURE_SEC};
+
+    use super::*;
+
+    fn decimal(value: i128, scale: u32) -> Decimal {
+        Decimal::from_i128_with_scale(value, scale).unwrap()
+    }
+
+    #[test]
+    fn pyth_decimal_negative_exponent() {
+        assert_eq!(pyth_decimal(15_000_000_000, -8).unwrap(), decimal(150, 0));
+        assert_eq!(pyth_decimal(123, -20).unwrap(), decimal(1, 18));
+        assert_eq!(pyth_decimal(1, -255).unwrap(), Decimal::ZERO);
+        assert!(matches!(
+            pyth_decimal(1, -256),
+            Err(PriceProxyError::InvalidPriceOrExpo)
+        ));
+    }
+
+    #[test]
+    fn pyth_decimal_zero_exponent() {
+        assert_eq!(pyth_decimal(150, 0).unwrap(), decimal(150, 0));
+        assert_eq!(pyth_decimal(0, 0).unwrap(), Decimal::ZERO);
+    }
+
+    #[test]
+    fn pyth_decimal_positive_exponent() {
+        assert_eq!(pyth_decimal(15, 1).unwrap(), decimal(150, 0));
+        assert_eq!(pyth_decimal(3, 5).unwrap(), decimal(300_000, 0));
+        assert!(matches!(
+            pyth_decimal(1, 39),
+            Err(PriceProxyError::PythExponentOverflow {
+                value: 1,
+                exponent: 39
+            })
+        ));
+        assert!(matches!(
+            pyth_decimal(i64::MAX as i128, 20),
+            Err(PriceProxyError::PythExponentOverflow { .. })
+        ));
+    }
+
+    fn source_price(price: i128, confidence: i128, timestamp: i64) -> SourcePrice {
+        SourcePrice {
+            price: decimal(price, 0),
+            confidence: decimal(confidence, 0),
+            timestamp,
+            ema: None,
+        }
+    }
+
+    #[test]
+    fn median_price_odd() {
+        let median = median_price(vec![
+            source_price(152, 2, 10),
+            source_price(100, 1, 20),
+            source_price(150, 3, 30),
+        ])
+        .unwrap();
+        assert_eq!(median.price, decimal(150, 0));
+        assert_eq!(median.confidence, decimal(3, 0));
+        assert_eq!(median.timestamp, 30);
+    }
+
+    #[test]
+    fn median_price_even() {
+        let median = median_price(vec![
+            source_price(152, 2, 10),
+            source_price(100, 1, 20),
+            source_price(150, 3, 30),
+            source_price(1000, 1, 40),
+        ])
+        .unwrap();
+        assert_eq!(median.price, decimal(151, 0));
+        assert_eq!(median.confidence, decimal(25, 1));
+        assert_eq!(median.timestamp, 10);
+    }
+
+    #[test]
+    fn divergence_within_tolerance() {
+        // 1% tolerance around 150
+        assert!(verify_divergence(decimal(1515, 1), decimal(150, 0), 100).is_ok());
+        assert!(verify_divergence(decimal(1485, 1), decimal(150, 0), 100).is_ok());
+        assert!(verify_divergence(decimal(150, 0), decimal(150, 0), 0).is_ok());
+    }
+
+    #[test]
+    fn divergence_exceeds_tolerance() {
+        assert!(matches!(
+            verify_divergence(decimal(1516, 1), decimal(150, 0), 100),
+            Err(PriceProxyError::PriceDivergence {
+                max_divergence_bps: 100,
+                ..
+            })
+        ));
+        assert!(matches!(
+            verify_divergence(decimal(1484, 1), decimal(150, 0), 100),
+            Err(PriceProxyError::PriceDivergence { .. })
+        ));
+        // tolerance above 100% never rejects lower prices
+        assert!(verify_divergence(Decimal::ZERO, decimal(150, 0), 20_000).is_ok());
+    }
+
+    fn circuit_breaker_feed() -> PriceFeed {
+        let params = PriceFeedParams::new(
+            FeedType::Direct,
+            "SOL",
+            "USD",
+            WormholeVerificationLevel::Full,
+            "/sol",
+            PriceFeedSource::OffChain,
+            PriceFeedSource::OffChain,
+        )
+        .with_circuit_breaker(1000, 60);
+        PriceFeed::new(
+            params,
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+        )
+        .with_price(decimal(100, 0), 1000, 1)
+    }
+
+    fn multi_writer_feed(writers: &[Pubkey]) -> PriceFeed {
+        let params = PriceFeedParams::new(
+            FeedType::Direct,
+            "SOL",
+            "USD",
+            WormholeVerificationLevel::Full,
+            "/sol",
+            PriceFeedSource::OffChain,
+            PriceFeedSource::OffChain,
+        )
+        .with_writer_quorum(2, 60);
+        let mut price_feed = PriceFeed::new(
+            params,
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+        );
+        for writer in writers {
+            assert!(price_feed.add_writer(*writer));
+        }
+        price_feed
+    }
+
+    #[test]
+    fn writer_quorum_not_reached() {
+        let writers = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let mut price_feed = multi_writer_feed(&writers);
+
+        let median =
+            submit_writer_price(&mut price_feed, &writers[0], decimal(100, 0), 1000, 1000).unwrap();
+        assert!(median.is_none());
+        assert_eq!(price_feed.writer_submissions[0].timestamp, 1000);
+        assert!(price_feed.writer_submissions[1].is_empty());
+
+        // Unregistered writer has no slot
+        assert!(matches!(
+            submit_writer_price(
+                &mut price_feed,
+                &Pubkey::new_unique(),
+                decimal(100, 0),
+                1000,
+                1000
+            ),
+            Err(PriceProxyError::UnauthorizedWriter(_))
+        ));
+    }
+
+    #[test]
+    fn writer_quorum_median() {
+        let writers = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        let mut price_feed = multi_writer_feed(&writers);
+
+        submit_writer_price(&mut price_feed, &writers[0], decimal(100, 0), 1000, 1010).unwrap();
+        submit_writer_price(&mut price_feed, &writers[1], decimal(104, 0), 1005, 1010).unwrap();
+        let median = submit_writer_price(&mut price_feed, &writers[2], decimal(110, 0), 1010, 1010)
+            .unwrap()
+            .unwrap();
+        assert_eq!(median.price, decimal(104, 0));
+        assert_eq!(median.timestamp, 1000);
+    }
+
+    #[test]
+    fn writer_quorum_median_not_older_than_price() {
+        let writers = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let mut price_feed = multi_writer_feed(&writers);
+        price_feed.update_timestamp = 1005;
+
+        // Submission older than the current price holds the median back
+        submit_writer_price(&mut price_feed, &writers[0], decimal(100, 0), 1000, 1010).unwrap();
+        let median =
+            submit_writer_price(&mut price_feed, &writers[1], decimal(104, 0), 1010, 1010).unwrap();
+        assert!(median.is_none());
+
+        let median = submit_writer_price(&mut price_feed, &writers[0], decimal(102, 0), 1008, 1010)
+            .unwrap()
+            .unwrap();
+        assert_eq!(median.price, decimal(103, 0));
+        assert_eq!(median.timestamp, 1008);
+    }
+
+    #[test]
+    fn writer_quorum_ignores_stale_submissions() {
+        let writers = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        let mut price_feed = multi_writer_feed(&writers);
+
+        submit_writer_price(&mut price_feed, &writers[0], decimal(100, 0), 1000, 1000).unwrap();
+        // First submission is out of the window at 1100
+        let median =
+            submit_writer_price(&mut price_feed, &writers[1], decimal(200, 0), 1100, 1100).unwrap();
+        assert!(median.is_none());
+
+        let median = submit_writer_price(&mut price_feed, &writers[2], decimal(210, 0), 1100, 1100)
+            .unwrap()
+            .unwrap();
+        assert_eq!(median.price, decimal(205, 0));
+
+        // Writer can't go back in time
+        assert!(matches!(
+            submit_writer_price(&mut price_feed, &writers[2], decimal(210, 0), 1090, 1100),
+            Err(PriceProxyError::TimestampIsNotRecent)
+        ));
+    }
+
+    #[test]
+    fn deviation_within_window() {
+        let mut price_feed = circuit_breaker_feed();
+        assert!(verify_deviation(&mut price_feed, decimal(110, 0), 1030).is_ok());
+        assert!(verify_deviation(&mut price_feed, decimal(90, 0), 1060).is_ok());
+        assert!(matches!(
+            verify_deviation(&mut price_feed, decimal(1000, 0), 1030),
+            Err(PriceProxyError::PriceDeviationTooLarge {
+                max_deviation_bps: 1000,
+                ..
+            })
+        ));
+        // misplaced decimal point
+        assert!(matches!(
+            verify_deviation(&mut price_feed, decimal(10, 0), 1030),
+            Err(PriceProxyError::PriceDeviationTooLarge { .. })
+        ));
+    }
+
+    #[test]
+    fn future_timestamp_tolerance() {
+        let mut price_feed = circuit_breaker_feed();
+        price_feed.max_future_sec = 60;
+        assert!(verify_not_future(&price_feed, 1000, 1000).is_ok());
+        assert!(verify_not_future(&price_feed, 1060, 1000).is_ok());
+        assert!(matches!(
+            verify_not_future(&price_feed, 1061, 1000),
+            Err(PriceProxyError::TimestampInFuture {
+                timestamp: 1061,
+                now: 1000,
+                max_future_sec: 60
+            })
+        ));
+
+        // Zero is the default limit
+        price_feed.max_future_sec = 0;
+        assert!(verify_not_future(&price_feed, 1000 + DEFAULT_MAX_FUTURE_SEC as i64, 1000).is_ok());
+        assert!(
+            verify_not_future(&price_feed, 1001 + DEFAULT_MAX_FUTURE_SEC as i64, 1000).is_err()
+        );
+
+        price_feed.max_future_sec = UNLIMITED_MAX_FUTURE_SEC;
+        assert!(verify_not_future(&price_feed, i64::MAX, 1000).is_ok());
+    }
+
+    #[test]
+    fn deviation_after_window() {
+        let mut price_feed = circuit_breaker_feed();
+        assert!(verify_deviation(&mut price_feed, decimal(1000, 0), 1061).is_ok());
+    }
+
+    #[test]
+    fn deviation_overridden_once() {
+        let mut price_feed = circuit_breaker_feed();
+        price_feed
+            .try_set_deviation_override_price(Some(decimal(1000, 0)))
+            .unwrap();
+        // Price far from the approved one is still checked and the override is kept
+        assert!(verify_deviation(&mut price_feed, decimal(1500, 0), 1030).is_err());
+        assert!(price_feed.is_deviation_overridden());
+        // Price within max deviation of the approved one
+        assert!(verify_deviation(&mut price_feed, decimal(1050, 0), 1030).is_ok());
+        assert!(!price_feed.is_deviation_overridden());
+        assert!(verify_deviation(&mut price_feed, decimal(1000, 0), 1030).is_err());
+    }
+
+    #[test]
+    fn ema_starts_from_value() {
+        let (previous, value) = (decimal(100, 0), decimal(200, 0));
+        assert_eq!(next_ema(Decimal::ZERO, value, 60, 60).unwrap(), value);
+        assert_eq!(next_ema(previous, value, 60, 0).unwrap(), value);
+    }
+
+    #[test]
+    fn ema_decays_by_half_life() {
+        let (previous, value) = (decimal(100, 0), decimal(200, 0));
+        assert_eq!(next_ema(previous, value, 0, 60).unwrap(), previous);
+        assert_eq!(next_ema(previous, value, 30, 60).unwrap(), decimal(125, 0));
+        assert_eq!(next_ema(previous, value, 60, 60).unwrap(), decimal(150, 0));
+        assert_eq!(next_ema(previous, value, 120, 60).unwrap(), decimal(175, 0));
+        assert_eq!(next_ema(previous, value, 60 * 32, 60).unwrap(), value);
+    }
+
+    #[test]
+    fn ema_updated_from_feed_timestamp() {
+        let mut price_feed = circuit_breaker_feed();
+        price_feed.ema_half_life_sec = 60;
+        price_feed
+            .try_set_ema(decimal(100, 0), decimal(2, 0))
+            .unwrap();
+
+        update_ema(&mut price_feed, decimal(200, 0), decimal(4, 0), 1060).unwrap();
+        assert_eq!(price_feed.try_ema_price().unwrap(), decimal(150, 0));
+        assert_eq!(price_feed.try_ema_confidence().unwrap(), decimal(3, 0));
+    }
+}
@@ -2,6 +2,11 @@ use crate::error::PriceProxyError;
 use crate::PriceProxyResult;
 use solana_program::account_info::AccountInfo;
 use solana_program::msg;
+use solana_program::program::{invoke, invoke_signed};
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use texture_common::remote::RemoteError;
 
 /// Transfers `amount` lamports from `from_account` (must be program owned)
 /// to another `to_account`. The `to_account` can be owned by anyone else.
@@ -34,3 +39,64 @@ pub fn transfer_lamports(
 
     Ok(())
 }
+
+/// Creates program owned `new_account` at PDA address signed by `signer_seeds`.
+/// Unlike plain `create_account` this also works when someone has already sent
+/// lamports to the address: missing rent is topped up by `payer` and the account
+/// is allocated and assigned in place.
+pub fn create_pda_account<'a>(
+    payer: &AccountInfo<'a>,
+    new_account: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    space: usize,
+    owner: &Pubkey,
+    rent: &Rent,
+    signer_seeds: &[&[u8]],
+) -> PriceProxyResult<()> {
+    let required_lamports = rent.minimum_balance(space);
+    let current_lamports = new_account.lamports();
+
+    if current_lamports == 0 {
+        return invoke_signed(
+            &system_instruction::create_account(
+                payer.key,
+                new_account.key,
+                required_lamports,
+                space as u64,
+                owner,
+            ),
+            &[payer.clone(), new_account.clone(), system_program.clone()],
+            &[signer_seeds],
+        )
+        .map_err(|err| PriceProxyError::SystemProgram(RemoteError::Unrecognized(err)));
+    }
+
+    msg!(
+        "{} is pre-funded with {} lamports",
+        new_account.key,
+        current_lamports
+    );
+
+    let top_up_lamports = required_lamports.saturating_sub(current_lamports);
+    if top_up_lamports > 0 {
+        invoke(
+            &system_instruction::transfer(payer.key, new_account.key, top_up_lamports),
+            &[payer.clone(), new_account.clone(), system_program.clone()],
+        )
+        .map_err(|err| PriceProxyError::SystemProgram(RemoteError::Unrecognized(err)))?;
+    }
+
+    invoke_signed(
+        &system_instruction::allocate(new_account.key, space as u64),
+        &[new_account.clone(), system_program.clone()],
+        &[signer_seeds],
+    )
+    .map_err(|err| PriceProxyError::SystemProgram(RemoteError::Unrecognized(err)))?;
+
+    invoke_signed(
+        &system_instruction::assign(new_account.key, owner),
+        &[new_account.clone(), system_program.clone()],
+        &[signer_seeds],
+    )
+    .map_err(|err| PriceProxyError::SystemProgram(RemoteError::Unrecognized(err)))
+}
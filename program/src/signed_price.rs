@@ -0,0 +1,166 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::ed25519_program;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::{Pubkey, PUBKEY_BYTES};
+use texture_common::math::Decimal;
+
+/// Prefix of every signed price message, so that a signature made for other purpose can't be
+/// relayed as a price.
+pub const SIGNED_PRICE_DOMAIN: &[u8] = b"price-proxy:signed-price:v1";
+
+pub const SIGNATURE_BYTES: usize = 64;
+
+// Layout of Ed25519 program instruction data: number of signatures, padding, offsets of
+// signature, public key and message, then the data itself.
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_SIZE: usize = 14;
+const DATA_START: usize = SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_SIZE;
+/// Instruction index which refers to the Ed25519 instruction itself.
+const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// Price of off-chain Price-feed signed by its writer. Anyone can relay it with `WriteSignedPrice`.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq)]
+pub struct SignedPriceMessage {
+    /// Price-feed to write the price into.
+    pub price_feed: Pubkey,
+    pub price: Decimal,
+    /// UTC unix-timestamp of price
+    pub price_timestamp: i64,
+    /// Generation of the writer in the Price-feed, see `PriceFeed::writer_generation_of`.
+    pub generation: u32,
+    /// Must be greater than nonce of the previous signed price of the writer in the Price-feed.
+    pub nonce: u64,
+}
+
+impl SignedPriceMessage {
+    /// Bytes to sign: `SIGNED_PRICE_DOMAIN` followed by borsh of the message.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = SIGNED_PRICE_DOMAIN.to_vec();
+        borsh::to_writer(&mut bytes, self).expect("serialize signed price message");
+        bytes
+    }
+
+    pub fn try_from_bytes(bytes: &[u8]) -> Option<Self> {
+        let data = bytes.strip_prefix(SIGNED_PRICE_DOMAIN)?;
+        Self::try_from_slice(data).ok()
+    }
+}
+
+/// Ed25519 program instruction verifying `signature` of `message` by `signer`. Must go right
+/// before `WriteSignedPrice` in the same transaction.
+pub fn new_ed25519_instruction(
+    signer: &Pubkey,
+    signature: &[u8; SIGNATURE_BYTES],
+    message: &[u8],
+) -> Instruction {
+    let public_key_offset = DATA_START;
+    let signature_offset = public_key_offset + PUBKEY_BYTES;
+    let message_offset = signature_offset + SIGNATURE_BYTES;
+
+    let mut data = Vec::with_capacity(message_offset + message.len());
+    // One signature and padding
+    data.extend_from_slice(&[1, 0]);
+    for offset in [
+        signature_offset as u16,
+        CURRENT_INSTRUCTION,
+        public_key_offset as u16,
+        CURRENT_INSTRUCTION,
+        message_offset as u16,
+        message.len() as u16,
+        CURRENT_INSTRUCTION,
+    ] {
+        data.extend_from_slice(&offset.to_le_bytes());
+    }
+    data.extend_from_slice(signer.as_ref());
+    data.extend_from_slice(signature);
+    data.extend_from_slice(message);
+
+    Instruction {
+        program_id: ed25519_program::ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+/// Signer and message of Ed25519 program instruction with single signature which refers to the
+/// data of the instruction itself. `None` for any other instruction.
+pub fn parse_ed25519_instruction(ix: &Instruction) -> Option<(Pubkey, &[u8])> {
+    if ix.program_id != ed25519_program::ID {
+        return None;
+    }
+    let data = ix.data.as_slice();
+    if data.len() < DATA_START || data[0] != 1 {
+        return None;
+    }
+
+    let offset = |index: usize| {
+        let start = SIGNATURE_OFFSETS_START + index * 2;
+        u16::from_le_bytes([data[start], data[start + 1]])
+    };
+    // Data verified by the precompile must be the data we read
+    if [offset(1), offset(3), offset(6)] != [CURRENT_INSTRUCTION; 3] {
+        return None;
+    }
+
+    let public_key_offset = offset(2) as usize;
+    let message_offset = offset(4) as usize;
+    let message_size = offset(5) as usize;
+    let signer = data.get(public_key_offset..public_key_offset + PUBKEY_BYTES)?;
+    let message = data.get(message_offset..message_offset + message_size)?;
+
+    Some((Pubkey::try_from(signer).ok()?, message))
+}
+
+#[cfg(test)]
+mod tests {
+    use texture_common::dec;
+
+    use super::*;
+
+    fn message() -> SignedPriceMessage {
+        SignedPriceMessage {
+            price_feed: Pubkey::new_unique(),
+            price: dec!(150.25),
+            price_timestamp: 1_700_000_000,
+            generation: 2,
+            nonce: 7,
+        }
+    }
+
+    #[test]
+    fn message_round_trip() {
+        let message = message();
+        let bytes = message.to_bytes();
+        assert!(bytes.starts_with(SIGNED_PRICE_DOMAIN));
+        assert_eq!(SignedPriceMessage::try_from_bytes(&bytes), Some(message));
+
+        // Message without domain is not a signed price
+        assert_eq!(
+            SignedPriceMessage::try_from_bytes(&bytes[SIGNED_PRICE_DOMAIN.len()..]),
+            None
+        );
+    }
+
+    #[test]
+    fn ed25519_instruction_round_trip() {
+        let signer = Pubkey::new_unique();
+        let bytes = message().to_bytes();
+        let ix = new_ed25519_instruction(&signer, &[3; SIGNATURE_BYTES], &bytes);
+
+        assert_eq!(
+            parse_ed25519_instruction(&ix),
+            Some((signer, bytes.as_slice()))
+        );
+    }
+
+    #[test]
+    fn ed25519_instruction_refers_to_other_instruction() {
+        let signer = Pubkey::new_unique();
+        let bytes = message().to_bytes();
+        let mut ix = new_ed25519_instruction(&signer, &[3; SIGNATURE_BYTES], &bytes);
+        // message_instruction_index points to the first instruction of transaction
+        ix.data[14..16].copy_from_slice(&0u16.to_le_bytes());
+
+        assert_eq!(parse_ed25519_instruction(&ix), None);
+    }
+}
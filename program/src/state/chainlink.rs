@@ -0,0 +1,174 @@
+use borsh::BorshDeserialize;
+use solana_program::pubkey;
+use solana_program::pubkey::Pubkey;
+
+use crate::error::SerializeError;
+
+/// Chainlink OCR2 store program
+pub const CHAINLINK_STORE_PROGRAM_ID: Pubkey =
+    pubkey!("HEvSKofvBgfaexv23kMabbYqxasxU3mQ4ibBMEmJWHny");
+
+/// Anchor discriminator of the store `Transmissions` account
+pub const TRANSMISSIONS_DISCRIMINATOR: [u8; 8] = [96, 179, 69, 66, 128, 129, 73, 117];
+
+/// Space reserved for `TransmissionsHeader` after the discriminator
+pub const HEADER_SIZE: usize = 192;
+
+/// Size of one `Transmission` in the ring-buffer following the header
+pub const TRANSMISSION_SIZE: usize = 48;
+
+/// Header of the store feed (`Transmissions`) account.
+#[derive(Clone, Debug, Default, PartialEq, BorshDeserialize)]
+pub struct TransmissionsHeader {
+    pub version: u8,
+    pub state: u8,
+    pub owner: Pubkey,
+    pub proposed_owner: Pubkey,
+    pub writer: Pubkey,
+    /// Raw UTF-8 byte string
+    pub description: [u8; 32],
+    /// Decimals of `Transmission::answer`
+    pub decimals: u8,
+    pub flagging_threshold: u32,
+    /// Zero if nothing has been transmitted yet
+    pub latest_round_id: u32,
+    pub granularity: u8,
+    /// Capacity of the live ring-buffer
+    pub live_length: u32,
+    /// Index of the next transmission to be written in the live ring-buffer
+    pub live_cursor: u32,
+    pub historical_cursor: u32,
+}
+
+/// One round of the store feed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, BorshDeserialize)]
+pub struct Transmission {
+    pub slot: u64,
+    /// UTC unix-timestamp of the round
+    pub timestamp: u32,
+    pub _padding0: u32,
+    pub answer: i128,
+    pub _padding1: u64,
+    pub _padding2: u64,
+}
+
+/// Store feed account: header and the latest round from the live ring-buffer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Transmissions {
+    pub header: TransmissionsHeader,
+    /// None if nothing has been transmitted yet
+    pub latest: Option<Transmission>,
+}
+
+impl Transmissions {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, SerializeError> {
+        if data.len() < TRANSMISSIONS_DISCRIMINATOR.len() {
+            return Err(SerializeError::NotEnoughData);
+        }
+        let (discriminator, data) = data.split_at(TRANSMISSIONS_DISCRIMINATOR.len());
+        if discriminator != TRANSMISSIONS_DISCRIMINATOR {
+            return Err(SerializeError::Invalid);
+        }
+        if data.len() < HEADER_SIZE {
+            return Err(SerializeError::NotEnoughData);
+        }
+
+        let (header, live) = data.split_at(HEADER_SIZE);
+        let header = TransmissionsHeader::deserialize(&mut &header[..])?;
+
+        if header.latest_round_id == 0 || header.live_length == 0 {
+            return Ok(Self {
+                header,
+                latest: None,
+            });
+        }
+
+        // Cursor points to the next slot to write, so the latest round is right before it.
+        let live_length = header.live_length as usize;
+        let index = (header.live_cursor as usize + live_length - 1) % live_length;
+        let offset = index * TRANSMISSION_SIZE;
+        let mut transmission = live
+            .get(offset..offset + TRANSMISSION_SIZE)
+            .ok_or(SerializeError::NotEnoughData)?;
+        let latest = Transmission::deserialize(&mut transmission)?;
+
+        Ok(Self {
+            header,
+            latest: Some(latest),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transmissions_bytes(
+        decimals: u8,
+        latest_round_id: u32,
+        live_cursor: u32,
+        answers: &[(u32, i128)],
+    ) -> Vec<u8> {
+        let mut data = TRANSMISSIONS_DISCRIMINATOR.to_vec();
+
+        let mut header = vec![1, 2]; // version, state
+        header.extend([0; 32 * 3]); // owner, proposed_owner, writer
+        header.extend([0; 32]); // description
+        header.push(decimals);
+        header.extend(0_u32.to_le_bytes()); // flagging_threshold
+        header.extend(latest_round_id.to_le_bytes());
+        header.push(0); // granularity
+        header.extend((answers.len() as u32).to_le_bytes()); // live_length
+        header.extend(live_cursor.to_le_bytes());
+        header.extend(0_u32.to_le_bytes()); // historical_cursor
+        header.resize(HEADER_SIZE, 0);
+        data.extend(header);
+
+        for (timestamp, answer) in answers {
+            data.extend(1_u64.to_le_bytes()); // slot
+            data.extend(timestamp.to_le_bytes());
+            data.extend([0; 4]);
+            data.extend(answer.to_le_bytes());
+            data.extend([0; 16]);
+        }
+        data
+    }
+
+    #[test]
+    fn latest_round() {
+        let data = transmissions_bytes(8, 3, 2, &[(10, 100), (20, 200), (5, 50)]);
+        let transmissions = Transmissions::try_from_bytes(&data).unwrap();
+        assert_eq!(transmissions.header.decimals, 8);
+        let latest = transmissions.latest.unwrap();
+        assert_eq!(latest.timestamp, 20);
+        assert_eq!(latest.answer, 200);
+    }
+
+    #[test]
+    fn latest_round_wraparound() {
+        let data = transmissions_bytes(8, 4, 0, &[(10, 100), (20, 200), (30, 300)]);
+        let latest = Transmissions::try_from_bytes(&data)
+            .unwrap()
+            .latest
+            .unwrap();
+        assert_eq!(latest.timestamp, 30);
+        assert_eq!(latest.answer, 300);
+    }
+
+    #[test]
+    fn no_rounds() {
+        let data = transmissions_bytes(8, 0, 0, &[(0, 0)]);
+        let transmissions = Transmissions::try_from_bytes(&data).unwrap();
+        assert_eq!(transmissions.latest, None);
+    }
+
+    #[test]
+    fn invalid_discriminator() {
+        let mut data = transmissions_bytes(8, 1, 1, &[(10, 100)]);
+        data[0] = 0;
+        assert!(matches!(
+            Transmissions::try_from_bytes(&data),
+            Err(SerializeError::Invalid)
+        ));
+    }
+}
@@ -0,0 +1,238 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use solana_program::pubkey::Pubkey;
+
+use texture_common::account::{PodAccount, PodAccountError};
+
+use crate::state::price_feed::{PriceFeedSource, TransformOp};
+use crate::state::COMPOSITE_FEED_DISCRIMINATOR;
+
+#[cfg(feature = "with-serde")]
+use super::utils::with_serde::{
+    array_as_str_serde, As, Deserialize, DisplayAsJsonPretty, DisplayFromStr, FromInto, Serialize,
+};
+
+/// Maximum number of legs in `CompositeFeed`
+pub const MAX_COMPOSITE_LEGS: usize = 8;
+
+static_assertions::const_assert_eq!(CompositeFeed::SIZE, std::mem::size_of::<CompositeFeed>());
+static_assertions::const_assert_eq!(0, std::mem::size_of::<CompositeFeed>() % 8);
+
+/// One leg of `CompositeFeed` as passed to instructions.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq)]
+pub struct CompositeLegParams {
+    pub source: PriceFeedSource,
+    /// Source account address. The same as `PriceFeed::source_address` for the given source.
+    pub address: Pubkey,
+    /// How the leg price is applied to the product of the previous legs.
+    pub op: TransformOp,
+}
+
+impl CompositeLegParams {
+    pub fn new(
+        source: impl Into<PriceFeedSource>,
+        address: Pubkey,
+        op: impl Into<TransformOp>,
+    ) -> Self {
+        Self {
+            source: source.into(),
+            address,
+            op: op.into(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Pod, Zeroable, PartialEq)]
+#[cfg_attr(
+    feature = "with-serde",
+    derive(Serialize, Deserialize),
+    serde_with::serde_as
+)]
+#[repr(C)]
+pub struct CompositeLeg {
+    /// See `PriceFeedSource`
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(rename = "source", with = "As::<FromInto<PriceFeedSource>>")
+    )]
+    pub source_raw: u8,
+
+    /// See `TransformOp`
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(rename = "op", with = "As::<FromInto<TransformOp>>")
+    )]
+    pub op_raw: u8,
+
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub _padding: [u8; 6],
+
+    #[cfg_attr(feature = "with-serde", serde(with = "As::<DisplayFromStr>"))]
+    pub address: Pubkey,
+}
+
+impl CompositeLeg {
+    pub fn source(&self) -> PriceFeedSource {
+        self.source_raw.into()
+    }
+
+    pub fn op(&self) -> TransformOp {
+        self.op_raw.into()
+    }
+}
+
+impl From<CompositeLegParams> for CompositeLeg {
+    fn from(params: CompositeLegParams) -> Self {
+        Self {
+            source_raw: params.source as u8,
+            op_raw: params.op as u8,
+            _padding: Zeroable::zeroed(),
+            address: params.address,
+        }
+    }
+}
+
+/// Price path of up to `MAX_COMPOSITE_LEGS` legs. `PriceFeed` of Composite or Median type refers
+/// to it with its `source_address`.
+/// Composite: price is computed starting from 1 by applying legs in order,
+/// e.g. LP/USD = 1 * LP/LST * LST/SOL * SOL/USD.
+/// Median: price is the median of the legs' prices which are fresh. Legs' ops are ignored.
+#[derive(Clone, Copy, Debug, Pod, Zeroable, PartialEq)]
+#[cfg_attr(
+    feature = "with-serde",
+    derive(Serialize, Deserialize, DisplayAsJsonPretty),
+    serde_with::serde_as
+)]
+#[repr(C)]
+pub struct CompositeFeed {
+    #[cfg_attr(feature = "with-serde", serde(with = "array_as_str_serde"))]
+    pub discriminator: [u8; 8],
+    pub version: u8,
+
+    /// Number of used entries in `legs`
+    pub legs_count: u8,
+
+    /// Minimum number of fresh legs required to update Median feed, 0 - all legs. Not used by
+    /// Composite feed which requires all legs.
+    pub quorum: u8,
+
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub _padding0: [u8; 5],
+
+    /// someone who created this composite feed and who can change and delete it.
+    #[cfg_attr(feature = "with-serde", serde(with = "As::<DisplayFromStr>"))]
+    pub authority: Pubkey,
+
+    pub legs: [CompositeLeg; MAX_COMPOSITE_LEGS],
+
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(skip, default = "Zeroable::zeroed"),
+        serde_as(as = "Bytes")
+    )]
+    pub _padding: [u8; 64],
+}
+
+impl CompositeFeed {
+    pub fn new(authority: Pubkey, legs: &[CompositeLegParams], quorum: u8) -> Self {
+        Self::from_init_params((authority, legs.to_vec(), quorum))
+    }
+
+    /// Legs in use
+    pub fn legs(&self) -> &[CompositeLeg] {
+        let count = (self.legs_count as usize).min(MAX_COMPOSITE_LEGS);
+        &self.legs[..count]
+    }
+
+    /// Replaces legs. Legs beyond `MAX_COMPOSITE_LEGS` are ignored so the caller must
+    /// validate their count.
+    pub fn set_legs(&mut self, legs: &[CompositeLegParams]) {
+        (self.legs_count, self.legs) = legs_from_params(legs);
+    }
+}
+
+fn legs_from_params(params: &[CompositeLegParams]) -> (u8, [CompositeLeg; MAX_COMPOSITE_LEGS]) {
+    let count = params.len().min(MAX_COMPOSITE_LEGS);
+    let mut legs: [CompositeLeg; MAX_COMPOSITE_LEGS] = Zeroable::zeroed();
+    for (leg, params) in legs.iter_mut().zip(&params[..count]) {
+        *leg = (*params).into();
+    }
+    (count as u8, legs)
+}
+
+impl PodAccount for CompositeFeed {
+    const DISCRIMINATOR: &'static [u8] = COMPOSITE_FEED_DISCRIMINATOR;
+
+    type Version = u8;
+
+    const VERSION: Self::Version = 1;
+
+    type InitParams = (
+        /*authority:*/ Pubkey,
+        /*legs:*/ Vec<CompositeLegParams>,
+        /*quorum:*/ u8,
+    );
+
+    type InitError = PodAccountError;
+
+    fn discriminator(&self) -> &[u8] {
+        &self.discriminator
+    }
+
+    fn version(&self) -> Self::Version {
+        self.version
+    }
+
+    fn init_unckecked(
+        &mut self,
+        (update_authority, leg_params, median_quorum): Self::InitParams,
+    ) -> Result<(), Self::InitError> {
+        let Self {
+            discriminator,
+            version,
+            legs_count,
+            quorum,
+            _padding0,
+            authority,
+            legs,
+            _padding,
+        } = self;
+
+        *discriminator = *COMPOSITE_FEED_DISCRIMINATOR;
+        *version = Self::VERSION;
+        *_padding0 = Zeroable::zeroed();
+        *authority = update_authority;
+        (*legs_count, *legs) = legs_from_params(&leg_params);
+        *quorum = median_quorum;
+        *_padding = Zeroable::zeroed();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_legs() {
+        let lst_pool = Pubkey::new_unique();
+        let sol_feed = Pubkey::new_unique();
+        let mut composite = CompositeFeed::new(
+            Pubkey::new_unique(),
+            &[
+                CompositeLegParams::new("stake-pool", lst_pool, "multiply"),
+                CompositeLegParams::new("pyth", sol_feed, "multiply"),
+            ],
+            0,
+        );
+        assert_eq!(composite.legs().len(), 2);
+        assert_eq!(composite.legs()[0].source(), PriceFeedSource::StakePool);
+        assert_eq!(composite.legs()[1].address, sol_feed);
+
+        composite.set_legs(&[CompositeLegParams::new("pyth", sol_feed, "divide")]);
+        assert_eq!(composite.legs().len(), 1);
+        assert_eq!(composite.legs()[0].op(), TransformOp::Divide);
+        assert_eq!(composite.legs[1], CompositeLeg::zeroed());
+    }
+}
@@ -0,0 +1,157 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey;
+use solana_program::pubkey::Pubkey;
+use texture_common::math::{CheckedDiv, Decimal, MathResult};
+
+use crate::error::SerializeError;
+
+/// Marinade liquid staking program
+pub const MARINADE_PROGRAM_ID: Pubkey = pubkey!("MarBmsSgKXdrN1egZf5sqe1TMai9K1rChYNDJgjq7aD");
+
+/// Anchor discriminator of the `State` account
+pub const STATE_DISCRIMINATOR: [u8; 8] = [216, 146, 107, 94, 104, 75, 182, 177];
+
+/// Denominator of `State::msol_price` fixed point value
+pub const PRICE_DENOMINATOR: u64 = 0x1_0000_0000;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct Fee {
+    pub basis_points: u32,
+}
+
+/// Reference to a list account
+#[derive(Clone, Copy, Debug, Default, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct List {
+    pub account: Pubkey,
+    pub item_size: u32,
+    pub count: u32,
+    pub reserved1: Pubkey,
+    pub reserved2: u32,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct StakeSystem {
+    pub stake_list: List,
+    pub delayed_unstake_cooling_down: u64,
+    pub stake_deposit_bump_seed: u8,
+    pub stake_withdraw_bump_seed: u8,
+    /// Set by admin, how much slots before the end of the epoch, stake-delta can start
+    pub slots_for_stake_delta: u64,
+    /// Epoch of the latest stake-delta run, i.e. when `State::msol_price` was updated
+    pub last_stake_delta_epoch: u64,
+    pub min_stake: u64,
+    pub extra_stake_delta_runs: u32,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct ValidatorSystem {
+    pub validator_list: List,
+    pub manager_authority: Pubkey,
+    pub total_validator_score: u32,
+    pub total_active_balance: u64,
+    pub auto_add_validator_enabled: u8,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct LiqPool {
+    pub lp_mint: Pubkey,
+    pub lp_mint_authority_bump_seed: u8,
+    pub sol_leg_bump_seed: u8,
+    pub msol_leg_authority_bump_seed: u8,
+    pub msol_leg: Pubkey,
+    pub lp_liquidity_target: u64,
+    pub lp_max_fee: Fee,
+    pub lp_min_fee: Fee,
+    pub treasury_cut: Fee,
+    pub lp_supply: u64,
+    pub lent_from_sol_leg: u64,
+    pub liquidity_sol_cap: u64,
+}
+
+/// Leading part of the Marinade `State` account up to `msol_price`. Rest of the account
+/// is not decoded.
+#[derive(Clone, Debug, Default, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct State {
+    pub msol_mint: Pubkey,
+    pub admin_authority: Pubkey,
+    pub operational_sol_account: Pubkey,
+    pub treasury_msol_account: Pubkey,
+    pub reserve_bump_seed: u8,
+    pub msol_mint_authority_bump_seed: u8,
+    pub rent_exempt_for_token_acc: u64,
+    pub reward_fee: Fee,
+    pub stake_system: StakeSystem,
+    pub validator_system: ValidatorSystem,
+    pub liq_pool: LiqPool,
+    pub available_reserve_balance: u64,
+    pub msol_supply: u64,
+    /// Price of one mSOL in SOL, fixed point with `PRICE_DENOMINATOR`
+    pub msol_price: u64,
+}
+
+impl State {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, SerializeError> {
+        if data.len() < STATE_DISCRIMINATOR.len() {
+            return Err(SerializeError::NotEnoughData);
+        }
+        let (discriminator, mut data) = data.split_at(STATE_DISCRIMINATOR.len());
+        if discriminator != STATE_DISCRIMINATOR {
+            return Err(SerializeError::Invalid);
+        }
+
+        Ok(Self::deserialize(&mut data)?)
+    }
+
+    /// Epoch `msol_price` was updated in
+    pub fn last_update_epoch(&self) -> u64 {
+        self.stake_system.last_stake_delta_epoch
+    }
+
+    /// Price of one mSOL in SOL
+    pub fn msol_price(&self) -> MathResult<Decimal> {
+        Decimal::from_i128_with_scale(self.msol_price as i128, 0)?
+            .checked_div(Decimal::from_i128_with_scale(PRICE_DENOMINATOR as i128, 0)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode() {
+        let state = State {
+            msol_mint: Pubkey::new_unique(),
+            stake_system: StakeSystem {
+                last_stake_delta_epoch: 650,
+                ..Default::default()
+            },
+            msol_supply: 1_000,
+            // 1.25 SOL
+            msol_price: PRICE_DENOMINATOR + PRICE_DENOMINATOR / 4,
+            ..Default::default()
+        };
+        let mut data = STATE_DISCRIMINATOR.to_vec();
+        data.extend(borsh::to_vec(&state).unwrap());
+        // Tail of the account which is not decoded
+        data.extend([0; 256]);
+
+        let decoded = State::try_from_bytes(&data).unwrap();
+        assert_eq!(decoded, state);
+        assert_eq!(decoded.last_update_epoch(), 650);
+        assert_eq!(
+            decoded.msol_price().unwrap(),
+            Decimal::from_i128_with_scale(125, 2).unwrap()
+        );
+    }
+
+    #[test]
+    fn invalid_discriminator() {
+        let mut data = vec![0; 8];
+        data.extend(borsh::to_vec(&State::default()).unwrap());
+        assert!(matches!(
+            State::try_from_bytes(&data),
+            Err(SerializeError::Invalid)
+        ));
+    }
+}
@@ -1,8 +1,15 @@
 pub use texture_common::account as texture_account;
 
+pub mod chainlink;
+pub mod composite_feed;
+pub mod marinade;
 pub mod price_feed;
+pub mod price_history;
 
 pub mod stake_pool;
+pub mod switchboard_on_demand;
 pub mod utils;
 
 pub const PRICE_FEED_DISCRIMINATOR: &[u8; 8] = b"PRICEEED";
+pub const COMPOSITE_FEED_DISCRIMINATOR: &[u8; 8] = b"COMPFEED";
+pub const PRICE_HISTORY_DISCRIMINATOR: &[u8; 8] = b"PRICEHIS";
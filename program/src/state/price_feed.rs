@@ -8,7 +8,7 @@ use solana_program::pubkey::Pubkey;
 
 use texture_common::{
     account::{PodAccount, PodAccountError},
-    math::{Decimal, MathResult},
+    math::{CheckedAdd, CheckedDiv, CheckedMul, Decimal, MathResult},
 };
 
 use crate::state::PRICE_FEED_DISCRIMINATOR;
@@ -21,6 +21,20 @@ use super::utils::with_serde::{
 
 pub const SYMBOL_MAX_SIZE: usize = 16;
 pub const LOGO_URL_MAX_LEN: usize = 128;
+/// Number of cumulative price checkpoints kept by `PriceFeed` for TWAP
+pub const TWAP_CHECKPOINTS: usize = 24;
+/// Minimum time between two TWAP checkpoints. Together with `TWAP_CHECKPOINTS` bounds
+/// the longest TWAP window.
+pub const TWAP_CHECKPOINT_INTERVAL_SEC: i64 = 300;
+/// Maximum number of off-chain writers authorized to `WritePrice` into one `PriceFeed`
+pub const MAX_WRITERS: usize = 4;
+/// How far `WritePrice` timestamp may be ahead of the cluster clock when `max_future_sec` is zero
+pub const DEFAULT_MAX_FUTURE_SEC: u32 = 120;
+/// `max_future_sec` which removes the limit of `WritePrice` timestamp
+pub const UNLIMITED_MAX_FUTURE_SEC: u32 = u32::MAX;
+/// How long primary source must produce no price before `UpdatePrice` may use the fallback
+/// source when `fallback_after_sec` is zero
+pub const DEFAULT_FALLBACK_AFTER_SEC: u32 = 300;
 
 static_assertions::const_assert_eq!(PriceFeed::SIZE, std::mem::size_of::<PriceFeed>());
 static_assertions::const_assert_eq!(0, std::mem::size_of::<PriceFeed>() % 8);
@@ -52,6 +66,16 @@ super::utils::source_enum_from_str_derive_infallibale!(
         SuperLendy,
         #[from_str("st", "stake-pool", "stakepool")]
         StakePool,
+        #[from_str("c", "chainlink")]
+        Chainlink,
+        #[from_str("so", "switchboard-on-demand", "switchboardondemand")]
+        SwitchboardOnDemand,
+        #[from_str("stn", "stake-pool-net", "stakepoolnet")]
+        StakePoolNetOfFee,
+        #[from_str("m", "marinade")]
+        Marinade,
+        #[from_str("ss", "sanctum-spl", "sanctumspl")]
+        SanctumSpl,
     }
 );
 
@@ -209,6 +233,10 @@ pub enum FeedType {
     #[num_enum(default)]
     Direct = 0,
     Transform,
+    /// Price is computed by `CompositeFeed` legs. `source_address` is the `CompositeFeed`.
+    Composite,
+    /// Price is the median of fresh `CompositeFeed` legs. `source_address` is the `CompositeFeed`.
+    Median,
 }
 
 impl FromStr for FeedType {
@@ -218,6 +246,8 @@ impl FromStr for FeedType {
         match s.as_str() {
             "direct" | "f" => Ok(Self::Direct),
             "transform" | "p" => Ok(Self::Transform),
+            "composite" | "c" => Ok(Self::Composite),
+            "median" | "md" => Ok(Self::Median),
             _ => Err(format!("`{}` is not a valid type", s)),
         }
     }
@@ -248,6 +278,118 @@ impl<'de> serde::Deserialize<'de> for FeedType {
     }
 }
 
+/// How Transform feed combines prices of its two sources.
+#[derive(
+    Debug,
+    Display,
+    Clone,
+    Copy,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    FromPrimitive,
+    IntoPrimitive,
+)]
+#[borsh(use_discriminant = false)]
+#[repr(u8)]
+pub enum TransformOp {
+    /// `source * transform_source`, e.g. JitoSOL/USD = JitoSOL/SOL * SOL/USD
+    #[num_enum(default)]
+    Multiply = 0,
+    /// `source / transform_source`, e.g. TOKEN/SOL = TOKEN/USD / SOL/USD
+    Divide,
+}
+
+impl FromStr for TransformOp {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.to_lowercase();
+        match s.as_str() {
+            "multiply" | "mul" | "m" => Ok(Self::Multiply),
+            "divide" | "div" | "d" => Ok(Self::Divide),
+            _ => Err(format!("`{}` is not a valid transform op", s)),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TransformOp {
+    fn from(value: &'a str) -> Self {
+        TransformOp::from_str(value).unwrap()
+    }
+}
+
+#[cfg(feature = "with-serde")]
+impl serde::Serialize for TransformOp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        display_from_str_serde::serialize(self, serializer)
+    }
+}
+#[cfg(feature = "with-serde")]
+impl<'de> serde::Deserialize<'de> for TransformOp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        display_from_str_serde::deserialize(deserializer)
+    }
+}
+
+/// Which of the feed's sources produced its current price.
+#[derive(
+    Debug,
+    Display,
+    Clone,
+    Copy,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    FromPrimitive,
+    IntoPrimitive,
+)]
+#[borsh(use_discriminant = false)]
+#[repr(u8)]
+pub enum ActiveSource {
+    /// `source` (and `transform_source` for Transform feed)
+    #[num_enum(default)]
+    Primary = 0,
+    /// `fallback_source` used instead of `source`
+    Fallback,
+}
+
+impl FromStr for ActiveSource {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.to_lowercase();
+        match s.as_str() {
+            "primary" => Ok(Self::Primary),
+            "fallback" => Ok(Self::Fallback),
+            _ => Err(format!("`{}` is not a valid active source", s)),
+        }
+    }
+}
+
+#[cfg(feature = "with-serde")]
+impl serde::Serialize for ActiveSource {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        display_from_str_serde::serialize(self, serializer)
+    }
+}
+#[cfg(feature = "with-serde")]
+impl<'de> serde::Deserialize<'de> for ActiveSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        display_from_str_serde::deserialize(deserializer)
+    }
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy)]
 pub struct PriceFeedParams {
     pub feed_type: FeedType,
@@ -257,6 +399,41 @@ pub struct PriceFeedParams {
     pub logo_url: [u8; LOGO_URL_MAX_LEN],
     pub source: PriceFeedSource,
     pub transform_source: PriceFeedSource,
+    /// Maximum allowed `confidence / price` ratio in basis points. Zero disables the check.
+    pub max_confidence_bps: u16,
+    /// For Transform feed type only. See `TransformOp`.
+    pub transform_op: TransformOp,
+    /// Source used by `UpdatePrice` when `source` is stale. `Unknown` disables fallback.
+    /// For Direct and Transform feed types only.
+    pub fallback_source: PriceFeedSource,
+    pub fallback_source_address: Pubkey,
+    /// Fallback source is used only when primary source produced no price for this number of
+    /// seconds. Zero - `DEFAULT_FALLBACK_AFTER_SEC`.
+    pub fallback_after_sec: u32,
+    /// Source which `UpdatePrice` compares the price with. `Unknown` disables the check.
+    pub reference_source: PriceFeedSource,
+    pub reference_source_address: Pubkey,
+    /// Maximum allowed divergence of the price from the reference price in basis points.
+    pub max_divergence_bps: u16,
+    /// Maximum allowed move of the price within `deviation_window_sec` in basis points.
+    /// Zero disables the circuit breaker.
+    pub max_deviation_bps: u16,
+    pub deviation_window_sec: u32,
+    /// Lowest price accepted by `WritePrice` and `UpdatePrice`. Must be positive.
+    pub min_price: Option<Decimal>,
+    /// Highest price accepted by `WritePrice` and `UpdatePrice`.
+    pub max_price: Option<Decimal>,
+    /// Half-life in seconds of the EMA computed on-chain for sources without own EMA.
+    /// Zero - EMA follows the price.
+    pub ema_half_life_sec: u32,
+    /// Minimum number of fresh writer submissions the price of OffChain feed is the median of.
+    /// Zero - every `WritePrice` sets the price.
+    pub writer_quorum: u8,
+    /// Submissions older than this number of seconds are not counted for `writer_quorum`.
+    pub submission_window_sec: u32,
+    /// Maximum number of seconds `WritePrice` timestamp may be ahead of the cluster clock.
+    /// Zero - `DEFAULT_MAX_FUTURE_SEC`, `UNLIMITED_MAX_FUTURE_SEC` - not limited.
+    pub max_future_sec: u32,
 }
 
 impl PriceFeedParams {
@@ -277,8 +454,173 @@ impl PriceFeedParams {
             logo_url: super::utils::str_to_array(logo_url),
             source: source.into(),
             transform_source: transform_source.into(),
+            max_confidence_bps: 0,
+            transform_op: TransformOp::Multiply,
+            fallback_source: PriceFeedSource::Unknown,
+            fallback_source_address: Pubkey::default(),
+            fallback_after_sec: 0,
+            reference_source: PriceFeedSource::Unknown,
+            reference_source_address: Pubkey::default(),
+            max_divergence_bps: 0,
+            max_deviation_bps: 0,
+            deviation_window_sec: 0,
+            min_price: None,
+            max_price: None,
+            ema_half_life_sec: 0,
+            writer_quorum: 0,
+            submission_window_sec: 0,
+            max_future_sec: 0,
         }
     }
+
+    pub fn with_max_confidence_bps(mut self, max_confidence_bps: u16) -> Self {
+        self.max_confidence_bps = max_confidence_bps;
+        self
+    }
+
+    pub fn with_transform_op(mut self, transform_op: impl Into<TransformOp>) -> Self {
+        self.transform_op = transform_op.into();
+        self
+    }
+
+    pub fn with_fallback(
+        mut self,
+        fallback_source: impl Into<PriceFeedSource>,
+        fallback_source_address: Pubkey,
+    ) -> Self {
+        self.fallback_source = fallback_source.into();
+        self.fallback_source_address = fallback_source_address;
+        self
+    }
+
+    pub fn with_fallback_after(mut self, fallback_after_sec: u32) -> Self {
+        self.fallback_after_sec = fallback_after_sec;
+        self
+    }
+
+    pub fn with_reference(
+        mut self,
+        reference_source: impl Into<PriceFeedSource>,
+        reference_source_address: Pubkey,
+        max_divergence_bps: u16,
+    ) -> Self {
+        self.reference_source = reference_source.into();
+        self.reference_source_address = reference_source_address;
+        self.max_divergence_bps = max_divergence_bps;
+        self
+    }
+
+    pub fn with_circuit_breaker(
+        mut self,
+        max_deviation_bps: u16,
+        deviation_window_sec: u32,
+    ) -> Self {
+        self.max_deviation_bps = max_deviation_bps;
+        self.deviation_window_sec = deviation_window_sec;
+        self
+    }
+
+    pub fn with_price_bounds(
+        mut self,
+        min_price: Option<Decimal>,
+        max_price: Option<Decimal>,
+    ) -> Self {
+        self.min_price = min_price;
+        self.max_price = max_price;
+        self
+    }
+
+    pub fn with_ema_half_life(mut self, ema_half_life_sec: u32) -> Self {
+        self.ema_half_life_sec = ema_half_life_sec;
+        self
+    }
+
+    pub fn with_writer_quorum(mut self, writer_quorum: u8, submission_window_sec: u32) -> Self {
+        self.writer_quorum = writer_quorum;
+        self.submission_window_sec = submission_window_sec;
+        self
+    }
+
+    pub fn with_max_future(mut self, max_future_sec: u32) -> Self {
+        self.max_future_sec = max_future_sec;
+        self
+    }
+}
+
+/// Cumulative price of `PriceFeed` recorded at `timestamp`.
+#[derive(Clone, Copy, Debug, Pod, Zeroable, PartialEq)]
+#[cfg_attr(
+    feature = "with-serde",
+    derive(Serialize, Deserialize),
+    serde_with::serde_as
+)]
+#[repr(C)]
+pub struct TwapCheckpoint {
+    /// sum of price × seconds it was held, decimal with scale 18.
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(rename = "cumulative_price", with = "decimal_bits_serde")
+    )]
+    pub cumulative_price_raw: i128,
+
+    /// UTC unix-timestamp. Zero - empty checkpoint.
+    pub timestamp: i64,
+
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub _padding: [u8; 8],
+}
+
+impl TwapCheckpoint {
+    pub fn is_empty(&self) -> bool {
+        self.timestamp == 0
+    }
+}
+
+/// State of `PriceFeed` writer slot: the latest price submitted by the writer of multi-writer
+/// feed, generation of the writer and the nonce of its latest signed price.
+#[derive(Clone, Copy, Debug, Pod, Zeroable, PartialEq)]
+#[cfg_attr(
+    feature = "with-serde",
+    derive(Serialize, Deserialize),
+    serde_with::serde_as
+)]
+#[repr(C)]
+pub struct WriterSubmission {
+    /// decimal with scale 18.
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(rename = "price", with = "decimal_bits_serde")
+    )]
+    pub price_raw: i128,
+
+    /// UTC unix-timestamp of price. Zero - nothing submitted.
+    pub timestamp: i64,
+
+    /// nonce of the last price of the writer relayed by `WriteSignedPrice`. A signed price is
+    /// accepted only with greater nonce, so it can't be replayed. Reset when a writer is added.
+    pub signed_price_nonce: u64,
+
+    /// `PriceFeed::writer_generation` at which the writer was added. Signed price must carry it,
+    /// so prices signed for the slot before the writer was added can't be replayed.
+    pub generation: u32,
+
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub _padding: [u8; 12],
+}
+
+impl WriterSubmission {
+    pub fn is_empty(&self) -> bool {
+        self.timestamp == 0
+    }
+
+    pub fn try_price(&self) -> MathResult<Decimal> {
+        Decimal::from_bits(self.price_raw)
+    }
+
+    /// Whether the submission is not older than `window_sec` at `now`.
+    pub fn is_fresh(&self, now: i64, window_sec: u32) -> bool {
+        !self.is_empty() && now.saturating_sub(self.timestamp) <= window_sec as i64
+    }
 }
 
 #[derive(Clone, Copy, Debug, Pod, Zeroable, PartialEq)]
@@ -296,7 +638,7 @@ pub struct PriceFeed {
     #[cfg_attr(feature = "with-serde", serde(skip))]
     pub _padding0: [u8; 2],
 
-    /// Direct or Transform, which get price from two sources. See `FeedType`.
+    /// Direct, Transform, which get price from two sources, Composite or Median. See `FeedType`.
     #[cfg_attr(
         feature = "with-serde",
         serde(rename = "feed_type", with = "As::<FromInto<FeedType>>")
@@ -370,12 +712,190 @@ pub struct PriceFeed {
     )]
     pub price_raw: i128,
 
+    /// confidence interval of `price_raw` (price is expected to lie within `price ± confidence`),
+    /// decimal with scale 18. Zero when the source doesn't provide one.
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(rename = "confidence", with = "decimal_bits_serde")
+    )]
+    pub confidence_raw: i128,
+
+    /// Maximum allowed `confidence / price` ratio in basis points for `UpdatePrice`.
+    /// Zero disables the check.
+    pub max_confidence_bps: u16,
+
+    /// How prices of source and transform source are combined. See `TransformOp`.
+    /// For Transform feed type only.
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(rename = "transform_op", with = "As::<FromInto<TransformOp>>")
+    )]
+    pub transform_op: u8,
+
+    /// Source used by `UpdatePrice` when `source` is stale. See `PriceFeedSource`.
+    /// `Unknown` - no fallback. For Direct and Transform feed types only.
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(rename = "fallback_source", with = "As::<FromInto<PriceFeedSource>>")
+    )]
+    pub fallback_source_raw: u8,
+
+    /// Source which produced the current price. See `ActiveSource`.
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(rename = "active_source", with = "As::<FromInto<ActiveSource>>")
+    )]
+    pub active_source: u8,
+
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub _padding1: [u8; 3],
+
+    /// address of the fallback source account, the same as `source_address` for given source.
+    #[cfg_attr(feature = "with-serde", serde(with = "As::<DisplayFromStr>"))]
+    pub fallback_source_address: Pubkey,
+
+    /// Source which `UpdatePrice` compares the price with, rejecting the price when they diverge
+    /// by more than `max_divergence_bps`. See `PriceFeedSource`. `Unknown` - no reference.
     #[cfg_attr(
         feature = "with-serde",
-        serde(skip, default = "Zeroable::zeroed"),
-        serde_as(as = "Bytes")
+        serde(rename = "reference_source", with = "As::<FromInto<PriceFeedSource>>")
     )]
-    pub _padding: [u8; 128],
+    pub reference_source_raw: u8,
+
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub _padding2: [u8; 1],
+
+    /// Maximum allowed `|price - reference price| / reference price` ratio in basis points.
+    pub max_divergence_bps: u16,
+
+    /// address of the reference source account, the same as `source_address` for given source.
+    #[cfg_attr(feature = "with-serde", serde(with = "As::<DisplayFromStr>"))]
+    pub reference_source_address: Pubkey,
+
+    /// Maximum allowed `|price - previous price| / previous price` ratio in basis points for
+    /// updates within `deviation_window_sec` since the previous price. Zero disables the check.
+    pub max_deviation_bps: u16,
+
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub _padding3: [u8; 2],
+
+    /// Window in seconds, in which the price may not move more than `max_deviation_bps`.
+    pub deviation_window_sec: u32,
+
+    /// Maximum number of seconds `WritePrice` timestamp may be ahead of the cluster clock.
+    /// Zero - `DEFAULT_MAX_FUTURE_SEC`, so feeds created before the field existed are limited too.
+    /// `UNLIMITED_MAX_FUTURE_SEC` - not limited.
+    pub max_future_sec: u32,
+
+    /// UTC unix-timestamp of the last price taken from the primary source before the feed
+    /// switched to the fallback source. See `last_primary_timestamp`.
+    #[cfg_attr(feature = "with-serde", serde(with = "timestamp_as_datetime_serde"))]
+    pub primary_timestamp: i64,
+
+    /// lowest accepted price, decimal with scale 18. Zero - no floor.
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(rename = "min_price", with = "decimal_bits_serde")
+    )]
+    pub min_price_raw: i128,
+
+    /// highest accepted price, decimal with scale 18. Zero - no ceiling.
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(rename = "max_price", with = "decimal_bits_serde")
+    )]
+    pub max_price_raw: i128,
+
+    /// exponentially-weighted moving average of the price, decimal with scale 18.
+    /// Taken from Pyth or computed on-chain with `ema_half_life_sec` for other sources.
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(rename = "ema_price", with = "decimal_bits_serde")
+    )]
+    pub ema_price_raw: i128,
+
+    /// exponentially-weighted moving average of the confidence, decimal with scale 18.
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(rename = "ema_conf", with = "decimal_bits_serde")
+    )]
+    pub ema_conf_raw: i128,
+
+    /// Half-life in seconds of the on-chain EMA. Zero - EMA follows the price.
+    pub ema_half_life_sec: u32,
+
+    /// `UpdatePrice` uses the fallback source only when primary source produced no price for
+    /// this number of seconds by the cluster clock. Zero - `DEFAULT_FALLBACK_AFTER_SEC`.
+    pub fallback_after_sec: u32,
+
+    /// UTC unix-timestamp of the last price observation accumulated into `cumulative_price_raw`.
+    #[cfg_attr(feature = "with-serde", serde(with = "timestamp_as_datetime_serde"))]
+    pub twap_timestamp: i64,
+
+    /// sum of price × seconds it was held since the first observation, decimal with scale 18.
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(rename = "cumulative_price", with = "decimal_bits_serde")
+    )]
+    pub cumulative_price_raw: i128,
+
+    /// Index in `twap_checkpoints` of the latest checkpoint.
+    pub twap_checkpoint_index: u8,
+
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub _padding6: [u8; 15],
+
+    /// Ring buffer of cumulative prices recorded at least `TWAP_CHECKPOINT_INTERVAL_SEC` apart.
+    pub twap_checkpoints: [TwapCheckpoint; TWAP_CHECKPOINTS],
+
+    /// `PriceHistory` account recording every price update. Default - no history.
+    #[cfg_attr(feature = "with-serde", serde(with = "As::<DisplayFromStr>"))]
+    pub price_history: Pubkey,
+
+    /// authority proposed by `ProposeAuthority`, becomes `update_authority` once it signs
+    /// `AcceptAuthority`. Default - no transfer pending.
+    #[cfg_attr(feature = "with-serde", serde(with = "As::<DisplayFromStr>"))]
+    pub pending_authority: Pubkey,
+
+    /// keys allowed to `WritePrice` into OffChain feed, managed by `update_authority`.
+    /// Default - empty slot. Until the first writer is added `source_address` is the writer.
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(with = "As::<[DisplayFromStr; MAX_WRITERS]>")
+    )]
+    pub writers: [Pubkey; MAX_WRITERS],
+
+    /// Minimum number of fresh `writer_submissions` the price is the median of.
+    /// Zero - every `WritePrice` sets the price.
+    pub writer_quorum: u8,
+
+    /// Non-zero once any writer was added. From then on only `writers` write price, even when
+    /// all slots are empty, so revoking the last writer doesn't give `source_address` access back.
+    pub writers_enabled: u8,
+
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub _padding7: [u8; 2],
+
+    /// Submissions older than this number of seconds are not counted for `writer_quorum`.
+    pub submission_window_sec: u32,
+
+    /// Number of writers ever added. Each added writer takes the next generation.
+    pub writer_generation: u32,
+
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub _padding8: [u8; 4],
+
+    /// Latest price submitted by each of `writers`, at the same index.
+    pub writer_submissions: [WriterSubmission; MAX_WRITERS],
+
+    /// Price approved by the authority with `OverrideCircuitBreaker`, decimal with scale 18.
+    /// The next update within `max_deviation_bps` of it bypasses the deviation check.
+    /// Zero - not overridden.
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(rename = "deviation_override_price", with = "decimal_bits_serde")
+    )]
+    pub deviation_override_price_raw: i128,
 }
 
 impl PriceFeed {
@@ -398,10 +918,24 @@ impl PriceFeed {
         self
     }
 
+    pub fn with_confidence(mut self, confidence: impl Into<Decimal>) -> Self {
+        self.try_set_confidence(confidence).unwrap();
+        self
+    }
+
     pub fn try_price(&self) -> MathResult<Decimal> {
         Decimal::from_bits(self.price_raw)
     }
 
+    pub fn try_confidence(&self) -> MathResult<Decimal> {
+        Decimal::from_bits(self.confidence_raw)
+    }
+
+    pub fn try_set_confidence(&mut self, confidence: impl Into<Decimal>) -> MathResult<()> {
+        self.confidence_raw = confidence.into().into_bits()?;
+        Ok(())
+    }
+
     pub fn try_set_price(
         &mut self,
         price: impl Into<Decimal>,
@@ -443,6 +977,274 @@ impl PriceFeed {
     pub fn transform_source(&self) -> PriceFeedSource {
         self.transform_source_raw.into()
     }
+
+    pub fn transform_op(&self) -> TransformOp {
+        self.transform_op.into()
+    }
+
+    pub fn fallback_source(&self) -> PriceFeedSource {
+        self.fallback_source_raw.into()
+    }
+
+    pub fn has_fallback(&self) -> bool {
+        self.fallback_source() != PriceFeedSource::Unknown
+    }
+
+    /// Effective `fallback_after_sec`.
+    pub fn fallback_after_sec(&self) -> u32 {
+        match self.fallback_after_sec {
+            0 => DEFAULT_FALLBACK_AFTER_SEC,
+            fallback_after_sec => fallback_after_sec,
+        }
+    }
+
+    /// Timestamp of the latest price taken from the primary source.
+    pub fn last_primary_timestamp(&self) -> i64 {
+        match self.active_source() {
+            ActiveSource::Primary => self.update_timestamp,
+            ActiveSource::Fallback => self.primary_timestamp,
+        }
+    }
+
+    /// Effective `max_future_sec`, `None` when not limited.
+    pub fn max_future_sec(&self) -> Option<u32> {
+        match self.max_future_sec {
+            0 => Some(DEFAULT_MAX_FUTURE_SEC),
+            UNLIMITED_MAX_FUTURE_SEC => None,
+            max_future_sec => Some(max_future_sec),
+        }
+    }
+
+    pub fn active_source(&self) -> ActiveSource {
+        self.active_source.into()
+    }
+
+    pub fn set_active_source(&mut self, active_source: ActiveSource) {
+        self.active_source = active_source as u8;
+    }
+
+    pub fn reference_source(&self) -> PriceFeedSource {
+        self.reference_source_raw.into()
+    }
+
+    pub fn has_reference(&self) -> bool {
+        self.reference_source() != PriceFeedSource::Unknown
+    }
+
+    pub fn try_min_price(&self) -> MathResult<Option<Decimal>> {
+        raw_price_bound(self.min_price_raw)
+    }
+
+    pub fn try_max_price(&self) -> MathResult<Option<Decimal>> {
+        raw_price_bound(self.max_price_raw)
+    }
+
+    pub fn try_set_price_bounds(
+        &mut self,
+        min_price: Option<Decimal>,
+        max_price: Option<Decimal>,
+    ) -> MathResult<()> {
+        self.min_price_raw = price_bound_bits(min_price)?;
+        self.max_price_raw = price_bound_bits(max_price)?;
+        Ok(())
+    }
+
+    pub fn try_ema_price(&self) -> MathResult<Decimal> {
+        Decimal::from_bits(self.ema_price_raw)
+    }
+
+    pub fn try_ema_confidence(&self) -> MathResult<Decimal> {
+        Decimal::from_bits(self.ema_conf_raw)
+    }
+
+    pub fn try_set_ema(
+        &mut self,
+        ema_price: impl Into<Decimal>,
+        ema_confidence: impl Into<Decimal>,
+    ) -> MathResult<()> {
+        self.ema_price_raw = ema_price.into().into_bits()?;
+        self.ema_conf_raw = ema_confidence.into().into_bits()?;
+        Ok(())
+    }
+
+    pub fn try_cumulative_price(&self) -> MathResult<Decimal> {
+        Decimal::from_bits(self.cumulative_price_raw)
+    }
+
+    pub fn latest_twap_checkpoint(&self) -> &TwapCheckpoint {
+        &self.twap_checkpoints[self.twap_checkpoint_index as usize % TWAP_CHECKPOINTS]
+    }
+
+    /// Accumulates the current price held since the last observation till `timestamp`.
+    /// Must be called before the price is updated.
+    pub fn try_accumulate_twap(&mut self, timestamp: i64) -> MathResult<()> {
+        if self.twap_timestamp == 0 {
+            // First observation starts the accumulator
+            self.twap_timestamp = timestamp;
+            self.cumulative_price_raw = 0;
+            self.twap_checkpoint_index = 0;
+            self.twap_checkpoints[0] = TwapCheckpoint {
+                cumulative_price_raw: 0,
+                timestamp,
+                _padding: Zeroable::zeroed(),
+            };
+            return Ok(());
+        }
+        if timestamp <= self.twap_timestamp {
+            return Ok(());
+        }
+
+        let held = Decimal::from_i128_with_scale((timestamp - self.twap_timestamp) as i128, 0)?;
+        let cumulative_price = self
+            .try_cumulative_price()?
+            .checked_add(self.try_price()?.checked_mul(held)?)?;
+        self.cumulative_price_raw = cumulative_price.into_bits()?;
+        self.twap_timestamp = timestamp;
+
+        if timestamp - self.latest_twap_checkpoint().timestamp >= TWAP_CHECKPOINT_INTERVAL_SEC {
+            let index = (self.twap_checkpoint_index as usize + 1) % TWAP_CHECKPOINTS;
+            self.twap_checkpoints[index] = TwapCheckpoint {
+                cumulative_price_raw: self.cumulative_price_raw,
+                timestamp,
+                _padding: Zeroable::zeroed(),
+            };
+            self.twap_checkpoint_index = index as u8;
+        }
+        Ok(())
+    }
+
+    /// Time-weighted average price from the newest checkpoint at least `window_sec` before `now`
+    /// till `now`. The current price is considered held till `now`.
+    /// `None` when checkpoints don't reach that far.
+    pub fn try_twap(&self, now: i64, window_sec: u32) -> MathResult<Option<Decimal>> {
+        let now = now.max(self.twap_timestamp);
+        let window_start = now.saturating_sub(window_sec as i64);
+        let checkpoint = self
+            .twap_checkpoints
+            .iter()
+            .filter(|checkpoint| !checkpoint.is_empty() && checkpoint.timestamp <= window_start)
+            .max_by_key(|checkpoint| checkpoint.timestamp);
+        let checkpoint = match checkpoint {
+            Some(checkpoint) => checkpoint,
+            None => return Ok(None),
+        };
+
+        let price = self.try_price()?;
+        if now == checkpoint.timestamp {
+            return Ok(Some(price));
+        }
+
+        let held = Decimal::from_i128_with_scale((now - self.twap_timestamp) as i128, 0)?;
+        let cumulative_price = self
+            .try_cumulative_price()?
+            .checked_add(price.checked_mul(held)?)?;
+        // Both are decimals with the same scale and cumulative price never decreases
+        let window_cumulative_price =
+            Decimal::from_bits(cumulative_price.into_bits()? - checkpoint.cumulative_price_raw)?;
+        let elapsed = Decimal::from_i128_with_scale((now - checkpoint.timestamp) as i128, 0)?;
+        Ok(Some(window_cumulative_price.checked_div(elapsed)?))
+    }
+
+    pub fn has_price_history(&self) -> bool {
+        self.price_history != Pubkey::default()
+    }
+
+    pub fn has_pending_authority(&self) -> bool {
+        self.pending_authority != Pubkey::default()
+    }
+
+    pub fn is_writers_enabled(&self) -> bool {
+        self.writers_enabled != 0
+    }
+
+    pub fn is_multi_writer(&self) -> bool {
+        self.writer_quorum != 0
+    }
+
+    /// Index of `key` in `writers` and `writer_submissions`.
+    pub fn writer_index(&self, key: &Pubkey) -> Option<usize> {
+        if *key == Pubkey::default() {
+            return None;
+        }
+        self.writers.iter().position(|writer| writer == key)
+    }
+
+    /// Whether `key` may write price into OffChain feed: one of `writers` once any writer was
+    /// added, `source_address` before that.
+    pub fn is_writer(&self, key: &Pubkey) -> bool {
+        if self.is_writers_enabled() {
+            self.writer_index(key).is_some()
+        } else {
+            self.source_address == *key
+        }
+    }
+
+    /// Puts `writer` into the first empty slot with the next generation and zero nonce. Returns
+    /// `false` when there is no empty slot.
+    pub fn add_writer(&mut self, writer: Pubkey) -> bool {
+        match self
+            .writers
+            .iter()
+            .position(|slot| *slot == Pubkey::default())
+        {
+            Some(index) => {
+                self.writers[index] = writer;
+                self.writers_enabled = 1;
+                self.writer_generation = self.writer_generation.wrapping_add(1);
+                let submission = &mut self.writer_submissions[index];
+                submission.generation = self.writer_generation;
+                submission.signed_price_nonce = 0;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Generation of `key` to put into its `SignedPriceMessage`.
+    pub fn writer_generation_of(&self, key: &Pubkey) -> Option<u32> {
+        self.writer_index(key)
+            .map(|index| self.writer_submissions[index].generation)
+    }
+
+    /// Empties slot of `writer` together with its submission. Returns `false` when `writer` is
+    /// not authorized.
+    pub fn remove_writer(&mut self, writer: &Pubkey) -> bool {
+        match self.writer_index(writer) {
+            Some(index) => {
+                self.writers[index] = Pubkey::default();
+                let submission = &mut self.writer_submissions[index];
+                submission.price_raw = 0;
+                submission.timestamp = 0;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn is_deviation_overridden(&self) -> bool {
+        self.deviation_override_price_raw != 0
+    }
+
+    pub fn try_deviation_override_price(&self) -> MathResult<Option<Decimal>> {
+        raw_price_bound(self.deviation_override_price_raw)
+    }
+
+    pub fn try_set_deviation_override_price(&mut self, price: Option<Decimal>) -> MathResult<()> {
+        self.deviation_override_price_raw = price_bound_bits(price)?;
+        Ok(())
+    }
+}
+
+/// Raw bits of optional price bound. Zero - no bound.
+pub fn price_bound_bits(bound: Option<Decimal>) -> MathResult<i128> {
+    bound.map_or(Ok(0), |bound| bound.into_bits())
+}
+
+fn raw_price_bound(raw: i128) -> MathResult<Option<Decimal>> {
+    if raw == 0 {
+        return Ok(None);
+    }
+    Decimal::from_bits(raw).map(Some)
 }
 
 impl PodAccount for PriceFeed {
@@ -450,7 +1252,9 @@ impl PodAccount for PriceFeed {
 
     type Version = u8;
 
-    const VERSION: Self::Version = 1;
+    /// 2 - price bounds, EMA, TWAP checkpoints, pending authority, off-chain writers and their
+    /// submissions. Account grown by `MigratePriceFeed` once from version 1.
+    const VERSION: Self::Version = 2;
 
     type InitParams = (
         /*params:*/ PriceFeedParams,
@@ -490,7 +1294,44 @@ impl PodAccount for PriceFeed {
             update_timestamp,
             update_slot,
             price_raw,
-            _padding,
+            confidence_raw,
+            max_confidence_bps,
+            transform_op,
+            fallback_source_raw,
+            active_source,
+            _padding1,
+            fallback_source_address,
+            reference_source_raw,
+            _padding2,
+            max_divergence_bps,
+            reference_source_address,
+            max_deviation_bps,
+            _padding3,
+            deviation_window_sec,
+            max_future_sec,
+            primary_timestamp,
+            min_price_raw,
+            max_price_raw,
+            ema_price_raw,
+            ema_conf_raw,
+            ema_half_life_sec,
+            fallback_after_sec,
+            twap_timestamp,
+            cumulative_price_raw,
+            twap_checkpoint_index,
+            _padding6,
+            twap_checkpoints,
+            price_history,
+            pending_authority,
+            writers,
+            writer_quorum,
+            writers_enabled,
+            _padding7,
+            submission_window_sec,
+            writer_generation,
+            _padding8,
+            writer_submissions,
+            deviation_override_price_raw,
         } = self;
 
         *discriminator = *PRICE_FEED_DISCRIMINATOR;
@@ -509,7 +1350,45 @@ impl PodAccount for PriceFeed {
         *update_timestamp = 0;
         *update_slot = 0;
         *price_raw = 0;
-        *_padding = Zeroable::zeroed();
+        *confidence_raw = 0;
+        *max_confidence_bps = params.max_confidence_bps;
+        *transform_op = params.transform_op as u8;
+        *fallback_source_raw = params.fallback_source as u8;
+        *active_source = ActiveSource::Primary as u8;
+        *_padding1 = Zeroable::zeroed();
+        *fallback_source_address = params.fallback_source_address;
+        *reference_source_raw = params.reference_source as u8;
+        *_padding2 = Zeroable::zeroed();
+        *max_divergence_bps = params.max_divergence_bps;
+        *reference_source_address = params.reference_source_address;
+        *max_deviation_bps = params.max_deviation_bps;
+        *_padding3 = Zeroable::zeroed();
+        *deviation_window_sec = params.deviation_window_sec;
+        *max_future_sec = params.max_future_sec;
+        *primary_timestamp = 0;
+        // Bounds are verified to fit by the processor
+        *min_price_raw = price_bound_bits(params.min_price).expect("min_price bits");
+        *max_price_raw = price_bound_bits(params.max_price).expect("max_price bits");
+        *ema_price_raw = 0;
+        *ema_conf_raw = 0;
+        *ema_half_life_sec = params.ema_half_life_sec;
+        *fallback_after_sec = params.fallback_after_sec;
+        *twap_timestamp = 0;
+        *cumulative_price_raw = 0;
+        *twap_checkpoint_index = 0;
+        *_padding6 = Zeroable::zeroed();
+        *twap_checkpoints = Zeroable::zeroed();
+        *price_history = Pubkey::default();
+        *pending_authority = Pubkey::default();
+        *writers = Zeroable::zeroed();
+        *writer_quorum = params.writer_quorum;
+        *writers_enabled = 0;
+        *_padding7 = Zeroable::zeroed();
+        *submission_window_sec = params.submission_window_sec;
+        *writer_generation = 0;
+        *_padding8 = Zeroable::zeroed();
+        *writer_submissions = Zeroable::zeroed();
+        *deviation_override_price_raw = 0;
 
         Ok(())
     }
@@ -540,7 +1419,8 @@ mod tests {
             Pubkey::new_unique(),
             Pubkey::new_unique(),
         )
-        .with_price(dec!(1.001), chrono::Utc::now().timestamp(), 1);
+        .with_price(dec!(1.001), chrono::Utc::now().timestamp(), 1)
+        .with_confidence(dec!(0.0005));
 
         let json = serde_json::to_string_pretty(&price_feed).unwrap();
         println!("{json}");
@@ -548,4 +1428,125 @@ mod tests {
         let new_price_feed: PriceFeed = serde_json::from_str(&json).unwrap();
         assert_eq!(new_price_feed, price_feed);
     }
+
+    fn twap_feed() -> super::PriceFeed {
+        use super::*;
+
+        PriceFeed::new(
+            PriceFeedParams::new(
+                "Direct",
+                "SOL",
+                "USD",
+                "full",
+                "/sol",
+                "off-chain",
+                "off-chain",
+            ),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        )
+    }
+
+    #[test]
+    fn writers() {
+        use super::*;
+
+        let mut price_feed = twap_feed();
+        let source_address = price_feed.source_address;
+        assert!(price_feed.is_writer(&source_address));
+
+        let writers: Vec<_> = (0..MAX_WRITERS).map(|_| Pubkey::new_unique()).collect();
+        for writer in &writers {
+            assert!(price_feed.add_writer(*writer));
+        }
+        assert!(!price_feed.add_writer(Pubkey::new_unique()));
+        // Source address isn't a writer once writers are set
+        assert!(!price_feed.is_writer(&source_address));
+        assert!(price_feed.is_writer(&writers[1]));
+
+        assert_eq!(price_feed.writer_generation_of(&writers[1]), Some(2));
+        price_feed.writer_submissions[1].timestamp = 1000;
+        price_feed.writer_submissions[1].signed_price_nonce = 7;
+        assert_eq!(price_feed.writer_index(&writers[1]), Some(1));
+        assert!(price_feed.remove_writer(&writers[1]));
+        assert!(!price_feed.remove_writer(&writers[1]));
+        assert_eq!(price_feed.writer_index(&writers[1]), None);
+        assert_eq!(price_feed.writer_generation_of(&writers[1]), None);
+        // Submission of removed writer is not inherited by the next one
+        assert!(price_feed.writer_submissions[1].is_empty());
+        assert!(!price_feed.is_writer(&writers[1]));
+        assert!(price_feed.add_writer(writers[1]));
+        assert_eq!(price_feed.writers[1], writers[1]);
+        // Re-added writer takes new generation, so signed prices of the old one can't be replayed
+        assert_eq!(
+            price_feed.writer_generation_of(&writers[1]),
+            Some(MAX_WRITERS as u32 + 1)
+        );
+        assert_eq!(price_feed.writer_submissions[1].signed_price_nonce, 0);
+
+        for writer in &writers {
+            assert!(price_feed.remove_writer(writer));
+        }
+        // Revoking the last writer doesn't give access back to source address
+        assert!(!price_feed.is_writer(&source_address));
+        assert!(price_feed.add_writer(source_address));
+        assert!(price_feed.is_writer(&source_address));
+    }
+
+    #[test]
+    fn twap_of_held_prices() {
+        use texture_common::dec;
+
+        let mut price_feed = twap_feed();
+        price_feed.try_accumulate_twap(1000).unwrap();
+        price_feed.try_set_price(dec!(100), 1000, 1).unwrap();
+        price_feed.try_accumulate_twap(1300).unwrap();
+        price_feed.try_set_price(dec!(200), 1300, 2).unwrap();
+        assert_eq!(price_feed.try_cumulative_price().unwrap(), dec!(30000));
+
+        // 200 is held since the checkpoint at 1300
+        assert_eq!(price_feed.try_twap(1600, 300).unwrap(), Some(dec!(200)));
+        // (100 * 300 + 200 * 300) / 600
+        assert_eq!(price_feed.try_twap(1600, 600).unwrap(), Some(dec!(150)));
+        // window starts before the first checkpoint
+        assert_eq!(price_feed.try_twap(1600, 601).unwrap(), None);
+    }
+
+    #[test]
+    fn twap_checkpoints_ring() {
+        use super::*;
+        use texture_common::dec;
+
+        let mut price_feed = twap_feed();
+        // Updates every minute make checkpoints every TWAP_CHECKPOINT_INTERVAL_SEC
+        for timestamp in (1000..1600).step_by(60) {
+            price_feed.try_accumulate_twap(timestamp).unwrap();
+            price_feed.try_set_price(dec!(100), timestamp, 1).unwrap();
+        }
+        assert_eq!(price_feed.twap_checkpoint_index, 1);
+        assert_eq!(price_feed.latest_twap_checkpoint().timestamp, 1300);
+
+        // The oldest checkpoints are overwritten
+        let last_timestamp = 1300 + TWAP_CHECKPOINT_INTERVAL_SEC * TWAP_CHECKPOINTS as i64;
+        for timestamp in (1600..=last_timestamp).step_by(TWAP_CHECKPOINT_INTERVAL_SEC as usize) {
+            price_feed.try_accumulate_twap(timestamp).unwrap();
+            price_feed.try_set_price(dec!(100), timestamp, 1).unwrap();
+        }
+        assert_eq!(
+            price_feed.latest_twap_checkpoint().timestamp,
+            last_timestamp
+        );
+        let oldest_window = (TWAP_CHECKPOINT_INTERVAL_SEC * (TWAP_CHECKPOINTS as i64 - 1)) as u32;
+        assert_eq!(
+            price_feed.try_twap(last_timestamp, oldest_window).unwrap(),
+            Some(dec!(100))
+        );
+        assert_eq!(
+            price_feed
+                .try_twap(last_timestamp, oldest_window + 1)
+                .unwrap(),
+            None
+        );
+    }
 }
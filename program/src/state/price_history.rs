@@ -0,0 +1,239 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::pubkey::Pubkey;
+
+use texture_common::account::{PodAccount, PodAccountError};
+use texture_common::math::{Decimal, MathResult};
+
+use crate::state::price_feed::PriceFeed;
+use crate::state::PRICE_HISTORY_DISCRIMINATOR;
+
+#[cfg(feature = "with-serde")]
+use super::utils::with_serde::{
+    array_as_str_serde, decimal_bits_serde, timestamp_as_datetime_serde, As, Deserialize,
+    DisplayAsJsonPretty, DisplayFromStr, Serialize,
+};
+
+/// Number of observations kept by `PriceHistory`
+pub const PRICE_HISTORY_LEN: usize = 64;
+
+static_assertions::const_assert_eq!(PriceHistory::SIZE, std::mem::size_of::<PriceHistory>());
+static_assertions::const_assert_eq!(0, std::mem::size_of::<PriceHistory>() % 8);
+
+/// Price of `PriceFeed` after a successful update.
+#[derive(Clone, Copy, Debug, Pod, Zeroable, PartialEq)]
+#[cfg_attr(
+    feature = "with-serde",
+    derive(Serialize, Deserialize),
+    serde_with::serde_as
+)]
+#[repr(C)]
+pub struct PriceObservation {
+    /// decimal with scale 18
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(rename = "price", with = "decimal_bits_serde")
+    )]
+    pub price_raw: i128,
+
+    /// decimal with scale 18
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(rename = "confidence", with = "decimal_bits_serde")
+    )]
+    pub confidence_raw: i128,
+
+    /// Price born time, the same as `PriceFeed::update_timestamp`
+    #[cfg_attr(feature = "with-serde", serde(with = "timestamp_as_datetime_serde"))]
+    pub timestamp: i64,
+
+    pub slot: u64,
+}
+
+impl PriceObservation {
+    pub fn try_price(&self) -> MathResult<Decimal> {
+        Decimal::from_bits(self.price_raw)
+    }
+
+    pub fn try_confidence(&self) -> MathResult<Decimal> {
+        Decimal::from_bits(self.confidence_raw)
+    }
+}
+
+impl From<&PriceFeed> for PriceObservation {
+    fn from(price_feed: &PriceFeed) -> Self {
+        Self {
+            price_raw: price_feed.price_raw,
+            confidence_raw: price_feed.confidence_raw,
+            timestamp: price_feed.update_timestamp,
+            slot: price_feed.update_slot,
+        }
+    }
+}
+
+/// Ring buffer of the last `PRICE_HISTORY_LEN` prices of `PriceFeed`. The feed refers to it
+/// with its `price_history` and every successful price update is appended.
+#[derive(Clone, Copy, Debug, Pod, Zeroable, PartialEq)]
+#[cfg_attr(
+    feature = "with-serde",
+    derive(Serialize, Deserialize, DisplayAsJsonPretty),
+    serde_with::serde_as
+)]
+#[repr(C)]
+pub struct PriceHistory {
+    #[cfg_attr(feature = "with-serde", serde(with = "array_as_str_serde"))]
+    pub discriminator: [u8; 8],
+    pub version: u8,
+
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub _padding0: [u8; 7],
+
+    /// Price-feed which prices are recorded.
+    #[cfg_attr(feature = "with-serde", serde(with = "As::<DisplayFromStr>"))]
+    pub price_feed: Pubkey,
+
+    /// someone who created this price history. It is deleted by the price-feed update authority.
+    #[cfg_attr(feature = "with-serde", serde(with = "As::<DisplayFromStr>"))]
+    pub authority: Pubkey,
+
+    /// Index in `observations` of the latest observation.
+    pub observation_index: u32,
+
+    /// Number of used entries in `observations`
+    pub observations_count: u32,
+
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub _padding1: [u8; 8],
+
+    #[cfg_attr(feature = "with-serde", serde_as(as = "[_; PRICE_HISTORY_LEN]"))]
+    pub observations: [PriceObservation; PRICE_HISTORY_LEN],
+
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(skip, default = "Zeroable::zeroed"),
+        serde_as(as = "Bytes")
+    )]
+    pub _padding: [u8; 64],
+}
+
+impl PriceHistory {
+    pub fn new(price_feed: Pubkey, authority: Pubkey) -> Self {
+        Self::from_init_params((price_feed, authority))
+    }
+
+    /// Appends `observation` overwriting the oldest one when the buffer is full.
+    pub fn push(&mut self, observation: PriceObservation) {
+        let index = if self.observations_count == 0 {
+            0
+        } else {
+            (self.observation_index as usize + 1) % PRICE_HISTORY_LEN
+        };
+        self.observations[index] = observation;
+        self.observation_index = index as u32;
+        self.observations_count = (self.observations_count + 1).min(PRICE_HISTORY_LEN as u32);
+    }
+
+    /// Observations in use from the oldest to the latest.
+    pub fn observations(&self) -> Vec<PriceObservation> {
+        let count = (self.observations_count as usize).min(PRICE_HISTORY_LEN);
+        let oldest =
+            (self.observation_index as usize + PRICE_HISTORY_LEN + 1 - count) % PRICE_HISTORY_LEN;
+        (0..count)
+            .map(|offset| self.observations[(oldest + offset) % PRICE_HISTORY_LEN])
+            .collect()
+    }
+
+    pub fn latest(&self) -> Option<&PriceObservation> {
+        (self.observations_count > 0)
+            .then(|| &self.observations[self.observation_index as usize % PRICE_HISTORY_LEN])
+    }
+}
+
+impl PodAccount for PriceHistory {
+    const DISCRIMINATOR: &'static [u8] = PRICE_HISTORY_DISCRIMINATOR;
+
+    type Version = u8;
+
+    const VERSION: Self::Version = 1;
+
+    type InitParams = (/*price_feed:*/ Pubkey, /*authority:*/ Pubkey);
+
+    type InitError = PodAccountError;
+
+    fn discriminator(&self) -> &[u8] {
+        &self.discriminator
+    }
+
+    fn version(&self) -> Self::Version {
+        self.version
+    }
+
+    fn init_unckecked(
+        &mut self,
+        (feed, history_authority): Self::InitParams,
+    ) -> Result<(), Self::InitError> {
+        let Self {
+            discriminator,
+            version,
+            _padding0,
+            price_feed,
+            authority,
+            observation_index,
+            observations_count,
+            _padding1,
+            observations,
+            _padding,
+        } = self;
+
+        *discriminator = *PRICE_HISTORY_DISCRIMINATOR;
+        *version = Self::VERSION;
+        *_padding0 = Zeroable::zeroed();
+        *price_feed = feed;
+        *authority = history_authority;
+        *observation_index = 0;
+        *observations_count = 0;
+        *_padding1 = Zeroable::zeroed();
+        *observations = Zeroable::zeroed();
+        *_padding = Zeroable::zeroed();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn observation(timestamp: i64) -> PriceObservation {
+        PriceObservation {
+            price_raw: timestamp as i128,
+            confidence_raw: 0,
+            timestamp,
+            slot: timestamp as u64,
+        }
+    }
+
+    #[test]
+    fn ring_buffer() {
+        let mut price_history = PriceHistory::new(Pubkey::new_unique(), Pubkey::new_unique());
+        assert!(price_history.latest().is_none());
+        assert!(price_history.observations().is_empty());
+
+        price_history.push(observation(1));
+        price_history.push(observation(2));
+        assert_eq!(price_history.latest(), Some(&observation(2)));
+        assert_eq!(
+            price_history.observations(),
+            vec![observation(1), observation(2)]
+        );
+
+        let last = PRICE_HISTORY_LEN as i64 + 10;
+        for timestamp in 3..=last {
+            price_history.push(observation(timestamp));
+        }
+        let observations = price_history.observations();
+        assert_eq!(observations.len(), PRICE_HISTORY_LEN);
+        assert_eq!(observations[0], observation(11));
+        assert_eq!(observations[PRICE_HISTORY_LEN - 1], observation(last));
+        assert_eq!(price_history.latest(), Some(&observation(last)));
+    }
+}
@@ -1,6 +1,22 @@
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use solana_program::pubkey;
 use solana_program::pubkey::Pubkey;
 use solana_program::stake::state::Lockup;
+use texture_common::math::{CheckedDiv, CheckedMul, Decimal, MathResult};
+
+/// SPL stake pool program
+pub const SPL_STAKE_POOL_PROGRAM_ID: Pubkey =
+    pubkey!("SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy");
+
+/// Sanctum deployment of the SPL stake pool program used by single-validator LSTs.
+/// Pools have the same `StakePool` layout.
+pub const SANCTUM_SPL_STAKE_POOL_PROGRAM_ID: Pubkey =
+    pubkey!("SP12tWFxD9oJsVWNavTTBZvMbA6gkAmxtVgxdqvyvhY");
+
+/// Sanctum deployment of the SPL stake pool program used by multi-validator LSTs.
+/// Pools have the same `StakePool` layout.
+pub const SANCTUM_SPL_MULTI_STAKE_POOL_PROGRAM_ID: Pubkey =
+    pubkey!("SPMBzsVUuoHA4Jm6KunbsotaahvVikZs1JyTW6iJvbn");
 
 /// Enum representing the account type managed by the program
 #[derive(Clone, Debug, Default, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
@@ -27,6 +43,30 @@ pub struct Fee {
     pub numerator: u64,
 }
 
+impl Fee {
+    /// Fee takes nothing
+    pub fn is_zero(&self) -> bool {
+        self.denominator == 0 || self.numerator == 0
+    }
+
+    /// Returns the fee with the greater ratio
+    pub fn max(self, other: Fee) -> Fee {
+        if self.is_zero() {
+            return other;
+        }
+        if other.is_zero() {
+            return self;
+        }
+        let lhs = self.numerator as u128 * other.denominator as u128;
+        let rhs = other.numerator as u128 * self.denominator as u128;
+        if lhs >= rhs {
+            self
+        } else {
+            other
+        }
+    }
+}
+
 /// Initialized program details.
 #[repr(C)]
 #[derive(Clone, Debug, Default, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
@@ -146,6 +186,39 @@ pub struct StakePool {
     pub last_epoch_total_lamports: u64,
 }
 
+impl StakePool {
+    /// Price of one pool token in SOL
+    pub fn lst_price(&self) -> MathResult<Decimal> {
+        Decimal::from_i128_with_scale(self.total_lamports as i128, 0)?.checked_div(
+            Decimal::from_i128_with_scale(self.pool_token_supply as i128, 0)?,
+        )
+    }
+
+    /// Fee charged on redemption of pool tokens. Greater one of SOL and stake withdrawal fees,
+    /// so the redemption route doesn't matter.
+    pub fn withdrawal_fee(&self) -> Fee {
+        self.sol_withdrawal_fee.max(self.stake_withdrawal_fee)
+    }
+
+    /// Price of one pool token in SOL redeemable after withdrawal fee is charged
+    pub fn lst_price_net_of_withdrawal_fee(&self) -> MathResult<Decimal> {
+        let fee = self.withdrawal_fee();
+        if fee.is_zero() {
+            return self.lst_price();
+        }
+
+        // total_lamports * (1 - numerator / denominator) / pool_token_supply
+        let net_ratio = fee.denominator.saturating_sub(fee.numerator);
+        Decimal::from_i128_with_scale(self.total_lamports as i128, 0)?
+            .checked_mul(Decimal::from_i128_with_scale(net_ratio as i128, 0)?)?
+            .checked_div(Decimal::from_i128_with_scale(fee.denominator as i128, 0)?)?
+            .checked_div(Decimal::from_i128_with_scale(
+                self.pool_token_supply as i128,
+                0,
+            )?)
+    }
+}
+
 /// Wrapper type that "counts down" epochs, which is Borsh-compatible with the
 /// native `Option`
 #[repr(C)]
@@ -201,3 +274,111 @@ impl<T> From<FutureEpoch<T>> for Option<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use solana_program::borsh1::try_from_slice_unchecked;
+
+    use super::*;
+
+    fn stake_pool_bytes(
+        total_lamports: u64,
+        pool_token_supply: u64,
+        sol_withdrawal_fee: Fee,
+        stake_withdrawal_fee: Fee,
+    ) -> Vec<u8> {
+        let stake_pool = StakePool {
+            account_type: AccountType::StakePool,
+            total_lamports,
+            pool_token_supply,
+            last_update_epoch: 1,
+            sol_withdrawal_fee,
+            stake_withdrawal_fee,
+            ..Default::default()
+        };
+        let mut data = borsh::to_vec(&stake_pool).unwrap();
+        // Account is usually larger than its content
+        data.resize(data.len() + 64, 0);
+        data
+    }
+
+    fn fee(numerator: u64, denominator: u64) -> Fee {
+        Fee {
+            denominator,
+            numerator,
+        }
+    }
+
+    fn decimal(value: i128, scale: u32) -> Decimal {
+        Decimal::from_i128_with_scale(value, scale).unwrap()
+    }
+
+    #[test]
+    fn price_without_fee() {
+        let data = stake_pool_bytes(1_100, 1_000, fee(0, 0), fee(0, 1000));
+        let stake_pool = try_from_slice_unchecked::<StakePool>(&data).unwrap();
+
+        assert!(stake_pool.withdrawal_fee().is_zero());
+        assert_eq!(stake_pool.lst_price().unwrap(), decimal(11, 1));
+        assert_eq!(
+            stake_pool.lst_price_net_of_withdrawal_fee().unwrap(),
+            decimal(11, 1)
+        );
+    }
+
+    #[test]
+    fn price_net_of_sol_withdrawal_fee() {
+        // 0.3% SOL withdrawal fee
+        let data = stake_pool_bytes(1_100, 1_000, fee(3, 1000), fee(0, 0));
+        let stake_pool = try_from_slice_unchecked::<StakePool>(&data).unwrap();
+
+        assert_eq!(stake_pool.lst_price().unwrap(), decimal(11, 1));
+        // 1.1 * 0.997
+        assert_eq!(
+            stake_pool.lst_price_net_of_withdrawal_fee().unwrap(),
+            decimal(10967, 4)
+        );
+    }
+
+    #[test]
+    fn price_net_of_greater_fee() {
+        // 0.1% SOL withdrawal fee, 0.5% stake withdrawal fee
+        let data = stake_pool_bytes(2_000, 1_000, fee(1, 1000), fee(1, 200));
+        let stake_pool = try_from_slice_unchecked::<StakePool>(&data).unwrap();
+
+        assert_eq!(stake_pool.withdrawal_fee(), fee(1, 200));
+        // 2 * 0.995
+        assert_eq!(
+            stake_pool.lst_price_net_of_withdrawal_fee().unwrap(),
+            decimal(199, 2)
+        );
+    }
+
+    #[test]
+    fn price_net_of_full_fee() {
+        let data = stake_pool_bytes(2_000, 1_000, fee(2, 1), fee(0, 0));
+        let stake_pool = try_from_slice_unchecked::<StakePool>(&data).unwrap();
+
+        assert_eq!(
+            stake_pool.lst_price_net_of_withdrawal_fee().unwrap(),
+            decimal(0, 0)
+        );
+    }
+
+    #[test]
+    fn price_net_of_fee_overflow() {
+        let data = stake_pool_bytes(u64::MAX, 1, fee(1, u64::MAX), fee(0, 0));
+        let stake_pool = try_from_slice_unchecked::<StakePool>(&data).unwrap();
+
+        assert!(stake_pool.lst_price_net_of_withdrawal_fee().is_err());
+    }
+
+    #[test]
+    fn price_of_empty_pool() {
+        let data = stake_pool_bytes(0, 0, fee(1, 1000), fee(0, 0));
+        let stake_pool = try_from_slice_unchecked::<StakePool>(&data).unwrap();
+
+        assert!(stake_pool.lst_price().is_err());
+        assert!(stake_pool.lst_price_net_of_withdrawal_fee().is_err());
+    }
+}
@@ -0,0 +1,172 @@
+use borsh::BorshDeserialize;
+use solana_program::pubkey;
+use solana_program::pubkey::Pubkey;
+
+use crate::error::SerializeError;
+
+/// Switchboard On-Demand program
+pub const SWITCHBOARD_ON_DEMAND_PROGRAM_ID: Pubkey =
+    pubkey!("SBondMDrcV3K4kxZR1HNVT7osZxAHVHgYXL5Ze1oMUv");
+
+/// Anchor discriminator of the `PullFeedAccountData` account
+pub const PULL_FEED_DISCRIMINATOR: [u8; 8] = [196, 27, 108, 196, 10, 215, 219, 40];
+
+/// Decimals of all values stored in the pull feed
+pub const PRECISION: u32 = 18;
+
+/// Result aggregated from the latest oracle submissions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, BorshDeserialize)]
+pub struct CurrentResult {
+    /// Median of the samples
+    pub value: i128,
+    pub std_dev: i128,
+    pub mean: i128,
+    pub range: i128,
+    pub min_value: i128,
+    pub max_value: i128,
+    /// Number of samples the result was computed from
+    pub num_samples: u8,
+    pub submission_idx: u8,
+    pub padding1: [u8; 6],
+    pub slot: u64,
+    pub min_slot: u64,
+    pub max_slot: u64,
+}
+
+impl CurrentResult {
+    pub const SIZE: usize = 16 * 6 + 1 + 1 + 6 + 8 * 3;
+}
+
+/// Offsets of used fields in the on-demand pull feed account following the discriminator.
+/// The account starts with 32 oracle submissions of 64 bytes each, then authority, queue,
+/// feed_hash, initialized_at, permissions, max_variance, min_responses, name, padding1 and
+/// historical_result_idx.
+const MIN_SAMPLE_SIZE_OFFSET: usize = 32 * 64 + 32 * 3 + 8 * 3 + 4 + 32 + 2 + 1;
+const LAST_UPDATE_TIMESTAMP_OFFSET: usize = MIN_SAMPLE_SIZE_OFFSET + 1;
+/// lut_slot and reserved1 precede the result
+const RESULT_OFFSET: usize = LAST_UPDATE_TIMESTAMP_OFFSET + 8 + 8 + 32;
+const MAX_STALENESS_OFFSET: usize = RESULT_OFFSET + CurrentResult::SIZE;
+const DECODED_SIZE: usize = MAX_STALENESS_OFFSET + 4;
+
+/// Fields of the on-demand pull feed account used to get the price. They are read at fixed
+/// offsets, so oracle submissions, history and reserved space are never copied.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PullFeedAccountData {
+    /// Minimum number of samples required for the result to be valid
+    pub min_sample_size: u8,
+    /// UTC unix-timestamp of the latest submission
+    pub last_update_timestamp: i64,
+    pub result: CurrentResult,
+    /// Maximum staleness of submissions in slots. Zero - not limited.
+    pub max_staleness: u32,
+}
+
+impl PullFeedAccountData {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, SerializeError> {
+        if data.len() < PULL_FEED_DISCRIMINATOR.len() + DECODED_SIZE {
+            return Err(SerializeError::NotEnoughData);
+        }
+        let (discriminator, data) = data.split_at(PULL_FEED_DISCRIMINATOR.len());
+        if discriminator != PULL_FEED_DISCRIMINATOR {
+            return Err(SerializeError::Invalid);
+        }
+
+        Ok(Self {
+            min_sample_size: data[MIN_SAMPLE_SIZE_OFFSET],
+            last_update_timestamp: i64::from_le_bytes(read_array(
+                data,
+                LAST_UPDATE_TIMESTAMP_OFFSET,
+            )),
+            result: CurrentResult::deserialize(
+                &mut &data[RESULT_OFFSET..RESULT_OFFSET + CurrentResult::SIZE],
+            )?,
+            max_staleness: u32::from_le_bytes(read_array(data, MAX_STALENESS_OFFSET)),
+        })
+    }
+
+    /// Minimum number of samples the feed result must be computed from.
+    pub fn min_samples(&self) -> u8 {
+        self.min_sample_size.max(1)
+    }
+
+    /// Whether the result is older than `max_staleness` slots at `slot`.
+    pub fn is_stale_at_slot(&self, slot: u64) -> bool {
+        self.max_staleness != 0 && slot.saturating_sub(self.result.slot) > self.max_staleness as u64
+    }
+}
+
+fn read_array<const N: usize>(data: &[u8], offset: usize) -> [u8; N] {
+    data[offset..offset + N].try_into().expect("N bytes")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pull_feed_bytes(
+        last_update_timestamp: i64,
+        min_sample_size: u8,
+        result: (i128, u8),
+    ) -> Vec<u8> {
+        let mut data = PULL_FEED_DISCRIMINATOR.to_vec();
+        data.extend([0; 64 * 32]); // submissions
+        data.extend([0; 32 * 3]); // authority, queue, feed_hash
+        data.extend(1_i64.to_le_bytes()); // initialized_at
+        data.extend([0; 8 + 8 + 4 + 32 + 2 + 1]);
+        data.push(min_sample_size);
+        data.extend(last_update_timestamp.to_le_bytes());
+        data.extend([0; 8 + 32]); // lut_slot, reserved1
+        data.extend(result.0.to_le_bytes()); // value
+        data.extend([0; 16 * 5]);
+        data.push(result.1); // num_samples
+        data.extend([0; 1 + 6 + 8 * 3]);
+        data.extend(100_u32.to_le_bytes()); // max_staleness
+        data.extend([0; 12 + 16 * 32 + 8 + 24 + 256]);
+        data
+    }
+
+    #[test]
+    fn decode() {
+        let data = pull_feed_bytes(1_700_000_000, 3, (150_250_000_000_000_000_000, 5));
+        assert_eq!(data.len(), 8 + 3200);
+
+        let feed = PullFeedAccountData::try_from_bytes(&data).unwrap();
+        assert_eq!(feed.last_update_timestamp, 1_700_000_000);
+        assert_eq!(feed.min_samples(), 3);
+        assert_eq!(feed.result.value, 150_250_000_000_000_000_000);
+        assert_eq!(feed.result.num_samples, 5);
+        assert_eq!(feed.max_staleness, 100);
+    }
+
+    #[test]
+    fn stale_at_slot() {
+        let data = pull_feed_bytes(1, 1, (1, 1));
+        let mut feed = PullFeedAccountData::try_from_bytes(&data).unwrap();
+        feed.result.slot = 1000;
+        assert!(!feed.is_stale_at_slot(1100));
+        assert!(feed.is_stale_at_slot(1101));
+
+        // Zero doesn't limit staleness
+        feed.max_staleness = 0;
+        assert!(!feed.is_stale_at_slot(u64::MAX));
+    }
+
+    #[test]
+    fn not_enough_data() {
+        let data = pull_feed_bytes(1, 1, (1, 1));
+        assert!(matches!(
+            PullFeedAccountData::try_from_bytes(&data[..8 + DECODED_SIZE - 1]),
+            Err(SerializeError::NotEnoughData)
+        ));
+    }
+
+    #[test]
+    fn invalid_discriminator() {
+        let mut data = pull_feed_bytes(1, 1, (1, 1));
+        data[7] = 0;
+        assert!(matches!(
+            PullFeedAccountData::try_from_bytes(&data),
+            Err(SerializeError::Invalid)
+        ));
+    }
+}
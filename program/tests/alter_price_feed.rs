@@ -76,6 +76,19 @@ async fn alter_success() {
             Some(new_source.into()),
             Some(SB_RAY_PRICE_SOURCE),
             Some(SB_RAY_PRICE_SOURCE),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .await
         .expect("alter price feed");
@@ -0,0 +1,219 @@
+use price_proxy_client::{
+    CompositeFeedSignatureView, CompositeFeedView, PriceFeedSignatureView, PriceFeedView,
+    SignatureView,
+};
+
+use pretty_assertions::assert_eq;
+use pyth_solana_receiver_sdk::price_update::get_feed_id_from_hex;
+use tracing::info;
+
+use price_proxy::error::PriceProxyErrorDiscriminants;
+use price_proxy::state::composite_feed::{CompositeFeed, CompositeLegParams};
+use price_proxy::state::price_feed::{PriceFeed, PriceFeedParams};
+
+mod utils;
+use utils::*;
+
+#[tokio::test]
+async fn update_composite_success() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+    let authority_key = price_proxy.authority.pubkey();
+
+    let feed_id = get_feed_id_from_hex(PYTH_SOL_FEED_ID_HEX).expect("feed_id_from_hex");
+    let sol_feed = Pubkey::from(feed_id);
+
+    // (LST/mSOL * mSOL/SOL) * SOL/USD
+    let legs = vec![
+        CompositeLegParams::new("marinade", MARINADE_STATE_SOURCE, "multiply"),
+        CompositeLegParams::new("sanctum-spl", SANCTUM_SPL_POOL_SOURCE, "multiply"),
+        CompositeLegParams::new("pyth", sol_feed, "multiply"),
+    ];
+
+    // CREATE COMPOSITE FEED
+
+    let CompositeFeedSignatureView { composite_feed, .. } = price_proxy
+        .create_composite_feed(legs.clone(), 0)
+        .await
+        .expect("create composite-feed");
+
+    let CompositeFeedView {
+        composite_feed: composite_feed_acc,
+        ..
+    } = price_proxy
+        .composite_feed(&composite_feed)
+        .await
+        .expect("composite-feed");
+    assert_eq!(
+        composite_feed_acc,
+        CompositeFeed::new(authority_key, &legs, 0)
+    );
+
+    // CREATE PRICE-FEED
+
+    let expected_params =
+        PriceFeedParams::new("Composite", "LST", "USD", "full", "/lst", "pyth", "pyth");
+    let mut expected = PriceFeed::new(
+        expected_params,
+        authority_key,
+        composite_feed,
+        composite_feed,
+    );
+
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(expected_params, composite_feed, composite_feed)
+        .await
+        .expect("create price-feed");
+
+    // UPDATE PRICE
+
+    let max_age_sec = 100000000; // cause synthesized price updates are not updating
+    let ixs = price_proxy
+        .update_composite_price_ix(
+            feed_key,
+            composite_feed,
+            &[
+                MARINADE_STATE_SOURCE,
+                SANCTUM_SPL_POOL_SOURCE,
+                PYTH_SOL_NARROW_CONF_PRICE_UPDATE,
+            ],
+            max_age_sec,
+        )
+        .await;
+    price_proxy
+        .send_transaction_by(ixs, &[&price_proxy.authority])
+        .await
+        .expect("update price");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    info!(%feed_key, %price_feed);
+
+    // 1.25 * 1.1 * 150
+    expected
+        .try_accumulate_twap(price_feed.update_timestamp)
+        .unwrap();
+    expected
+        .try_set_price(
+            dec!(206.25),
+            price_feed.update_timestamp,
+            price_feed.update_slot,
+        )
+        .unwrap();
+    // 1.25 * 1.1 * 0.015
+    expected.try_set_confidence(dec!(0.020625)).unwrap();
+    // EMA of composite price is computed on-chain and starts from the first price
+    expected.try_set_ema(dec!(206.25), dec!(0.020625)).unwrap();
+    assert_eq!(price_feed, expected);
+}
+
+#[tokio::test]
+async fn update_composite_missing_leg_account() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+
+    let legs = vec![
+        CompositeLegParams::new("marinade", MARINADE_STATE_SOURCE, "multiply"),
+        CompositeLegParams::new("sanctum-spl", SANCTUM_SPL_POOL_SOURCE, "multiply"),
+    ];
+    let CompositeFeedSignatureView { composite_feed, .. } = price_proxy
+        .create_composite_feed(legs, 0)
+        .await
+        .expect("create composite-feed");
+
+    let params = PriceFeedParams::new(
+        "Composite",
+        "LST",
+        "SOL",
+        "full",
+        "/lst",
+        "marinade",
+        "marinade",
+    );
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(params, composite_feed, composite_feed)
+        .await
+        .expect("create price-feed");
+
+    let ixs = price_proxy
+        .update_composite_price_ix(feed_key, composite_feed, &[MARINADE_STATE_SOURCE], 60)
+        .await;
+    let result = price_proxy
+        .send_transaction_by(ixs, &[&price_proxy.authority])
+        .await;
+    assert_custom_error(
+        result,
+        PriceProxyErrorDiscriminants::CompositeLegAccountMissing,
+    );
+}
+
+#[tokio::test]
+async fn alter_and_delete_composite_feed() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+    let authority_key = price_proxy.authority.pubkey();
+
+    let CompositeFeedSignatureView { composite_feed, .. } = price_proxy
+        .create_composite_feed(
+            vec![CompositeLegParams::new(
+                "marinade",
+                MARINADE_STATE_SOURCE,
+                "multiply",
+            )],
+            0,
+        )
+        .await
+        .expect("create composite-feed");
+
+    // ALTER
+
+    let legs = vec![
+        CompositeLegParams::new("marinade", MARINADE_STATE_SOURCE, "multiply"),
+        CompositeLegParams::new("sanctum-spl", SANCTUM_SPL_POOL_SOURCE, "divide"),
+    ];
+    let SignatureView { signature: _ } = price_proxy
+        .alter_composite_feed(composite_feed, legs.clone(), 0)
+        .await
+        .expect("alter composite-feed");
+
+    let CompositeFeedView {
+        composite_feed: composite_feed_acc,
+        ..
+    } = price_proxy
+        .composite_feed(&composite_feed)
+        .await
+        .expect("composite-feed");
+    assert_eq!(
+        composite_feed_acc,
+        CompositeFeed::new(authority_key, &legs, 0)
+    );
+
+    let result = price_proxy
+        .alter_composite_feed(composite_feed, vec![], 0)
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::InvalidCompositeLegs);
+
+    // DELETE
+
+    let SignatureView { signature: _ } = price_proxy
+        .delete_composite_feed(composite_feed)
+        .await
+        .expect("delete composite-feed");
+
+    assert!(!price_proxy
+        .account_exists(&composite_feed)
+        .await
+        .expect("account exists"));
+}
@@ -0,0 +1,298 @@
+use chrono::Utc;
+use price_proxy_client::{
+    CompositeFeedSignatureView, PriceFeedSignatureView, PriceFeedView, PriceProxyClient,
+    SignatureView,
+};
+
+use pretty_assertions::assert_eq;
+use pyth_solana_receiver_sdk::price_update::get_feed_id_from_hex;
+use tracing::info;
+
+use price_proxy::error::PriceProxyErrorDiscriminants;
+use price_proxy::state::composite_feed::CompositeLegParams;
+use price_proxy::state::price_feed::{PriceFeed, PriceFeedParams};
+
+mod utils;
+use utils::*;
+
+/// Creates OffChain price-feed with the price written at `price_timestamp`.
+async fn create_offchain_feed(
+    price_proxy: &PriceProxyClient,
+    price: Decimal,
+    confidence: Decimal,
+    price_timestamp: i64,
+) -> Pubkey {
+    let authority_key = price_proxy.authority.pubkey();
+    let params = PriceFeedParams::new(
+        "Direct",
+        "SOL",
+        "USD",
+        "full",
+        "/sol",
+        "off-chain",
+        "off-chain",
+    );
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(params, authority_key, authority_key)
+        .await
+        .expect("create off-chain price-feed");
+
+    let SignatureView { signature: _ } = price_proxy
+        .write_price(feed_key, price, price_timestamp, Some(confidence))
+        .await
+        .expect("write price");
+
+    feed_key
+}
+
+#[tokio::test]
+async fn update_median_success() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+    let authority_key = price_proxy.authority.pubkey();
+
+    let feed_id = get_feed_id_from_hex(PYTH_SOL_FEED_ID_HEX).expect("feed_id_from_hex");
+    let sol_feed = Pubkey::from(feed_id);
+
+    let now = Utc::now().timestamp();
+    let high_feed = create_offchain_feed(&price_proxy, dec!(152), dec!(0.5), now).await;
+    let low_feed = create_offchain_feed(&price_proxy, dec!(149), dec!(0.5), now).await;
+
+    // CREATE COMPOSITE FEED WITH MEDIAN SOURCES
+
+    let legs = vec![
+        CompositeLegParams::new("pyth", sol_feed, "multiply"),
+        CompositeLegParams::new("off-chain", high_feed, "multiply"),
+        CompositeLegParams::new("off-chain", low_feed, "multiply"),
+    ];
+    let CompositeFeedSignatureView { composite_feed, .. } = price_proxy
+        .create_composite_feed(legs, 2)
+        .await
+        .expect("create composite-feed");
+
+    // CREATE PRICE-FEED
+
+    let expected_params =
+        PriceFeedParams::new("Median", "SOL", "USD", "full", "/sol", "pyth", "pyth");
+    let mut expected = PriceFeed::new(
+        expected_params,
+        authority_key,
+        composite_feed,
+        composite_feed,
+    );
+
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(expected_params, composite_feed, composite_feed)
+        .await
+        .expect("create price-feed");
+
+    // UPDATE PRICE
+
+    let max_age_sec = 100000000; // cause synthesized price updates are not updating
+    let ixs = price_proxy
+        .update_composite_price_ix(
+            feed_key,
+            composite_feed,
+            &[PYTH_SOL_NARROW_CONF_PRICE_UPDATE, high_feed, low_feed],
+            max_age_sec,
+        )
+        .await;
+    price_proxy
+        .send_transaction_by(ixs, &[&price_proxy.authority])
+        .await
+        .expect("update price");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    info!(%feed_key, %price_feed);
+
+    // median of 149, 150 and 152 is Pyth price
+    expected
+        .try_accumulate_twap(price_feed.update_timestamp)
+        .unwrap();
+    expected
+        .try_set_price(
+            dec!(150),
+            price_feed.update_timestamp,
+            price_feed.update_slot,
+        )
+        .unwrap();
+    expected.try_set_confidence(dec!(0.015)).unwrap();
+    // EMA of median price is computed on-chain and starts from the first price
+    expected.try_set_ema(dec!(150), dec!(0.015)).unwrap();
+    assert_eq!(price_feed, expected);
+}
+
+#[tokio::test]
+async fn update_median_quorum() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+
+    let now = Utc::now().timestamp();
+    let fresh_feed = create_offchain_feed(&price_proxy, dec!(1.01), dec!(0.01), now).await;
+    let stale_feed = create_offchain_feed(&price_proxy, dec!(0.5), dec!(0.01), now - 3600).await;
+
+    let legs = vec![
+        CompositeLegParams::new("off-chain", fresh_feed, "multiply"),
+        CompositeLegParams::new("off-chain", stale_feed, "multiply"),
+    ];
+    let CompositeFeedSignatureView { composite_feed, .. } = price_proxy
+        .create_composite_feed(legs.clone(), 2)
+        .await
+        .expect("create composite-feed");
+
+    let params = PriceFeedParams::new("Median", "USDC", "USD", "full", "/usdc", "pyth", "pyth");
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(params, composite_feed, composite_feed)
+        .await
+        .expect("create price-feed");
+
+    // ONLY ONE OF TWO REQUIRED SOURCES IS FRESH
+
+    let max_age_sec = 600;
+    let leg_addresses = [fresh_feed, stale_feed];
+    let ixs = price_proxy
+        .update_composite_price_ix(feed_key, composite_feed, &leg_addresses, max_age_sec)
+        .await;
+    let result = price_proxy
+        .send_transaction_by(ixs, &[&price_proxy.authority])
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::QuorumNotReached);
+
+    // QUORUM GREATER THAN NUMBER OF LEGS
+
+    let result = price_proxy
+        .alter_composite_feed(composite_feed, legs.clone(), 3)
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::InvalidQuorum);
+
+    // LOWER QUORUM
+
+    let SignatureView { signature: _ } = price_proxy
+        .alter_composite_feed(composite_feed, legs, 1)
+        .await
+        .expect("alter composite-feed");
+
+    let ixs = price_proxy
+        .update_composite_price_ix(feed_key, composite_feed, &leg_addresses, max_age_sec)
+        .await;
+    price_proxy
+        .send_transaction_by(ixs, &[&price_proxy.authority])
+        .await
+        .expect("update price");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    info!(%feed_key, %price_feed);
+    assert_eq!(price_feed.try_price().unwrap(), dec!(1.01));
+    assert_eq!(price_feed.try_confidence().unwrap(), dec!(0.01));
+}
+
+#[tokio::test]
+async fn update_median_leg_dropping() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+
+    let feed_id = get_feed_id_from_hex(PYTH_SOL_FEED_ID_HEX).expect("feed_id_from_hex");
+    let sol_feed = Pubkey::from(feed_id);
+
+    let now = Utc::now().timestamp();
+    let high_feed = create_offchain_feed(&price_proxy, dec!(152), dec!(0.5), now).await;
+    let low_feed = create_offchain_feed(&price_proxy, dec!(149), dec!(0.5), now).await;
+
+    let legs = vec![
+        CompositeLegParams::new("pyth", sol_feed, "multiply"),
+        CompositeLegParams::new("off-chain", high_feed, "multiply"),
+        CompositeLegParams::new("off-chain", low_feed, "multiply"),
+    ];
+    let CompositeFeedSignatureView { composite_feed, .. } = price_proxy
+        .create_composite_feed(legs.clone(), 0)
+        .await
+        .expect("create composite-feed");
+
+    let params = PriceFeedParams::new("Median", "SOL", "USD", "full", "/sol", "pyth", "pyth");
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(params, composite_feed, composite_feed)
+        .await
+        .expect("create price-feed");
+
+    let max_age_sec = PYTH_SOL_STALE_PRICE_UPDATE_AGE_SEC as u64 / 2;
+    let leg_addresses = |pyth_price_update| [pyth_price_update, high_feed, low_feed];
+
+    info!("default quorum requires all legs");
+
+    let ixs = price_proxy
+        .update_composite_price_ix(
+            feed_key,
+            composite_feed,
+            &leg_addresses(PYTH_SOL_STALE_PRICE_UPDATE),
+            max_age_sec,
+        )
+        .await;
+    let result = price_proxy
+        .send_transaction_by(ixs, &[&price_proxy.authority])
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::QuorumNotReached);
+
+    let ixs = price_proxy
+        .update_composite_price_ix(
+            feed_key,
+            composite_feed,
+            &leg_addresses(PYTH_SOL_NARROW_CONF_PRICE_UPDATE),
+            max_age_sec,
+        )
+        .await;
+    price_proxy
+        .send_transaction_by(ixs, &[&price_proxy.authority])
+        .await
+        .expect("update price");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    info!(%feed_key, %price_feed);
+    assert_eq!(price_feed.try_price().unwrap(), dec!(150));
+
+    info!("with lower quorum stale account of the same Pyth feed drops its leg");
+
+    let SignatureView { signature: _ } = price_proxy
+        .alter_composite_feed(composite_feed, legs, 2)
+        .await
+        .expect("alter composite-feed");
+
+    let ixs = price_proxy
+        .update_composite_price_ix(
+            feed_key,
+            composite_feed,
+            &leg_addresses(PYTH_SOL_STALE_PRICE_UPDATE),
+            max_age_sec,
+        )
+        .await;
+    price_proxy
+        .send_transaction_by(ixs, &[&price_proxy.authority])
+        .await
+        .expect("update price");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    info!(%feed_key, %price_feed);
+    // median of 149 and 152 instead of Pyth price of 150
+    assert_eq!(price_feed.try_price().unwrap(), dec!(150.5));
+}
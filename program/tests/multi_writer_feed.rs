@@ -0,0 +1,184 @@
+use chrono::Utc;
+use price_proxy_client::{sign_price, PriceFeedSignatureView, PriceFeedView, SignatureView};
+
+use pretty_assertions::assert_eq;
+use tracing::info;
+
+use price_proxy::error::PriceProxyErrorDiscriminants;
+use price_proxy::signed_price::SignedPriceMessage;
+use price_proxy::state::price_feed::{PriceFeedParams, MAX_WRITERS};
+
+mod utils;
+use utils::*;
+
+#[tokio::test]
+async fn median_of_fresh_submissions() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+    let authority_key = price_proxy.authority.pubkey();
+
+    // Writers only sign, the authority relays and pays fees
+    let writers: Vec<_> = (0..3).map(|_| Keypair::new()).collect();
+
+    // 2 of 3 submissions within two minutes
+    let params = PriceFeedParams::new(
+        "Direct",
+        "SOL",
+        "USD",
+        "full",
+        "/sol",
+        "off-chain",
+        "off-chain",
+    )
+    .with_writer_quorum(2, 120);
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(params, authority_key, authority_key)
+        .await
+        .expect("create price-feed");
+
+    for writer in &writers {
+        let SignatureView { signature: _ } = price_proxy
+            .add_writer(feed_key, writer.pubkey())
+            .await
+            .expect("add writer");
+    }
+
+    let PriceFeedView {
+        price_feed: writers_feed,
+        ..
+    } = price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    let now = Utc::now().timestamp();
+    let mut nonce = 0;
+    let mut submit = |writer: &Keypair, price, price_timestamp| {
+        nonce += 1;
+        let message = SignedPriceMessage {
+            price_feed: feed_key,
+            price,
+            price_timestamp,
+            generation: writers_feed
+                .writer_generation_of(&writer.pubkey())
+                .expect("writer generation"),
+            nonce,
+        };
+        (writer.pubkey(), sign_price(writer, &message), message)
+    };
+
+    info!("stale submission is recorded but not counted");
+
+    let (writer_key, signature, message) = submit(&writers[0], dec!(100), now - 600);
+    let SignatureView { signature: _ } = price_proxy
+        .write_signed_price(&writer_key, &signature, &message)
+        .await
+        .expect("write stale price");
+
+    let (writer_key, signature, message) = submit(&writers[1], dec!(150), now);
+    let SignatureView { signature: _ } = price_proxy
+        .write_signed_price(&writer_key, &signature, &message)
+        .await
+        .expect("write fresh price");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    info!(%feed_key, %price_feed);
+    assert_eq!(price_feed.writer_submissions[0].timestamp, now - 600);
+    assert_eq!(price_feed.writer_submissions[1].timestamp, now);
+    assert_eq!(price_feed.try_price().unwrap(), dec!(0));
+    assert_eq!(price_feed.update_timestamp, 0);
+
+    info!("writer can't submit older price than its previous one");
+
+    let (writer_key, signature, message) = submit(&writers[1], dec!(151), now - 10);
+    let result = price_proxy
+        .write_signed_price(&writer_key, &signature, &message)
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::TimestampIsNotRecent);
+
+    info!("quorum is reached while the third writer is missing");
+
+    let (writer_key, signature, message) = submit(&writers[0], dec!(160), now);
+    let SignatureView { signature: _ } = price_proxy
+        .write_signed_price(&writer_key, &signature, &message)
+        .await
+        .expect("refresh stale price");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    info!(%feed_key, %price_feed);
+    assert!(price_feed.writer_submissions[2].is_empty());
+    assert_eq!(price_feed.try_price().unwrap(), dec!(155));
+    assert_eq!(price_feed.update_timestamp, now);
+
+    info!("third submission moves the median");
+
+    let (writer_key, signature, message) = submit(&writers[2], dec!(152), now);
+    let SignatureView { signature: _ } = price_proxy
+        .write_signed_price(&writer_key, &signature, &message)
+        .await
+        .expect("write third price");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    assert_eq!(price_feed.try_price().unwrap(), dec!(152));
+
+    info!("removed writer's submission is dropped");
+
+    let SignatureView { signature: _ } = price_proxy
+        .remove_writer(feed_key, writers[2].pubkey())
+        .await
+        .expect("remove writer");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    assert!(price_feed.writer_submissions[2].is_empty());
+
+    let (writer_key, signature, message) = submit(&writers[2], dec!(152), now);
+    let result = price_proxy
+        .write_signed_price(&writer_key, &signature, &message)
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::UnauthorizedWriter);
+}
+
+#[tokio::test]
+async fn invalid_writer_quorum() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+    let authority_key = price_proxy.authority.pubkey();
+
+    let params = PriceFeedParams::new(
+        "Direct",
+        "SOL",
+        "USD",
+        "full",
+        "/sol",
+        "off-chain",
+        "off-chain",
+    );
+
+    let result = price_proxy
+        .create_price_feed(
+            params.with_writer_quorum(MAX_WRITERS as u8 + 1, 60),
+            authority_key,
+            authority_key,
+        )
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::InvalidQuorum);
+
+    let result = price_proxy
+        .create_price_feed(
+            params.with_writer_quorum(2, 0),
+            authority_key,
+            authority_key,
+        )
+        .await;
+    assert_custom_error(
+        result,
+        PriceProxyErrorDiscriminants::OperationCanNotBePerformed,
+    );
+}
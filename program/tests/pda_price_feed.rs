@@ -0,0 +1,203 @@
+use price_proxy_client::PriceFeedSignatureView;
+
+use pretty_assertions::assert_eq;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use tracing::info;
+
+use price_proxy::pda::{find_price_feed_address, find_price_feed_address_with_seed};
+use price_proxy::state::price_feed::{PriceFeed, PriceFeedParams, QuoteSymbol};
+
+mod utils;
+use utils::*;
+
+#[tokio::test]
+async fn create_pda_from_symbol() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+
+    let authority_key = price_proxy.authority.pubkey();
+
+    let params = PriceFeedParams::new(
+        "Direct",
+        "PDASOL",
+        "USD",
+        "full",
+        "/sol",
+        "switchboard",
+        "switchboard",
+    );
+
+    info!("create price-feed at PDA");
+
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed_pda(params, SB_SOL_PRICE_SOURCE, SB_SOL_PRICE_SOURCE, None)
+        .await
+        .expect("create price-feed");
+
+    let (expected_key, _) = find_price_feed_address("PDASOL", QuoteSymbol::USD, &authority_key);
+    assert_eq!(feed_key, expected_key);
+
+    let price_feed = price_proxy.price_feed(&feed_key).await.unwrap().price_feed;
+    assert_eq!(
+        price_feed,
+        PriceFeed::from_init_params((
+            params,
+            authority_key,
+            SB_SOL_PRICE_SOURCE,
+            SB_SOL_PRICE_SOURCE
+        ))
+    );
+
+    info!("the same symbol with other quote is another price-feed");
+
+    let sol_quoted_params = PriceFeedParams::new(
+        "Direct",
+        "PDASOL",
+        "SOL",
+        "full",
+        "/sol",
+        "switchboard",
+        "switchboard",
+    );
+    let PriceFeedSignatureView {
+        price_feed: sol_quoted_feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed_pda(
+            sol_quoted_params,
+            SB_SOL_PRICE_SOURCE,
+            SB_SOL_PRICE_SOURCE,
+            None,
+        )
+        .await
+        .expect("create price-feed");
+    assert_ne!(sol_quoted_feed_key, feed_key);
+
+    info!("the same symbol and quote can not be created twice");
+
+    price_proxy
+        .create_price_feed_pda(params, SB_RAY_PRICE_SOURCE, SB_RAY_PRICE_SOURCE, None)
+        .await
+        .expect_err("create price-feed twice");
+}
+
+#[tokio::test]
+async fn create_pda_from_seed() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+
+    let authority_key = price_proxy.authority.pubkey();
+
+    let params = PriceFeedParams::new(
+        "Direct",
+        "SEEDSOL",
+        "USD",
+        "full",
+        "/sol",
+        "switchboard",
+        "switchboard",
+    );
+
+    let seed = b"sol-usd-switchboard".to_vec();
+
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed_pda(
+            params,
+            SB_SOL_PRICE_SOURCE,
+            SB_SOL_PRICE_SOURCE,
+            Some(seed.clone()),
+        )
+        .await
+        .expect("create price-feed");
+
+    let (expected_key, _) = find_price_feed_address_with_seed(&seed, &authority_key);
+    assert_eq!(feed_key, expected_key);
+
+    let price_feed = price_proxy.price_feed(&feed_key).await.unwrap().price_feed;
+    assert_eq!(price_feed.symbol(), "SEEDSOL");
+
+    info!("seed longer than 32 bytes is rejected");
+
+    let result = price_proxy
+        .create_price_feed_pda(
+            params,
+            SB_SOL_PRICE_SOURCE,
+            SB_SOL_PRICE_SOURCE,
+            Some(vec![1; 33]),
+        )
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn create_pda_pre_funded() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+
+    let authority_key = price_proxy.authority.pubkey();
+
+    let params = PriceFeedParams::new(
+        "Direct",
+        "FUNDSOL",
+        "USD",
+        "full",
+        "/sol",
+        "switchboard",
+        "switchboard",
+    );
+
+    info!("anyone can send lamports to the predictable price-feed address");
+
+    let (feed_key, _) = find_price_feed_address("FUNDSOL", QuoteSymbol::USD, &authority_key);
+    price_proxy
+        .send_transaction_by(
+            vec![system_instruction::transfer(
+                &authority_key,
+                &feed_key,
+                1_000_000,
+            )],
+            &[&price_proxy.authority],
+        )
+        .await
+        .expect("pre-fund price-feed address");
+
+    info!("price-feed is still created at the pre-funded address");
+
+    let PriceFeedSignatureView {
+        price_feed: created_key,
+        ..
+    } = price_proxy
+        .create_price_feed_pda(params, SB_SOL_PRICE_SOURCE, SB_SOL_PRICE_SOURCE, None)
+        .await
+        .expect("create price-feed");
+    assert_eq!(created_key, feed_key);
+
+    let price_feed = price_proxy.price_feed(&feed_key).await.unwrap().price_feed;
+    assert_eq!(
+        price_feed,
+        PriceFeed::from_init_params((
+            params,
+            authority_key,
+            SB_SOL_PRICE_SOURCE,
+            SB_SOL_PRICE_SOURCE
+        ))
+    );
+
+    let rent = Rent::default().minimum_balance(PriceFeed::SIZE);
+    let account = price_proxy.rpc.get_account(&feed_key).await.unwrap();
+    assert_eq!(account.lamports, rent);
+    assert_eq!(account.owner, price_proxy::ID);
+}
@@ -1,11 +1,17 @@
 use chrono::Utc;
 use futures::future::join_all;
-use price_proxy_client::{PriceFeedSignatureView, PriceFeedView, PriceFeedsView, SignatureView};
+use price_proxy_client::{
+    sign_price, write_signed_price_ixs, PriceFeedSignatureView, PriceFeedView, PriceFeedsView,
+    PriceHistorySignatureView, PriceProxyClient, SignatureView,
+};
 
 use pretty_assertions::assert_eq;
 use tracing::info;
 
-use price_proxy::state::price_feed::{PriceFeed, PriceFeedParams};
+use price_proxy::error::PriceProxyErrorDiscriminants;
+use price_proxy::signed_price::SignedPriceMessage;
+use price_proxy::state::price_feed::{PriceFeed, PriceFeedParams, MAX_WRITERS};
+use texture_common::account::PodAccount;
 
 mod utils;
 use utils::*;
@@ -43,14 +49,23 @@ async fn offchain() {
     assert_eq!(price_feed, expected);
 
     let new_price = dec!(1.05);
+    let new_confidence = dec!(0.01);
     let SignatureView { signature: _ } = price_proxy
-        .write_price(feed_key, new_price, Utc::now().timestamp())
+        .write_price(
+            feed_key,
+            new_price,
+            Utc::now().timestamp(),
+            Some(new_confidence),
+        )
         .await
         .expect("write price");
 
     let PriceFeedView { price_feed, .. } =
         price_proxy.price_feed(&feed_key).await.expect("price-feed");
     info!(%feed_key, %price_feed);
+    expected
+        .try_accumulate_twap(price_feed.update_timestamp)
+        .unwrap();
     expected
         .try_set_price(
             new_price,
@@ -58,6 +73,9 @@ async fn offchain() {
             price_feed.update_slot,
         )
         .unwrap();
+    expected.try_set_confidence(new_confidence).unwrap();
+    // First price starts EMA
+    expected.try_set_ema(new_price, new_confidence).unwrap();
     assert_eq!(price_feed, expected);
 }
 
@@ -104,3 +122,980 @@ async fn offchain_force_timestamps() {
         assert_ne!(feed.update_timestamp, 0, "{key} timestamp not updated");
     });
 }
+
+#[tokio::test]
+async fn offchain_circuit_breaker() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+    let authority_key = price_proxy.authority.pubkey();
+
+    // 10% within an hour
+    let params = PriceFeedParams::new(
+        "Direct",
+        "SOL",
+        "USD",
+        "full",
+        "/sol",
+        "off-chain",
+        "off-chain",
+    )
+    .with_circuit_breaker(1000, 3600);
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(params, authority_key, authority_key)
+        .await
+        .expect("create price-feed");
+
+    let now = Utc::now().timestamp();
+    let SignatureView { signature: _ } = price_proxy
+        .write_price(feed_key, dec!(150), now, None)
+        .await
+        .expect("write first price");
+
+    let SignatureView { signature: _ } = price_proxy
+        .write_price(feed_key, dec!(160), now, None)
+        .await
+        .expect("write price within deviation");
+
+    // MISPLACED DECIMAL POINT
+
+    let result = price_proxy
+        .write_price(feed_key, dec!(1600), now, None)
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::PriceDeviationTooLarge);
+
+    // AUTHORITY OVERRIDES CIRCUIT BREAKER FOR ONE UPDATE WITH APPROVED PRICE
+
+    let SignatureView { signature: _ } = price_proxy
+        .override_circuit_breaker(feed_key, dec!(16))
+        .await
+        .expect("override circuit breaker");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    assert!(price_feed.is_deviation_overridden());
+    assert_eq!(
+        price_feed.try_deviation_override_price().unwrap(),
+        Some(dec!(16))
+    );
+
+    // Other price doesn't use the override
+    let result = price_proxy
+        .write_price(feed_key, dec!(1600), now, None)
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::PriceDeviationTooLarge);
+
+    let SignatureView { signature: _ } = price_proxy
+        .write_price(feed_key, dec!(16), now, None)
+        .await
+        .expect("write overridden price");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    info!(%feed_key, %price_feed);
+    assert_eq!(price_feed.try_price().unwrap(), dec!(16));
+    assert!(!price_feed.is_deviation_overridden());
+
+    let result = price_proxy
+        .write_price(feed_key, dec!(161), now, None)
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::PriceDeviationTooLarge);
+}
+
+#[tokio::test]
+async fn offchain_price_bounds() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+    let authority_key = price_proxy.authority.pubkey();
+
+    let params = PriceFeedParams::new(
+        "Direct",
+        "SOL",
+        "USD",
+        "full",
+        "/sol",
+        "off-chain",
+        "off-chain",
+    )
+    .with_price_bounds(Some(dec!(100)), Some(dec!(200)));
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(params, authority_key, authority_key)
+        .await
+        .expect("create price-feed");
+
+    let now = Utc::now().timestamp();
+
+    let result = price_proxy.write_price(feed_key, dec!(0), now, None).await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::NonPositivePrice);
+
+    let result = price_proxy.write_price(feed_key, dec!(99), now, None).await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::PriceBelowMin);
+
+    let result = price_proxy
+        .write_price(feed_key, dec!(201), now, None)
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::PriceAboveMax);
+
+    let SignatureView { signature: _ } = price_proxy
+        .write_price(feed_key, dec!(150), now, None)
+        .await
+        .expect("write price within bounds");
+
+    // MIN PRICE ABOVE MAX PRICE
+
+    let result = price_proxy
+        .alter_price_feed(
+            feed_key,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(dec!(300)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::InvalidPriceBounds);
+
+    // REMOVE CEILING
+
+    let SignatureView { signature: _ } = price_proxy
+        .alter_price_feed(
+            feed_key,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(dec!(0)),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .expect("alter price-feed");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    assert_eq!(price_feed.try_min_price().unwrap(), Some(dec!(100)));
+    assert_eq!(price_feed.try_max_price().unwrap(), None);
+
+    let SignatureView { signature: _ } = price_proxy
+        .write_price(feed_key, dec!(1000), now, None)
+        .await
+        .expect("write price without ceiling");
+}
+
+#[tokio::test]
+async fn offchain_future_timestamp() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+    let authority_key = price_proxy.authority.pubkey();
+
+    let params = PriceFeedParams::new(
+        "Direct",
+        "SOL",
+        "USD",
+        "full",
+        "/sol",
+        "off-chain",
+        "off-chain",
+    )
+    .with_max_future(600);
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(params, authority_key, authority_key)
+        .await
+        .expect("create price-feed");
+
+    let now = Utc::now().timestamp();
+
+    // FAR FUTURE TIMESTAMP WOULD BLOCK ALL FOLLOWING PRICES
+
+    let result = price_proxy
+        .write_price(feed_key, dec!(150), now + 3600, None)
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::TimestampInFuture);
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    assert_eq!(price_feed.update_timestamp, 0);
+
+    // CLOCK SKEW WITHIN TOLERANCE
+
+    let SignatureView { signature: _ } = price_proxy
+        .write_price(feed_key, dec!(150), now + 60, None)
+        .await
+        .expect("write price slightly ahead of clock");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    info!(%feed_key, %price_feed);
+    assert_eq!(price_feed.update_timestamp, now + 60);
+}
+
+#[tokio::test]
+async fn offchain_ema() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+    let authority_key = price_proxy.authority.pubkey();
+
+    let params = PriceFeedParams::new(
+        "Direct",
+        "SOL",
+        "USD",
+        "full",
+        "/sol",
+        "off-chain",
+        "off-chain",
+    )
+    .with_ema_half_life(60);
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(params, authority_key, authority_key)
+        .await
+        .expect("create price-feed");
+
+    let now = Utc::now().timestamp();
+
+    let SignatureView { signature: _ } = price_proxy
+        .write_price(feed_key, dec!(100), now - 120, Some(dec!(2)))
+        .await
+        .expect("write first price");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    assert_eq!(price_feed.try_ema_price().unwrap(), dec!(100));
+    assert_eq!(price_feed.try_ema_confidence().unwrap(), dec!(2));
+
+    // One half-life later EMA is halfway to the new price
+    let SignatureView { signature: _ } = price_proxy
+        .write_price(feed_key, dec!(200), now - 60, Some(dec!(4)))
+        .await
+        .expect("write second price");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    info!(%price_feed);
+    assert_eq!(price_feed.try_ema_price().unwrap(), dec!(150));
+    assert_eq!(price_feed.try_ema_confidence().unwrap(), dec!(3));
+}
+
+#[tokio::test]
+async fn offchain_twap() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+    let authority_key = price_proxy.authority.pubkey();
+
+    let params = PriceFeedParams::new(
+        "Direct",
+        "SOL",
+        "USD",
+        "full",
+        "/sol",
+        "off-chain",
+        "off-chain",
+    );
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(params, authority_key, authority_key)
+        .await
+        .expect("create price-feed");
+
+    let now = Utc::now().timestamp();
+
+    let SignatureView { signature: _ } = price_proxy
+        .write_price(feed_key, dec!(100), now - 600, None)
+        .await
+        .expect("write first price");
+    let SignatureView { signature: _ } = price_proxy
+        .write_price(feed_key, dec!(200), now - 300, None)
+        .await
+        .expect("write second price");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    info!(%price_feed);
+    assert_eq!(price_feed.twap_timestamp, now - 300);
+    assert_eq!(price_feed.try_cumulative_price().unwrap(), dec!(30000));
+    assert_eq!(price_feed.latest_twap_checkpoint().timestamp, now - 300);
+
+    // From the first checkpoint: 100 is held 300 sec, 200 is held since then
+    let twap = price_proxy.twap(feed_key, 590).await.expect("get TWAP");
+    info!(%twap);
+    assert!(twap.twap > dec!(100) && twap.twap < dec!(200));
+
+    let result = price_proxy.twap(feed_key, 900).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn offchain_price_history() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+    let authority_key = price_proxy.authority.pubkey();
+
+    let params = PriceFeedParams::new(
+        "Direct",
+        "SOL",
+        "USD",
+        "full",
+        "/sol",
+        "off-chain",
+        "off-chain",
+    );
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(params, authority_key, authority_key)
+        .await
+        .expect("create price-feed");
+
+    let PriceHistorySignatureView {
+        price_history: history_key,
+        ..
+    } = price_proxy
+        .create_price_history(feed_key)
+        .await
+        .expect("create price-history");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    assert_eq!(price_feed.price_history, history_key);
+
+    let now = Utc::now().timestamp();
+
+    let SignatureView { signature: _ } = price_proxy
+        .write_price(feed_key, dec!(100), now - 10, Some(dec!(1)))
+        .await
+        .expect("write first price");
+    let SignatureView { signature: _ } = price_proxy
+        .write_price(feed_key, dec!(101), now, None)
+        .await
+        .expect("write second price");
+
+    let price_history = price_proxy
+        .price_history(&history_key)
+        .await
+        .expect("price-history");
+    info!(%price_history);
+    assert_eq!(price_history.price_feed, feed_key);
+    assert_eq!(price_history.observations.len(), 2);
+    assert_eq!(
+        price_history.observations[0].try_price().unwrap(),
+        dec!(100)
+    );
+    assert_eq!(
+        price_history.observations[0].try_confidence().unwrap(),
+        dec!(1)
+    );
+    assert_eq!(price_history.observations[0].timestamp, now - 10);
+    assert_eq!(
+        price_history.observations[1].try_price().unwrap(),
+        dec!(101)
+    );
+    assert_eq!(price_history.observations[1].timestamp, now);
+
+    // History account must be passed once the feed has one
+    let ixs = price_proxy
+        .write_price_ix(feed_key, dec!(102), now, None)
+        .await;
+    let result = price_proxy
+        .send_transaction_by(ixs, &[&price_proxy.authority])
+        .await;
+    assert_custom_error(
+        result,
+        PriceProxyErrorDiscriminants::PriceHistoryAccountMissing,
+    );
+
+    let SignatureView { signature: _ } = price_proxy
+        .delete_price_history(history_key)
+        .await
+        .expect("delete price-history");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    assert!(!price_feed.has_price_history());
+
+    let SignatureView { signature: _ } = price_proxy
+        .write_price(feed_key, dec!(103), now, None)
+        .await
+        .expect("write price without history");
+}
+
+#[tokio::test]
+async fn migrate_legacy_price_feed() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+
+    let (account, _) = price_proxy
+        .get_account_with_slot(&LEGACY_PRICE_FEED)
+        .await
+        .expect("legacy price-feed");
+    assert_eq!(account.data.len(), LEGACY_PRICE_FEED_SIZE);
+
+    let SignatureView { signature: _ } = price_proxy
+        .migrate_price_feed(LEGACY_PRICE_FEED)
+        .await
+        .expect("migrate price-feed");
+
+    let PriceFeedView { price_feed, .. } = price_proxy
+        .price_feed(&LEGACY_PRICE_FEED)
+        .await
+        .expect("price-feed");
+    info!(%price_feed);
+    assert_eq!(price_feed.version, PriceFeed::VERSION);
+    assert_eq!(price_feed.try_price().unwrap(), dec!(150));
+    assert_eq!(price_feed.try_min_price().unwrap(), None);
+    assert_eq!(price_feed.try_max_price().unwrap(), None);
+
+    let SignatureView { signature: _ } = price_proxy
+        .write_price(LEGACY_PRICE_FEED, dec!(151), Utc::now().timestamp(), None)
+        .await
+        .expect("write price into migrated price-feed");
+}
+
+#[tokio::test]
+async fn offchain_writers() {
+    let TestContext { price_proxy, payer } = init_test().await;
+    let authority_key = price_proxy.authority.pubkey();
+
+    // Pricing bot with its own key
+    let writer = PriceProxyClient {
+        rpc: RpcClient::new(price_proxy.rpc.url()),
+        authority: Keypair::from_bytes(&payer.to_bytes()).unwrap(),
+        priority_fee: None,
+        show_spinner: true,
+    };
+    let writer_key = writer.authority.pubkey();
+
+    let params = PriceFeedParams::new(
+        "Direct",
+        "SOL",
+        "USD",
+        "full",
+        "/sol",
+        "off-chain",
+        "off-chain",
+    );
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(params, authority_key, authority_key)
+        .await
+        .expect("create price-feed");
+
+    let now = Utc::now().timestamp();
+
+    info!("source address writes while no writers are authorized");
+
+    let result = writer.write_price(feed_key, dec!(150), now, None).await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::UnauthorizedWriter);
+
+    let SignatureView { signature: _ } = price_proxy
+        .write_price(feed_key, dec!(150), now, None)
+        .await
+        .expect("write price by source address");
+
+    info!("authorize writer");
+
+    let SignatureView { signature: _ } = price_proxy
+        .add_writer(feed_key, writer_key)
+        .await
+        .expect("add writer");
+
+    let result = price_proxy.add_writer(feed_key, writer_key).await;
+    assert_custom_error(
+        result,
+        PriceProxyErrorDiscriminants::WriterAlreadyAuthorized,
+    );
+
+    let result = writer.add_writer(feed_key, Pubkey::new_unique()).await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::InvalidKey);
+
+    let SignatureView { signature: _ } = writer
+        .write_price(feed_key, dec!(151), now, None)
+        .await
+        .expect("write price by writer");
+
+    let result = price_proxy
+        .write_price(feed_key, dec!(152), now, None)
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::UnauthorizedWriter);
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    info!(%feed_key, %price_feed);
+    assert_eq!(price_feed.try_price().unwrap(), dec!(151));
+    assert!(price_feed.is_writer(&writer_key));
+
+    info!("writer slots are limited");
+
+    for _ in 1..MAX_WRITERS {
+        let SignatureView { signature: _ } = price_proxy
+            .add_writer(feed_key, Pubkey::new_unique())
+            .await
+            .expect("add writer");
+    }
+    let result = price_proxy.add_writer(feed_key, Pubkey::new_unique()).await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::TooManyWriters);
+
+    info!("revoke writer");
+
+    let SignatureView { signature: _ } = price_proxy
+        .remove_writer(feed_key, writer_key)
+        .await
+        .expect("remove writer");
+
+    let result = price_proxy.remove_writer(feed_key, writer_key).await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::WriterNotFound);
+
+    let result = writer.write_price(feed_key, dec!(153), now, None).await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::UnauthorizedWriter);
+}
+
+#[tokio::test]
+async fn offchain_last_writer_revoked() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+    let authority_key = price_proxy.authority.pubkey();
+
+    let params = PriceFeedParams::new(
+        "Direct",
+        "SOL",
+        "USD",
+        "full",
+        "/sol",
+        "off-chain",
+        "off-chain",
+    );
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(params, authority_key, authority_key)
+        .await
+        .expect("create price-feed");
+
+    let writer_key = Pubkey::new_unique();
+    let SignatureView { signature: _ } = price_proxy
+        .add_writer(feed_key, writer_key)
+        .await
+        .expect("add writer");
+    let SignatureView { signature: _ } = price_proxy
+        .remove_writer(feed_key, writer_key)
+        .await
+        .expect("remove writer");
+
+    info!("revoking the last writer doesn't give access back to source address");
+
+    let now = Utc::now().timestamp();
+    let result = price_proxy
+        .write_price(feed_key, dec!(150), now, None)
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::UnauthorizedWriter);
+
+    info!("source address is authorized explicitly");
+
+    let SignatureView { signature: _ } = price_proxy
+        .add_writer(feed_key, authority_key)
+        .await
+        .expect("add source address as writer");
+    let SignatureView { signature: _ } = price_proxy
+        .write_price(feed_key, dec!(150), now, None)
+        .await
+        .expect("write price by source address");
+}
+
+#[tokio::test]
+async fn offchain_signed_price() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+    let authority_key = price_proxy.authority.pubkey();
+
+    // Writer only signs, the authority relays and pays fees
+    let writer = Keypair::new();
+    let writer_key = writer.pubkey();
+
+    let params = PriceFeedParams::new(
+        "Direct",
+        "SOL",
+        "USD",
+        "full",
+        "/sol",
+        "off-chain",
+        "off-chain",
+    );
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(params, authority_key, authority_key)
+        .await
+        .expect("create price-feed");
+
+    let now = Utc::now().timestamp();
+    let message = SignedPriceMessage {
+        price_feed: feed_key,
+        price: dec!(150),
+        price_timestamp: now,
+        generation: 1,
+        nonce: 1,
+    };
+
+    info!("signer must be authorized writer");
+
+    let signature = sign_price(&writer, &message);
+    let result = price_proxy
+        .write_signed_price(&writer_key, &signature, &message)
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::UnauthorizedWriter);
+
+    let SignatureView { signature: _ } = price_proxy
+        .add_writer(feed_key, writer_key)
+        .await
+        .expect("add writer");
+
+    info!("relay signed price");
+
+    let SignatureView { signature: _ } = price_proxy
+        .write_signed_price(&writer_key, &signature, &message)
+        .await
+        .expect("write signed price");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    info!(%feed_key, %price_feed);
+    assert_eq!(price_feed.try_price().unwrap(), dec!(150));
+    assert_eq!(price_feed.update_timestamp, now);
+    assert_eq!(price_feed.writer_submissions[0].signed_price_nonce, 1);
+
+    info!("signed price can't be replayed");
+
+    let result = price_proxy
+        .write_signed_price(&writer_key, &signature, &message)
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::SignedPriceNonceUsed);
+
+    info!("signature doesn't match tampered message");
+
+    let tampered = SignedPriceMessage {
+        price: dec!(1500),
+        nonce: 2,
+        ..message
+    };
+    let result = price_proxy
+        .write_signed_price(&writer_key, &signature, &tampered)
+        .await;
+    assert!(result.is_err(), "tampered message accepted");
+
+    info!("message signed for other feed");
+
+    let other_feed = SignedPriceMessage {
+        price_feed: LEGACY_PRICE_FEED,
+        nonce: 2,
+        ..message
+    };
+    let mut ixs =
+        write_signed_price_ixs(&writer_key, &sign_price(&writer, &other_feed), &other_feed);
+    // Points WriteSignedPrice to the feed of the test
+    ixs[1].accounts[0].pubkey = feed_key;
+    let result = price_proxy
+        .send_transaction_by(ixs, &[&price_proxy.authority])
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::InvalidKey);
+
+    let next = SignedPriceMessage {
+        price: dec!(151),
+        nonce: 2,
+        ..message
+    };
+    let SignatureView { signature: _ } = price_proxy
+        .write_signed_price(&writer_key, &sign_price(&writer, &next), &next)
+        .await
+        .expect("write next signed price");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    assert_eq!(price_feed.try_price().unwrap(), dec!(151));
+    assert_eq!(price_feed.writer_submissions[0].signed_price_nonce, 2);
+}
+
+#[tokio::test]
+async fn offchain_signed_price_nonce_per_writer() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+    let authority_key = price_proxy.authority.pubkey();
+
+    let writer_a = Keypair::new();
+    let writer_b = Keypair::new();
+
+    let params = PriceFeedParams::new(
+        "Direct",
+        "SOL",
+        "USD",
+        "full",
+        "/sol",
+        "off-chain",
+        "off-chain",
+    );
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(params, authority_key, authority_key)
+        .await
+        .expect("create price-feed");
+
+    for writer in [&writer_a, &writer_b] {
+        let SignatureView { signature: _ } = price_proxy
+            .add_writer(feed_key, writer.pubkey())
+            .await
+            .expect("add writer");
+    }
+
+    let now = Utc::now().timestamp();
+
+    info!("writer A uses the largest nonce");
+
+    let message_a = SignedPriceMessage {
+        price_feed: feed_key,
+        price: dec!(150),
+        price_timestamp: now,
+        generation: 1,
+        nonce: u64::MAX,
+    };
+    let SignatureView { signature: _ } = price_proxy
+        .write_signed_price(
+            &writer_a.pubkey(),
+            &sign_price(&writer_a, &message_a),
+            &message_a,
+        )
+        .await
+        .expect("write signed price of writer A");
+
+    info!("writer B is not blocked and starts from its own nonce");
+
+    let message_b = SignedPriceMessage {
+        price: dec!(151),
+        generation: 2,
+        nonce: 1,
+        ..message_a
+    };
+    let SignatureView { signature: _ } = price_proxy
+        .write_signed_price(
+            &writer_b.pubkey(),
+            &sign_price(&writer_b, &message_b),
+            &message_b,
+        )
+        .await
+        .expect("write signed price of writer B");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    info!(%feed_key, %price_feed);
+    assert_eq!(price_feed.try_price().unwrap(), dec!(151));
+    assert_eq!(
+        price_feed.writer_submissions[0].signed_price_nonce,
+        u64::MAX
+    );
+    assert_eq!(price_feed.writer_submissions[1].signed_price_nonce, 1);
+
+    info!("writer A can't reuse its nonce");
+
+    let result = price_proxy
+        .write_signed_price(
+            &writer_a.pubkey(),
+            &sign_price(&writer_a, &message_a),
+            &message_a,
+        )
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::SignedPriceNonceUsed);
+}
+
+#[tokio::test]
+async fn offchain_signed_price_not_replayed_after_writer_change() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+    let authority_key = price_proxy.authority.pubkey();
+
+    let writer_a = Keypair::new();
+    let writer_b = Keypair::new();
+
+    let params = PriceFeedParams::new(
+        "Direct",
+        "SOL",
+        "USD",
+        "full",
+        "/sol",
+        "off-chain",
+        "off-chain",
+    );
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(params, authority_key, authority_key)
+        .await
+        .expect("create price-feed");
+
+    let SignatureView { signature: _ } = price_proxy
+        .add_writer(feed_key, writer_a.pubkey())
+        .await
+        .expect("add writer A");
+
+    let now = Utc::now().timestamp();
+    let message_a = SignedPriceMessage {
+        price_feed: feed_key,
+        price: dec!(150),
+        price_timestamp: now,
+        generation: 1,
+        nonce: 1,
+    };
+    let signature_a = sign_price(&writer_a, &message_a);
+    let SignatureView { signature: _ } = price_proxy
+        .write_signed_price(&writer_a.pubkey(), &signature_a, &message_a)
+        .await
+        .expect("write signed price of writer A");
+
+    info!("re-added writer can't replay its old signed price");
+
+    let SignatureView { signature: _ } = price_proxy
+        .remove_writer(feed_key, writer_a.pubkey())
+        .await
+        .expect("remove writer A");
+    let SignatureView { signature: _ } = price_proxy
+        .add_writer(feed_key, writer_a.pubkey())
+        .await
+        .expect("add writer A again");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    assert_eq!(price_feed.writer_generation_of(&writer_a.pubkey()), Some(2));
+    assert_eq!(price_feed.writer_submissions[0].signed_price_nonce, 0);
+
+    let result = price_proxy
+        .write_signed_price(&writer_a.pubkey(), &signature_a, &message_a)
+        .await;
+    assert_custom_error(
+        result,
+        PriceProxyErrorDiscriminants::SignedPriceGenerationMismatch,
+    );
+
+    let message_a = SignedPriceMessage {
+        price: dec!(151),
+        generation: 2,
+        ..message_a
+    };
+    let SignatureView { signature: _ } = price_proxy
+        .write_signed_price(
+            &writer_a.pubkey(),
+            &sign_price(&writer_a, &message_a),
+            &message_a,
+        )
+        .await
+        .expect("write signed price of re-added writer A");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    assert_eq!(price_feed.try_price().unwrap(), dec!(151));
+
+    info!("next writer of the slot starts from zero nonce of its own generation");
+
+    let SignatureView { signature: _ } = price_proxy
+        .remove_writer(feed_key, writer_a.pubkey())
+        .await
+        .expect("remove writer A");
+    let SignatureView { signature: _ } = price_proxy
+        .add_writer(feed_key, writer_b.pubkey())
+        .await
+        .expect("add writer B");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    assert_eq!(price_feed.writer_index(&writer_b.pubkey()), Some(0));
+    assert_eq!(price_feed.writer_generation_of(&writer_b.pubkey()), Some(3));
+    assert_eq!(price_feed.writer_submissions[0].signed_price_nonce, 0);
+
+    let message_b = SignedPriceMessage {
+        price: dec!(152),
+        generation: 3,
+        nonce: 1,
+        ..message_a
+    };
+    let SignatureView { signature: _ } = price_proxy
+        .write_signed_price(
+            &writer_b.pubkey(),
+            &sign_price(&writer_b, &message_b),
+            &message_b,
+        )
+        .await
+        .expect("write signed price of writer B");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    assert_eq!(price_feed.try_price().unwrap(), dec!(152));
+}
@@ -0,0 +1,217 @@
+use price_proxy_client::{
+    PriceFeedSignatureView, PriceFeedView, PriceHistorySignatureView, PriceProxyClient,
+    SignatureView,
+};
+
+use pretty_assertions::assert_eq;
+use tracing::info;
+
+use price_proxy::error::PriceProxyErrorDiscriminants;
+use price_proxy::state::price_feed::PriceFeedParams;
+
+mod utils;
+use utils::*;
+
+#[tokio::test]
+async fn propose_and_accept() {
+    let TestContext { price_proxy, payer } = init_test().await;
+
+    let authority_key = price_proxy.authority.pubkey();
+
+    let new_authority = PriceProxyClient {
+        rpc: RpcClient::new(price_proxy.rpc.url()),
+        authority: Keypair::from_bytes(&payer.to_bytes()).unwrap(),
+        priority_fee: None,
+        show_spinner: true,
+    };
+    let new_authority_key = new_authority.authority.pubkey();
+
+    let params = PriceFeedParams::new(
+        "Direct",
+        "SOL",
+        "USD",
+        "full",
+        "/sol",
+        "off-chain",
+        "off-chain",
+    );
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(params, authority_key, authority_key)
+        .await
+        .expect("create price-feed");
+
+    info!("nothing to accept");
+
+    let result = new_authority.accept_authority(feed_key).await;
+    assert_custom_error(
+        result,
+        PriceProxyErrorDiscriminants::OperationCanNotBePerformed,
+    );
+
+    info!("only update authority can propose");
+
+    let result = new_authority
+        .propose_authority(feed_key, new_authority_key)
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::InvalidKey);
+
+    info!("propose new authority");
+
+    let SignatureView { signature: _ } = price_proxy
+        .propose_authority(feed_key, new_authority_key)
+        .await
+        .expect("propose authority");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    assert_eq!(price_feed.update_authority, authority_key);
+    assert_eq!(price_feed.pending_authority, new_authority_key);
+
+    info!("only pending authority can accept");
+
+    let result = price_proxy.accept_authority(feed_key).await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::InvalidKey);
+
+    info!("accept authority");
+
+    let SignatureView { signature: _ } = new_authority
+        .accept_authority(feed_key)
+        .await
+        .expect("accept authority");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    assert_eq!(price_feed.update_authority, new_authority_key);
+    assert!(!price_feed.has_pending_authority());
+
+    info!("old authority lost control over price-feed");
+
+    let result = price_proxy.delete_price_feed(feed_key).await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::InvalidKey);
+
+    let result = price_proxy.propose_authority(feed_key, authority_key).await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::InvalidKey);
+}
+
+#[tokio::test]
+async fn cancel_proposal() {
+    let TestContext { price_proxy, payer } = init_test().await;
+
+    let authority_key = price_proxy.authority.pubkey();
+
+    let new_authority = PriceProxyClient {
+        rpc: RpcClient::new(price_proxy.rpc.url()),
+        authority: Keypair::from_bytes(&payer.to_bytes()).unwrap(),
+        priority_fee: None,
+        show_spinner: true,
+    };
+    let new_authority_key = new_authority.authority.pubkey();
+
+    let params = PriceFeedParams::new(
+        "Direct",
+        "SOL",
+        "USD",
+        "full",
+        "/sol",
+        "off-chain",
+        "off-chain",
+    );
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(params, authority_key, authority_key)
+        .await
+        .expect("create price-feed");
+
+    let SignatureView { signature: _ } = price_proxy
+        .propose_authority(feed_key, new_authority_key)
+        .await
+        .expect("propose authority");
+
+    info!("proposing current authority cancels transfer");
+
+    let SignatureView { signature: _ } = price_proxy
+        .propose_authority(feed_key, authority_key)
+        .await
+        .expect("cancel proposal");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    assert_eq!(price_feed.update_authority, authority_key);
+    assert!(!price_feed.has_pending_authority());
+
+    let result = new_authority.accept_authority(feed_key).await;
+    assert_custom_error(
+        result,
+        PriceProxyErrorDiscriminants::OperationCanNotBePerformed,
+    );
+}
+
+#[tokio::test]
+async fn price_history_follows_authority() {
+    let TestContext { price_proxy, payer } = init_test().await;
+
+    let authority_key = price_proxy.authority.pubkey();
+
+    let new_authority = PriceProxyClient {
+        rpc: RpcClient::new(price_proxy.rpc.url()),
+        authority: Keypair::from_bytes(&payer.to_bytes()).unwrap(),
+        priority_fee: None,
+        show_spinner: true,
+    };
+    let new_authority_key = new_authority.authority.pubkey();
+
+    let params = PriceFeedParams::new(
+        "Direct",
+        "SOL",
+        "USD",
+        "full",
+        "/sol",
+        "off-chain",
+        "off-chain",
+    );
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(params, authority_key, authority_key)
+        .await
+        .expect("create price-feed");
+
+    let PriceHistorySignatureView {
+        price_history: history_key,
+        ..
+    } = price_proxy
+        .create_price_history(feed_key)
+        .await
+        .expect("create price-history");
+
+    let SignatureView { signature: _ } = price_proxy
+        .propose_authority(feed_key, new_authority_key)
+        .await
+        .expect("propose authority");
+    let SignatureView { signature: _ } = new_authority
+        .accept_authority(feed_key)
+        .await
+        .expect("accept authority");
+
+    info!("old authority can't delete price history of transferred price-feed");
+
+    let result = price_proxy.delete_price_history(history_key).await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::InvalidKey);
+
+    info!("new authority deletes price history");
+
+    let SignatureView { signature: _ } = new_authority
+        .delete_price_history(history_key)
+        .await
+        .expect("delete price-history");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    assert!(!price_feed.has_price_history());
+}
@@ -1,6 +1,11 @@
 use anchor_lang::AccountDeserialize;
-use price_proxy_client::{PriceFeedSignatureView, PriceFeedView, SignatureView};
+use chrono::Utc;
+use price_proxy_client::{
+    insert_reference_account, PriceFeedSignatureView, PriceFeedView, SignatureView,
+};
 use std::str::FromStr;
+use std::thread::sleep;
+use std::time::Duration;
 
 use pretty_assertions::assert_eq;
 use pyth_solana_receiver_sdk::price_update::{get_feed_id_from_hex, PriceUpdateV2};
@@ -8,7 +13,10 @@ use super_lendy::state::reserve::Reserve;
 use switchboard_solana::AggregatorAccountData;
 use tracing::info;
 
-use price_proxy::state::price_feed::{PriceFeed, PriceFeedParams, WormholeVerificationLevel};
+use price_proxy::error::PriceProxyErrorDiscriminants;
+use price_proxy::state::price_feed::{
+    ActiveSource, PriceFeed, PriceFeedParams, WormholeVerificationLevel,
+};
 
 mod utils;
 use utils::*;
@@ -79,6 +87,9 @@ async fn update_from_pyth_success() {
     let pyth_price = price_update
         .get_price_unchecked(&price_update.price_message.feed_id)
         .expect("get Pyth price");
+    expected
+        .try_accumulate_twap(price_feed.update_timestamp)
+        .unwrap();
     expected
         .try_set_price(
             Decimal::from_i128_with_scale(
@@ -90,6 +101,24 @@ async fn update_from_pyth_success() {
             price_feed.update_slot,
         )
         .unwrap();
+    expected
+        .try_set_confidence(
+            Decimal::from_i128_with_scale(
+                pyth_price.conf as i128,
+                pyth_price.exponent.unsigned_abs(),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+    let exponent = price_update.price_message.exponent.unsigned_abs();
+    expected
+        .try_set_ema(
+            Decimal::from_i128_with_scale(price_update.price_message.ema_price as i128, exponent)
+                .unwrap(),
+            Decimal::from_i128_with_scale(price_update.price_message.ema_conf as i128, exponent)
+                .unwrap(),
+        )
+        .unwrap();
 
     assert_eq!(price_feed, expected);
 }
@@ -209,6 +238,9 @@ async fn update_from_switchboard_success() {
     let data_feed = AggregatorAccountData::new_from_bytes(source_acc.data.as_slice())
         .expect("deserialize Switchboard feed");
     let feed_result = data_feed.get_result().expect("get Switchboard price");
+    expected
+        .try_accumulate_twap(price_feed.update_timestamp)
+        .unwrap();
     expected
         .try_set_price(
             Decimal::from_i128_with_scale(feed_result.mantissa, feed_result.scale).unwrap(),
@@ -216,6 +248,19 @@ async fn update_from_switchboard_success() {
             price_feed.update_slot,
         )
         .unwrap();
+    let std_deviation = data_feed.latest_confirmed_round.std_deviation;
+    expected
+        .try_set_confidence(
+            Decimal::from_i128_with_scale(std_deviation.mantissa, std_deviation.scale).unwrap(),
+        )
+        .unwrap();
+    // EMA is computed on-chain and starts from the first price
+    expected
+        .try_set_ema(
+            expected.try_price().unwrap(),
+            expected.try_confidence().unwrap(),
+        )
+        .unwrap();
 
     assert_eq!(price_feed, expected);
 }
@@ -273,6 +318,64 @@ async fn update_from_switchboard_incorrect_source() {
     assert!(result.is_err())
 }
 
+#[tokio::test]
+async fn update_from_switchboard_wide_confidence() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+    let authority_key = price_proxy.authority.pubkey();
+
+    let expected_params = PriceFeedParams::new(
+        "Direct",
+        "SOL",
+        "USD",
+        "full",
+        "/sol",
+        "switchboard",
+        "switchboard",
+    )
+    .with_max_confidence_bps(100);
+    let expected = PriceFeed::new(
+        expected_params,
+        authority_key,
+        SB_SOL_WIDE_CONF_PRICE_SOURCE,
+        SB_SOL_WIDE_CONF_PRICE_SOURCE,
+    );
+
+    // CREATE PRICE-FEED
+
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(
+            expected_params,
+            SB_SOL_WIDE_CONF_PRICE_SOURCE,
+            SB_SOL_WIDE_CONF_PRICE_SOURCE,
+        )
+        .await
+        .expect("create price-feed");
+
+    // UPDATE PRICE FROM AGGREGATOR WITH STD DEVIATION OF 10%
+
+    info!("std deviation of Switchboard round is the confidence");
+    let max_age_sec = 100000000; // cause synthesized aggregator is not updating
+    let result = price_proxy
+        .update_price(
+            feed_key,
+            SB_SOL_WIDE_CONF_PRICE_SOURCE,
+            SB_SOL_WIDE_CONF_PRICE_SOURCE,
+            max_age_sec,
+        )
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::ConfidenceTooWide);
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    assert_eq!(price_feed, expected);
+}
+
 #[tokio::test]
 async fn update_from_superlendy_success() {
     let TestContext {
@@ -335,6 +438,9 @@ async fn update_from_superlendy_success() {
     let data_feed =
         Reserve::try_from_bytes(source_acc.data.as_slice()).expect("deserialize reserve acc");
     let price = data_feed.lp_market_price().expect("get lp market price");
+    expected
+        .try_accumulate_twap(price_feed.update_timestamp)
+        .unwrap();
     expected
         .try_set_price(
             price,
@@ -342,6 +448,8 @@ async fn update_from_superlendy_success() {
             price_feed.update_slot,
         )
         .unwrap();
+    // EMA is computed on-chain and starts from the first price
+    expected.try_set_ema(price, Decimal::ZERO).unwrap();
 
     assert_eq!(price_feed, expected);
 }
@@ -398,3 +506,1096 @@ async fn update_from_superlendy_incorrect_source() {
         .await;
     assert!(result.is_err())
 }
+
+#[tokio::test]
+async fn update_from_pyth_narrow_confidence() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+    let authority_key = price_proxy.authority.pubkey();
+
+    let feed_id = get_feed_id_from_hex(PYTH_SOL_FEED_ID_HEX).expect("feed_id_from_hex");
+    let source_address = Pubkey::from(feed_id);
+
+    let expected_params =
+        PriceFeedParams::new("Direct", "SOL", "USD", "full", "/sol", "pyth", "pyth")
+            .with_max_confidence_bps(100);
+    let mut expected = PriceFeed::new(
+        expected_params,
+        authority_key,
+        source_address,
+        source_address,
+    );
+
+    // CREATE PRICE-FEED
+
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(expected_params, source_address, source_address)
+        .await
+        .expect("create price-feed");
+
+    // UPDATE PRICE FROM SYNTHESIZED PRICE UPDATE WITH 0.01% CONFIDENCE
+
+    let max_age_sec = 100000000; // cause synthesized price update is not updating
+    let SignatureView { signature: _ } = price_proxy
+        .update_price(
+            feed_key,
+            PYTH_SOL_NARROW_CONF_PRICE_UPDATE,
+            PYTH_SOL_NARROW_CONF_PRICE_UPDATE,
+            max_age_sec,
+        )
+        .await
+        .expect("update price");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    info!(%feed_key, %price_feed);
+
+    expected
+        .try_accumulate_twap(price_feed.update_timestamp)
+        .unwrap();
+    expected
+        .try_set_price(
+            dec!(150),
+            price_feed.update_timestamp,
+            price_feed.update_slot,
+        )
+        .unwrap();
+    expected.try_set_confidence(dec!(0.015)).unwrap();
+    // synthesized price update has EMA equal to the price
+    expected.try_set_ema(dec!(150), dec!(0.015)).unwrap();
+    assert_eq!(price_feed, expected);
+}
+
+#[tokio::test]
+async fn update_from_pyth_wide_confidence() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+    let authority_key = price_proxy.authority.pubkey();
+
+    let feed_id = get_feed_id_from_hex(PYTH_SOL_FEED_ID_HEX).expect("feed_id_from_hex");
+    let source_address = Pubkey::from(feed_id);
+
+    let expected_params =
+        PriceFeedParams::new("Direct", "SOL", "USD", "full", "/sol", "pyth", "pyth")
+            .with_max_confidence_bps(100);
+    let expected = PriceFeed::new(
+        expected_params,
+        authority_key,
+        source_address,
+        source_address,
+    );
+
+    // CREATE PRICE-FEED
+
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(expected_params, source_address, source_address)
+        .await
+        .expect("create price-feed");
+
+    // UPDATE PRICE FROM SYNTHESIZED PRICE UPDATE WITH 10% CONFIDENCE
+
+    info!("update price with too wide confidence");
+    let max_age_sec = 100000000; // cause synthesized price update is not updating
+    let result = price_proxy
+        .update_price(
+            feed_key,
+            PYTH_SOL_WIDE_CONF_PRICE_UPDATE,
+            PYTH_SOL_WIDE_CONF_PRICE_UPDATE,
+            max_age_sec,
+        )
+        .await;
+    assert!(result.is_err());
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    assert_eq!(price_feed, expected);
+}
+
+#[tokio::test]
+async fn update_from_pyth_older_price_update() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+
+    let feed_id = get_feed_id_from_hex(PYTH_SOL_FEED_ID_HEX).expect("feed_id_from_hex");
+    let source_address = Pubkey::from(feed_id);
+
+    let params = PriceFeedParams::new("Direct", "SOL", "USD", "full", "/sol", "pyth", "pyth");
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(params, source_address, source_address)
+        .await
+        .expect("create price-feed");
+
+    let max_age_sec = 100000000; // cause synthesized price update is not updating
+    let SignatureView { signature: _ } = price_proxy
+        .update_price(
+            feed_key,
+            PYTH_SOL_NARROW_CONF_PRICE_UPDATE,
+            PYTH_SOL_NARROW_CONF_PRICE_UPDATE,
+            max_age_sec,
+        )
+        .await
+        .expect("update price");
+
+    let PriceFeedView {
+        price_feed: expected,
+        ..
+    } = price_proxy.price_feed(&feed_key).await.expect("price-feed");
+
+    // OLDER PRICE UPDATE WITHIN MAXIMUM AGE CAN'T ROLL THE PRICE BACK
+
+    info!("update price from older price update");
+    let result = price_proxy
+        .update_price(
+            feed_key,
+            PYTH_SOL_OLD_PRICE_UPDATE,
+            PYTH_SOL_OLD_PRICE_UPDATE,
+            max_age_sec,
+        )
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::TimestampIsNotRecent);
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    info!(%feed_key, %price_feed);
+    assert_eq!(price_feed, expected);
+    assert_eq!(price_feed.try_price().unwrap(), dec!(150));
+}
+
+#[tokio::test]
+async fn update_from_pyth_invalid_price_update() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+
+    let feed_id = get_feed_id_from_hex(PYTH_SOL_FEED_ID_HEX).expect("feed_id_from_hex");
+    let source_address = Pubkey::from(feed_id);
+
+    let params = PriceFeedParams::new("Direct", "SOL", "USD", "full", "/sol", "pyth", "pyth");
+
+    // CREATE PRICE-FEED
+
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(params, source_address, source_address)
+        .await
+        .expect("create price-feed");
+
+    // UPDATE PRICE FROM NON PYTH ACCOUNT
+
+    info!("update price from Switchboard account");
+    let max_age_sec = 100000000;
+    let result = price_proxy
+        .update_price(
+            feed_key,
+            SB_SOL_PRICE_SOURCE,
+            SB_SOL_PRICE_SOURCE,
+            max_age_sec,
+        )
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::SourceOwnerMismatch);
+
+    // UPDATE PRICE FROM PYTH RECEIVER ACCOUNT OF ANOTHER LAYOUT
+
+    info!("update price from Pyth receiver config account");
+    let result = price_proxy
+        .update_price(feed_key, PYTH_CONFIG, PYTH_CONFIG, max_age_sec)
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::PythDeserialize);
+}
+
+#[tokio::test]
+async fn update_from_pyth_spoofed_price_update() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+
+    let feed_id = get_feed_id_from_hex(PYTH_SOL_FEED_ID_HEX).expect("feed_id_from_hex");
+    let source_address = Pubkey::from(feed_id);
+
+    let params = PriceFeedParams::new("Direct", "SOL", "USD", "full", "/sol", "pyth", "pyth");
+
+    // CREATE PRICE-FEED
+
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(params, source_address, source_address)
+        .await
+        .expect("create price-feed");
+
+    // UPDATE PRICE FROM LOOK-ALIKE PRICE UPDATE NOT OWNED BY PYTH RECEIVER
+
+    info!("update price from spoofed price update");
+    let max_age_sec = 100000000;
+    let result = price_proxy
+        .update_price(
+            feed_key,
+            PYTH_SOL_SPOOFED_PRICE_UPDATE,
+            PYTH_SOL_SPOOFED_PRICE_UPDATE,
+            max_age_sec,
+        )
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::SourceOwnerMismatch);
+}
+
+#[tokio::test]
+async fn update_from_switchboard_stale() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+
+    let params = PriceFeedParams::new(
+        "Direct",
+        "SOL",
+        "USD",
+        "full",
+        "/sol",
+        "switchboard",
+        "switchboard",
+    );
+
+    // CREATE PRICE-FEED
+
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(params, SB_SOL_PRICE_SOURCE, SB_SOL_PRICE_SOURCE)
+        .await
+        .expect("create price-feed");
+
+    // UPDATE PRICE FROM STALE FEED
+
+    info!("update price from stale feed");
+    let max_age_sec = 60; // feed from switchboard_sol_price.json is not updating
+    let result = price_proxy
+        .update_price(
+            feed_key,
+            SB_SOL_PRICE_SOURCE,
+            SB_SOL_PRICE_SOURCE,
+            max_age_sec,
+        )
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::SwitchboardStale);
+}
+
+#[tokio::test]
+async fn update_from_marinade_success() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+    let authority_key = price_proxy.authority.pubkey();
+
+    let expected_params = PriceFeedParams::new(
+        "Direct", "MSOL", "SOL", "full", "/msol", "marinade", "marinade",
+    );
+    let mut expected = PriceFeed::new(
+        expected_params,
+        authority_key,
+        MARINADE_STATE_SOURCE,
+        MARINADE_STATE_SOURCE,
+    );
+
+    // CREATE PRICE-FEED
+
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(
+            expected_params,
+            MARINADE_STATE_SOURCE,
+            MARINADE_STATE_SOURCE,
+        )
+        .await
+        .expect("create price-feed");
+
+    // UPDATE PRICE
+
+    let max_age_sec = 60;
+    let SignatureView { signature: _ } = price_proxy
+        .update_price(
+            feed_key,
+            MARINADE_STATE_SOURCE,
+            SB_SOL_NARROW_CONF_PRICE_SOURCE,
+            max_age_sec,
+        )
+        .await
+        .expect("update price");
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    info!(%feed_key, %price_feed);
+
+    expected
+        .try_accumulate_twap(price_feed.update_timestamp)
+        .unwrap();
+    expected
+        .try_set_price(
+            Decimal::from_i128_with_scale(125, 2).unwrap(),
+            price_feed.update_timestamp,
+            price_feed.update_slot,
+        )
+        .unwrap();
+    // EMA is computed on-chain and starts from the first price
+    expected
+        .try_set_ema(
+            Decimal::from_i128_with_scale(125, 2).unwrap(),
+            Decimal::ZERO,
+        )
+        .unwrap();
+
+    assert_eq!(price_feed, expected);
+}
+
+#[tokio::test]
+async fn update_from_marinade_incorrect_source() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+
+    let params = PriceFeedParams::new(
+        "Direct", "MSOL", "SOL", "full", "/msol", "marinade", "marinade",
+    );
+
+    // CREATE PRICE-FEED POINTING TO STAKE POOL
+
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(params, SANCTUM_SPL_POOL_SOURCE, SANCTUM_SPL_POOL_SOURCE)
+        .await
+        .expect("create price-feed");
+
+    // UPDATE PRICE FROM ACCOUNT OF ANOTHER PROGRAM
+
+    info!("update price from account of another program");
+    let max_age_sec = 60;
+    let result = price_proxy
+        .update_price(
+            feed_key,
+            SANCTUM_SPL_POOL_SOURCE,
+            SANCTUM_SPL_POOL_SOURCE,
+            max_age_sec,
+        )
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::SourceOwnerMismatch);
+}
+
+#[tokio::test]
+async fn update_from_marinade_spoofed_state() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+
+    let params = PriceFeedParams::new(
+        "Direct", "MSOL", "SOL", "full", "/msol", "marinade", "marinade",
+    );
+
+    // CREATE PRICE-FEED POINTING TO LOOK-ALIKE STATE
+
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(
+            params,
+            MARINADE_SPOOFED_STATE_SOURCE,
+            MARINADE_SPOOFED_STATE_SOURCE,
+        )
+        .await
+        .expect("create price-feed");
+
+    // UPDATE PRICE
+
+    info!("update price from spoofed Marinade state");
+    let max_age_sec = 60;
+    let result = price_proxy
+        .update_price(
+            feed_key,
+            MARINADE_SPOOFED_STATE_SOURCE,
+            MARINADE_SPOOFED_STATE_SOURCE,
+            max_age_sec,
+        )
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::SourceOwnerMismatch);
+}
+
+#[tokio::test]
+async fn update_from_stake_pool_spoofed_pool() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+
+    for source in ["stake-pool", "sanctum-spl"] {
+        let params = PriceFeedParams::new("Direct", "LST", "SOL", "full", "/lst", source, source);
+
+        // CREATE PRICE-FEED POINTING TO LOOK-ALIKE STAKE POOL
+
+        let PriceFeedSignatureView {
+            price_feed: feed_key,
+            ..
+        } = price_proxy
+            .create_price_feed(params, SPOOFED_STAKE_POOL_SOURCE, SPOOFED_STAKE_POOL_SOURCE)
+            .await
+            .expect("create price-feed");
+
+        // UPDATE PRICE
+
+        info!(source, "update price from spoofed stake pool");
+        let max_age_sec = 60;
+        let result = price_proxy
+            .update_price(
+                feed_key,
+                SPOOFED_STAKE_POOL_SOURCE,
+                SPOOFED_STAKE_POOL_SOURCE,
+                max_age_sec,
+            )
+            .await;
+        assert_custom_error(result, PriceProxyErrorDiscriminants::SourceOwnerMismatch);
+    }
+}
+
+#[tokio::test]
+async fn update_from_sanctum_spl_success() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+    let authority_key = price_proxy.authority.pubkey();
+
+    let expected_params = PriceFeedParams::new(
+        "Direct",
+        "LST",
+        "SOL",
+        "full",
+        "/lst",
+        "sanctum-spl",
+        "sanctum-spl",
+    );
+    let mut expected = PriceFeed::new(
+        expected_params,
+        authority_key,
+        SANCTUM_SPL_POOL_SOURCE,
+        SANCTUM_SPL_POOL_SOURCE,
+    );
+
+    // CREATE PRICE-FEED
+
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(
+            expected_params,
+            SANCTUM_SPL_POOL_SOURCE,
+            SANCTUM_SPL_POOL_SOURCE,
+        )
+        .await
+        .expect("create price-feed");
+
+    // UPDATE PRICE
+
+    let max_age_sec = 60;
+    let SignatureView { signature: _ } = price_proxy
+        .update_price(
+            feed_key,
+            SANCTUM_SPL_POOL_SOURCE,
+            SANCTUM_SPL_POOL_SOURCE,
+            max_age_sec,
+        )
+        .await
+        .expect("update price");
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    info!(%feed_key, %price_feed);
+
+    expected
+        .try_accumulate_twap(price_feed.update_timestamp)
+        .unwrap();
+    expected
+        .try_set_price(
+            Decimal::from_i128_with_scale(11, 1).unwrap(),
+            price_feed.update_timestamp,
+            price_feed.update_slot,
+        )
+        .unwrap();
+    // EMA is computed on-chain and starts from the first price
+    expected
+        .try_set_ema(Decimal::from_i128_with_scale(11, 1).unwrap(), Decimal::ZERO)
+        .unwrap();
+
+    assert_eq!(price_feed, expected);
+}
+
+#[tokio::test]
+async fn update_transform_divide() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+    let authority_key = price_proxy.authority.pubkey();
+
+    let feed_id = get_feed_id_from_hex(PYTH_SOL_FEED_ID_HEX).expect("feed_id_from_hex");
+    let source_address = Pubkey::from(feed_id);
+
+    // SOL/USD / SOL/USD must give exactly 1
+    let expected_params =
+        PriceFeedParams::new("Transform", "SOL", "SOL", "full", "/sol", "pyth", "pyth")
+            .with_transform_op("divide");
+    let mut expected = PriceFeed::new(
+        expected_params,
+        authority_key,
+        source_address,
+        source_address,
+    );
+
+    // CREATE PRICE-FEED
+
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(expected_params, source_address, source_address)
+        .await
+        .expect("create price-feed");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    assert_eq!(price_feed, expected);
+
+    // UPDATE PRICE
+
+    let max_age_sec = 100000000; // cause synthesized price updates are not updating
+    let SignatureView { signature: _ } = price_proxy
+        .update_price(
+            feed_key,
+            PYTH_SOL_NARROW_CONF_PRICE_UPDATE,
+            PYTH_SOL_WIDE_CONF_PRICE_UPDATE,
+            max_age_sec,
+        )
+        .await
+        .expect("update price");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    info!(%feed_key, %price_feed);
+
+    expected
+        .try_accumulate_twap(price_feed.update_timestamp)
+        .unwrap();
+    expected
+        .try_set_price(dec!(1), price_feed.update_timestamp, price_feed.update_slot)
+        .unwrap();
+    // (0.015 + 1 * 15) / 150
+    expected.try_set_confidence(dec!(0.1001)).unwrap();
+    // EMA of transformed price is computed on-chain and starts from the first price
+    expected.try_set_ema(dec!(1), dec!(0.1001)).unwrap();
+    assert_eq!(price_feed, expected);
+}
+
+#[tokio::test]
+async fn update_fallback_when_primary_stale() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+    let authority_key = price_proxy.authority.pubkey();
+
+    let feed_id = get_feed_id_from_hex(PYTH_SOL_FEED_ID_HEX).expect("feed_id_from_hex");
+    let source_address = Pubkey::from(feed_id);
+
+    let expected_params =
+        PriceFeedParams::new("Direct", "SOL", "USD", "full", "/sol", "pyth", "pyth")
+            .with_fallback("switchboard", SB_SOL_NARROW_CONF_PRICE_SOURCE);
+    let mut expected = PriceFeed::new(
+        expected_params,
+        authority_key,
+        source_address,
+        source_address,
+    );
+
+    // CREATE PRICE-FEED
+
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(expected_params, source_address, source_address)
+        .await
+        .expect("create price-feed");
+
+    // PRIMARY IS STALE AND FALLBACK IS NOT PASSED
+
+    let max_age_sec = 60; // stale price update is published an hour before genesis
+    let result = price_proxy
+        .update_price(
+            feed_key,
+            PYTH_SOL_STALE_PRICE_UPDATE,
+            PYTH_SOL_STALE_PRICE_UPDATE,
+            max_age_sec,
+        )
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::PythPriceTooOld);
+
+    // PRIMARY IS STALE - UPDATE FROM FALLBACK
+
+    let ixs = price_proxy
+        .update_fallback_price_ix(
+            feed_key,
+            PYTH_SOL_STALE_PRICE_UPDATE,
+            PYTH_SOL_STALE_PRICE_UPDATE,
+            SB_SOL_NARROW_CONF_PRICE_SOURCE,
+            max_age_sec,
+        )
+        .await;
+    price_proxy
+        .send_transaction_by(ixs, &[&price_proxy.authority])
+        .await
+        .expect("update price from fallback");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    info!(%feed_key, %price_feed);
+
+    expected
+        .try_accumulate_twap(price_feed.update_timestamp)
+        .unwrap();
+    expected
+        .try_set_price(
+            dec!(150),
+            price_feed.update_timestamp,
+            price_feed.update_slot,
+        )
+        .unwrap();
+    // Switchboard has no EMA, so it is computed on-chain and starts from the first price
+    expected.try_set_ema(dec!(150), Decimal::ZERO).unwrap();
+    expected.set_active_source(ActiveSource::Fallback);
+    assert_eq!(price_feed, expected);
+
+    // PRIMARY IS FRESH - FALLBACK IS IGNORED
+
+    let max_age_sec = 100000000; // cause synthesized price updates are not updating
+    let ixs = price_proxy
+        .update_fallback_price_ix(
+            feed_key,
+            PYTH_SOL_NARROW_CONF_PRICE_UPDATE,
+            PYTH_SOL_NARROW_CONF_PRICE_UPDATE,
+            SB_SOL_NARROW_CONF_PRICE_SOURCE,
+            max_age_sec,
+        )
+        .await;
+    price_proxy
+        .send_transaction_by(ixs, &[&price_proxy.authority])
+        .await
+        .expect("update price from primary");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    info!(%feed_key, %price_feed);
+
+    expected
+        .try_accumulate_twap(price_feed.update_timestamp)
+        .unwrap();
+    expected
+        .try_set_price(
+            dec!(150),
+            price_feed.update_timestamp,
+            price_feed.update_slot,
+        )
+        .unwrap();
+    expected.try_set_confidence(dec!(0.015)).unwrap();
+    expected.try_set_ema(dec!(150), dec!(0.015)).unwrap();
+    expected.set_active_source(ActiveSource::Primary);
+    assert_eq!(price_feed, expected);
+
+    // PRIMARY TIMESTAMP IS MONOTONIC AGAIN AFTER SWITCHING BACK
+
+    let result = price_proxy
+        .update_price(
+            feed_key,
+            PYTH_SOL_OLD_PRICE_UPDATE,
+            PYTH_SOL_OLD_PRICE_UPDATE,
+            max_age_sec,
+        )
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::TimestampIsNotRecent);
+}
+
+#[tokio::test]
+async fn update_no_rollback_when_switching_back_from_fallback() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+
+    let feed_id = get_feed_id_from_hex(PYTH_SOL_FEED_ID_HEX).expect("feed_id_from_hex");
+    let source_address = Pubkey::from(feed_id);
+
+    let fallback_after_sec = 1;
+    let params = PriceFeedParams::new("Direct", "SOL", "USD", "full", "/sol", "pyth", "pyth")
+        .with_fallback("switchboard", SB_SOL_NARROW_CONF_PRICE_SOURCE)
+        .with_fallback_after(fallback_after_sec);
+
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(params, source_address, source_address)
+        .await
+        .expect("create price-feed");
+
+    // UPDATE FROM PRIMARY
+
+    let max_age_sec = 100000000; // cause synthesized price updates are not updating
+    price_proxy
+        .update_price(
+            feed_key,
+            PYTH_SOL_NARROW_CONF_PRICE_UPDATE,
+            PYTH_SOL_NARROW_CONF_PRICE_UPDATE,
+            max_age_sec,
+        )
+        .await
+        .expect("update price from primary");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    let primary_timestamp = price_feed.update_timestamp;
+
+    // PRIMARY IS STALE - UPDATE FROM FALLBACK
+
+    sleep(Duration::from_secs(fallback_after_sec as u64 + 1));
+    let ixs = price_proxy
+        .update_fallback_price_ix(
+            feed_key,
+            PYTH_SOL_STALE_PRICE_UPDATE,
+            PYTH_SOL_STALE_PRICE_UPDATE,
+            SB_SOL_NARROW_CONF_PRICE_SOURCE,
+            60, // stale price update is published an hour before genesis
+        )
+        .await;
+    price_proxy
+        .send_transaction_by(ixs, &[&price_proxy.authority])
+        .await
+        .expect("update price from fallback");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    assert_eq!(price_feed.active_source(), ActiveSource::Fallback);
+    assert_eq!(price_feed.primary_timestamp, primary_timestamp);
+    let expected = price_feed;
+
+    // OLDER PRIMARY PRICE DOESN'T ROLL BACK THE PRICE WHEN SWITCHING BACK
+
+    let result = price_proxy
+        .update_price(
+            feed_key,
+            PYTH_SOL_OLD_PRICE_UPDATE,
+            PYTH_SOL_OLD_PRICE_UPDATE,
+            max_age_sec,
+        )
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::TimestampIsNotRecent);
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    assert_eq!(price_feed, expected);
+
+    // PRIMARY PRICE NOT OLDER THAN THE LAST ONE SWITCHES BACK
+
+    price_proxy
+        .update_price(
+            feed_key,
+            PYTH_SOL_NARROW_CONF_PRICE_UPDATE,
+            PYTH_SOL_NARROW_CONF_PRICE_UPDATE,
+            max_age_sec,
+        )
+        .await
+        .expect("update price from primary");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    assert_eq!(price_feed.active_source(), ActiveSource::Primary);
+    assert_eq!(price_feed.update_timestamp, primary_timestamp);
+}
+
+#[tokio::test]
+async fn holistic_update_fallback_when_pyth_unavailable() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+
+    let feed_id = get_feed_id_from_hex(PYTH_SOL_FEED_ID_HEX).expect("feed_id_from_hex");
+    let source_address = Pubkey::from(feed_id);
+
+    let params = PriceFeedParams::new("Direct", "SOL", "USD", "full", "/sol", "pyth", "pyth")
+        .with_fallback("switchboard", SB_SOL_NARROW_CONF_PRICE_SOURCE);
+
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(params, source_address, source_address)
+        .await
+        .expect("create price-feed");
+
+    // HERMES IS UNAVAILABLE - PYTH FEED ID IS PASSED AS PRIMARY AND FALLBACK IS USED
+
+    let max_age_sec = 100000000; // cause synthesized aggregator is not updating
+    let unavailable_pyth_api_url = "http://127.0.0.1:1".to_string();
+    price_proxy
+        .holistic_update_price(&feed_key, max_age_sec, Some(unavailable_pyth_api_url), None)
+        .await
+        .expect("update price from fallback");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    info!(%feed_key, %price_feed);
+
+    assert_eq!(price_feed.try_price().unwrap(), dec!(150));
+    assert_eq!(price_feed.active_source(), ActiveSource::Fallback);
+}
+
+#[tokio::test]
+async fn update_no_fallback_when_primary_spoofed() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+    let authority_key = price_proxy.authority.pubkey();
+
+    let feed_id = get_feed_id_from_hex(PYTH_SOL_FEED_ID_HEX).expect("feed_id_from_hex");
+    let source_address = Pubkey::from(feed_id);
+
+    let params = PriceFeedParams::new("Direct", "SOL", "USD", "full", "/sol", "pyth", "pyth")
+        .with_fallback("switchboard", SB_SOL_NARROW_CONF_PRICE_SOURCE);
+    let expected = PriceFeed::new(params, authority_key, source_address, source_address);
+
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(params, source_address, source_address)
+        .await
+        .expect("create price-feed");
+
+    // JUNK PRIMARY ACCOUNT DOESN'T SWITCH THE FEED TO FALLBACK
+
+    let max_age_sec = 100000000; // cause synthesized price update is not updating
+    let ixs = price_proxy
+        .update_fallback_price_ix(
+            feed_key,
+            PYTH_SOL_SPOOFED_PRICE_UPDATE,
+            PYTH_SOL_SPOOFED_PRICE_UPDATE,
+            SB_SOL_NARROW_CONF_PRICE_SOURCE,
+            max_age_sec,
+        )
+        .await;
+    let result = price_proxy
+        .send_transaction_by(ixs, &[&price_proxy.authority])
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::SourceOwnerMismatch);
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    assert_eq!(price_feed, expected);
+}
+
+#[tokio::test]
+async fn update_no_fallback_when_primary_recent() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+
+    let feed_id = get_feed_id_from_hex(PYTH_SOL_FEED_ID_HEX).expect("feed_id_from_hex");
+    let source_address = Pubkey::from(feed_id);
+
+    let params = PriceFeedParams::new("Direct", "SOL", "USD", "full", "/sol", "pyth", "pyth")
+        .with_fallback("switchboard", SB_SOL_NARROW_CONF_PRICE_SOURCE);
+
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(params, source_address, source_address)
+        .await
+        .expect("create price-feed");
+
+    // SMALL MAXIMUM AGE DOESN'T FORCE FALLBACK WHILE PRIMARY SOURCE IS WITHIN FALLBACK-AFTER
+
+    let max_age_sec = 10; // old price update is published 30 sec before genesis
+    let ixs = price_proxy
+        .update_fallback_price_ix(
+            feed_key,
+            PYTH_SOL_OLD_PRICE_UPDATE,
+            PYTH_SOL_OLD_PRICE_UPDATE,
+            SB_SOL_NARROW_CONF_PRICE_SOURCE,
+            max_age_sec,
+        )
+        .await;
+    let result = price_proxy
+        .send_transaction_by(ixs, &[&price_proxy.authority])
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::PythPriceTooOld);
+
+    // UPDATE FROM PRIMARY
+
+    let max_age_sec = 100000000; // cause synthesized price updates are not updating
+    price_proxy
+        .update_price(
+            feed_key,
+            PYTH_SOL_NARROW_CONF_PRICE_UPDATE,
+            PYTH_SOL_NARROW_CONF_PRICE_UPDATE,
+            max_age_sec,
+        )
+        .await
+        .expect("update price from primary");
+
+    let PriceFeedView {
+        price_feed: expected,
+        ..
+    } = price_proxy.price_feed(&feed_key).await.expect("price-feed");
+
+    // STALE PRIMARY ACCOUNT DOESN'T FORCE FALLBACK WHILE FEED'S PRIMARY PRICE IS RECENT
+
+    let max_age_sec = 60; // stale price update is published an hour before genesis
+    let ixs = price_proxy
+        .update_fallback_price_ix(
+            feed_key,
+            PYTH_SOL_STALE_PRICE_UPDATE,
+            PYTH_SOL_STALE_PRICE_UPDATE,
+            SB_SOL_NARROW_CONF_PRICE_SOURCE,
+            max_age_sec,
+        )
+        .await;
+    let result = price_proxy
+        .send_transaction_by(ixs, &[&price_proxy.authority])
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::PythPriceTooOld);
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    assert_eq!(price_feed, expected);
+}
+
+#[tokio::test]
+async fn update_rejected_on_reference_divergence() {
+    let TestContext {
+        price_proxy,
+        payer: _,
+    } = init_test().await;
+    let authority_key = price_proxy.authority.pubkey();
+
+    // OFF-CHAIN REFERENCE PRICE-FEED
+
+    let reference_params = PriceFeedParams::new(
+        "Direct",
+        "SOL",
+        "USD",
+        "full",
+        "/sol",
+        "off-chain",
+        "off-chain",
+    );
+    let PriceFeedSignatureView {
+        price_feed: reference_key,
+        ..
+    } = price_proxy
+        .create_price_feed(reference_params, authority_key, authority_key)
+        .await
+        .expect("create reference price-feed");
+    let SignatureView { signature: _ } = price_proxy
+        .write_price(reference_key, dec!(152), Utc::now().timestamp(), None)
+        .await
+        .expect("write reference price");
+
+    // PRICE-FEED WITH 1% TOLERANCE
+
+    let feed_id = get_feed_id_from_hex(PYTH_SOL_FEED_ID_HEX).expect("feed_id_from_hex");
+    let source_address = Pubkey::from(feed_id);
+    let params = PriceFeedParams::new("Direct", "SOL", "USD", "full", "/sol", "pyth", "pyth")
+        .with_reference("off-chain", reference_key, 100);
+    let PriceFeedSignatureView {
+        price_feed: feed_key,
+        ..
+    } = price_proxy
+        .create_price_feed(params, source_address, source_address)
+        .await
+        .expect("create price-feed");
+
+    let max_age_sec = 100000000; // cause synthesized price updates are not updating
+
+    // REFERENCE ACCOUNT IS NOT PASSED
+
+    let result = price_proxy
+        .update_price(
+            feed_key,
+            PYTH_SOL_NARROW_CONF_PRICE_UPDATE,
+            PYTH_SOL_NARROW_CONF_PRICE_UPDATE,
+            max_age_sec,
+        )
+        .await;
+    assert_custom_error(
+        result,
+        PriceProxyErrorDiscriminants::ReferenceAccountMissing,
+    );
+
+    // 150 DIVERGES FROM 152 BY MORE THAN 1%
+
+    let mut ixs = price_proxy
+        .update_price_ix(
+            feed_key,
+            PYTH_SOL_NARROW_CONF_PRICE_UPDATE,
+            PYTH_SOL_NARROW_CONF_PRICE_UPDATE,
+            max_age_sec,
+        )
+        .await;
+    insert_reference_account(&mut ixs[0], reference_key);
+    let result = price_proxy
+        .send_transaction_by(ixs.clone(), &[&price_proxy.authority])
+        .await;
+    assert_custom_error(result, PriceProxyErrorDiscriminants::PriceDivergence);
+
+    // 150 IS WITHIN 1% OF 151
+
+    let SignatureView { signature: _ } = price_proxy
+        .write_price(reference_key, dec!(151), Utc::now().timestamp(), None)
+        .await
+        .expect("write reference price");
+    price_proxy
+        .send_transaction_by(ixs, &[&price_proxy.authority])
+        .await
+        .expect("update price");
+
+    let PriceFeedView { price_feed, .. } =
+        price_proxy.price_feed(&feed_key).await.expect("price-feed");
+    info!(%feed_key, %price_feed);
+    assert_eq!(price_feed.try_price().unwrap(), dec!(150));
+}
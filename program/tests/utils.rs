@@ -1,8 +1,20 @@
 use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
 
+use anchor_lang::{AccountSerialize, Discriminator};
 use async_once_cell::OnceCell;
+use price_proxy::error::PriceProxyErrorDiscriminants;
+use price_proxy::state::marinade;
+use price_proxy::state::price_feed::{PriceFeed, PriceFeedParams};
+use price_proxy::state::stake_pool::{AccountType, StakePool, SANCTUM_SPL_STAKE_POOL_PROGRAM_ID};
 use price_proxy_client::PriceProxyClient;
+use pyth_solana_receiver_sdk::price_update::{
+    get_feed_id_from_hex, PriceUpdateV2, VerificationLevel,
+};
 use pyth_solana_receiver_sdk::PYTH_PUSH_ORACLE_ID;
+use pythnet_sdk::messages::PriceFeedMessage;
+use solana_program::rent::Rent;
+use switchboard_solana::{AggregatorAccountData, SwitchboardDecimal, SWITCHBOARD_PROGRAM_ID};
 use tracing::debug;
 
 pub use dev_utils::*;
@@ -12,9 +24,54 @@ pub const PYTH_GURDIAN_SET: Pubkey = pubkey!("5gxPdahvSzcKySxXxPuRXZZ9s6h8hZ88XD
 
 pub const SB_SOL_PRICE_SOURCE: Pubkey = pubkey!("GvDMxPzN1sCj7L26YDK2HnMRXEQmQ2aemov8YBtPS7vR");
 pub const SB_RAY_PRICE_SOURCE: Pubkey = pubkey!("2oALNZVi5czyHvKbnjE4Jf2gR7dNp1FBpEGaq4PzVAf7");
+/// SOL/USD Switchboard aggregator synthesized at genesis with std deviation of 10% of the price.
+pub const SB_SOL_WIDE_CONF_PRICE_SOURCE: Pubkey =
+    pubkey!("2RQoGk2tLvYRRNqYMQebKmvH8XukjmCvoEeHU9LtTUUv");
+/// SOL/USD Switchboard aggregator synthesized at genesis with zero std deviation.
+pub const SB_SOL_NARROW_CONF_PRICE_SOURCE: Pubkey =
+    pubkey!("5LpaneJtLuct6PRVhwA4gSxEs65hVi7L3T5jtRUJ8z7u");
 pub const SOL_RESERVE_SOURCE: Pubkey = pubkey!("4c8DmkmxmjdN1UPXQ6z5LXKx3kRByCE76PY3PVWLGhfo");
 pub const USDC_RESERVE_SOURCE: Pubkey = pubkey!("BHvvvAHZYBTuUR5HnfSDR9Go1VnhcA1eLvUs9yfTxKey");
 
+/// SOL/USD Price Feed ID from https://pyth.network/developers/price-feed-ids#solana-mainnet-beta
+pub const PYTH_SOL_FEED_ID_HEX: &str =
+    "0xef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d";
+/// SOL/USD `PriceUpdateV2` synthesized at genesis with confidence of 0.01% of the price.
+pub const PYTH_SOL_NARROW_CONF_PRICE_UPDATE: Pubkey =
+    pubkey!("6GtExaYSi7mB3Xsgs6dR8WS1ac9RbvH1ZZzKxnuUqxrF");
+/// SOL/USD `PriceUpdateV2` synthesized at genesis with confidence of 10% of the price.
+pub const PYTH_SOL_WIDE_CONF_PRICE_UPDATE: Pubkey =
+    pubkey!("9kXmEPPrNY5XLpVXUwfRuBTBgMsNkEWpZ8QtJUcZW5Cj");
+/// SOL/USD `PriceUpdateV2` synthesized at genesis and published
+/// `PYTH_SOL_OLD_PRICE_UPDATE_AGE_SEC` before the others.
+pub const PYTH_SOL_OLD_PRICE_UPDATE: Pubkey =
+    pubkey!("6Ytizn4kWDfjYGW39uxz3vv2Xk6W1UoA7UZawdD7H3BV");
+pub const PYTH_SOL_OLD_PRICE_UPDATE_AGE_SEC: i64 = 30;
+/// SOL/USD `PriceUpdateV2` synthesized at genesis and published
+/// `PYTH_SOL_STALE_PRICE_UPDATE_AGE_SEC` before it.
+pub const PYTH_SOL_STALE_PRICE_UPDATE: Pubkey =
+    pubkey!("Hym1n5t8SfYtxPbypTbKEaWn3fdpon3JDhcK5KkPxygK");
+pub const PYTH_SOL_STALE_PRICE_UPDATE_AGE_SEC: i64 = 3600;
+
+/// Marinade `State` with mSOL price of 1.25 SOL updated at genesis epoch.
+pub const MARINADE_STATE_SOURCE: Pubkey = pubkey!("8szGkuLTAux9XMgZ2vtY39jVSowEcpBfFfD8hXSEqdGC");
+pub const MARINADE_MSOL_PRICE: u64 = marinade::PRICE_DENOMINATOR + marinade::PRICE_DENOMINATOR / 4;
+/// Sanctum single-validator SPL pool with 1.1 SOL per pool token updated at genesis epoch.
+pub const SANCTUM_SPL_POOL_SOURCE: Pubkey = pubkey!("DtYKbQELgMZ3ihFUrCcCs9gy4djcUuhwgR7UpxVpP2Tg");
+
+/// Program which owns look-alike copies of source accounts
+pub const SPOOFING_PROGRAM_ID: Pubkey = pubkey!("EYe9zWsv3ATGegQrdSpyfUZmk1JrFCUBzDJADD8qesrK");
+pub const PYTH_SOL_SPOOFED_PRICE_UPDATE: Pubkey =
+    pubkey!("9fvwt1W8RiYJMbVDNq5gQc2VaSnvCHRXCFb5Prm9dnwx");
+pub const MARINADE_SPOOFED_STATE_SOURCE: Pubkey =
+    pubkey!("HVpiYYSDPQYVtZy92VEsToFC6QgKGEn1NoCbafvgL3po");
+pub const SPOOFED_STAKE_POOL_SOURCE: Pubkey =
+    pubkey!("37hQrZJFb8zT1sp2tJM7ZVuGmPFpBi5PpSQ6JfxExM8o");
+
+/// Off-chain Price-feed of `sys_admin` created by the program version 1, i.e. of the old size.
+pub const LEGACY_PRICE_FEED: Pubkey = pubkey!("FSVSciSKbPjC1QfToLYGB8MhC4e5v1nRfZDEPaPqbpMK");
+pub const LEGACY_PRICE_FEED_SIZE: usize = 416;
+
 pub async fn init_test() -> TestContext {
     tracing_init();
 
@@ -80,6 +137,106 @@ pub async fn init_test() -> TestContext {
             add_price_feed_accounts(&mut solana_genesis, PYTH_CONFIG, "pyth-config");
             add_price_feed_accounts(&mut solana_genesis, PYTH_GURDIAN_SET, "pyth-guardian-set");
 
+            add_pyth_price_update_account(
+                &mut solana_genesis,
+                PYTH_SOL_NARROW_CONF_PRICE_UPDATE,
+                &pyth_solana_receiver_sdk::id(),
+                PYTH_SOL_FEED_ID_HEX,
+                15_000_000_000,
+                1_500_000,
+                -8,
+                0,
+            );
+            add_pyth_price_update_account(
+                &mut solana_genesis,
+                PYTH_SOL_WIDE_CONF_PRICE_UPDATE,
+                &pyth_solana_receiver_sdk::id(),
+                PYTH_SOL_FEED_ID_HEX,
+                15_000_000_000,
+                1_500_000_000,
+                -8,
+                0,
+            );
+            add_pyth_price_update_account(
+                &mut solana_genesis,
+                PYTH_SOL_OLD_PRICE_UPDATE,
+                &pyth_solana_receiver_sdk::id(),
+                PYTH_SOL_FEED_ID_HEX,
+                14_000_000_000,
+                1_400_000,
+                -8,
+                PYTH_SOL_OLD_PRICE_UPDATE_AGE_SEC,
+            );
+            add_pyth_price_update_account(
+                &mut solana_genesis,
+                PYTH_SOL_STALE_PRICE_UPDATE,
+                &pyth_solana_receiver_sdk::id(),
+                PYTH_SOL_FEED_ID_HEX,
+                15_000_000_000,
+                1_500_000,
+                -8,
+                PYTH_SOL_STALE_PRICE_UPDATE_AGE_SEC,
+            );
+
+            add_switchboard_aggregator_account(
+                &mut solana_genesis,
+                SB_SOL_WIDE_CONF_PRICE_SOURCE,
+                150,
+                15,
+            );
+
+            add_switchboard_aggregator_account(
+                &mut solana_genesis,
+                SB_SOL_NARROW_CONF_PRICE_SOURCE,
+                150,
+                0,
+            );
+
+            add_marinade_state_account(
+                &mut solana_genesis,
+                MARINADE_STATE_SOURCE,
+                &marinade::MARINADE_PROGRAM_ID,
+                MARINADE_MSOL_PRICE,
+            );
+            add_stake_pool_account(
+                &mut solana_genesis,
+                SANCTUM_SPL_POOL_SOURCE,
+                &SANCTUM_SPL_STAKE_POOL_PROGRAM_ID,
+                1_100_000_000_000,
+                1_000_000_000_000,
+            );
+
+            // Look-alike source accounts which are owned by other program
+            add_pyth_price_update_account(
+                &mut solana_genesis,
+                PYTH_SOL_SPOOFED_PRICE_UPDATE,
+                &SPOOFING_PROGRAM_ID,
+                PYTH_SOL_FEED_ID_HEX,
+                15_000_000_000,
+                1_500_000,
+                -8,
+                0,
+            );
+            add_marinade_state_account(
+                &mut solana_genesis,
+                MARINADE_SPOOFED_STATE_SOURCE,
+                &SPOOFING_PROGRAM_ID,
+                MARINADE_MSOL_PRICE,
+            );
+            add_stake_pool_account(
+                &mut solana_genesis,
+                SPOOFED_STAKE_POOL_SOURCE,
+                &SPOOFING_PROGRAM_ID,
+                1_100_000_000_000,
+                1_000_000_000_000,
+            );
+
+            add_legacy_price_feed_account(
+                &mut solana_genesis,
+                LEGACY_PRICE_FEED,
+                &sys_admin.pubkey(),
+            );
+
             let (validator, payer) = solana_genesis.start_async().await;
             debug!(rpc = %validator.rpc_url(), "solana validator started");
 
@@ -126,3 +283,175 @@ pub fn add_price_feed_accounts(genesis: &mut TestValidatorGenesis, pubkey: Pubke
         }])
         .expect("add_accounts_from_json_files");
 }
+
+/// Adds fully verified Pyth `PriceUpdateV2` account owned by `owner` and published `age_sec`
+/// before genesis time.
+#[allow(dead_code, clippy::too_many_arguments)]
+pub fn add_pyth_price_update_account(
+    genesis: &mut TestValidatorGenesis,
+    pubkey: Pubkey,
+    owner: &Pubkey,
+    feed_id_hex: &str,
+    price: i64,
+    conf: u64,
+    exponent: i32,
+    age_sec: i64,
+) {
+    let publish_time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("now")
+        .as_secs() as i64
+        - age_sec;
+    let price_update = PriceUpdateV2 {
+        write_authority: Pubkey::new_unique(),
+        verification_level: VerificationLevel::Full,
+        price_message: PriceFeedMessage {
+            feed_id: get_feed_id_from_hex(feed_id_hex).expect("feed_id_from_hex"),
+            price,
+            conf,
+            exponent,
+            publish_time,
+            prev_publish_time: publish_time - 1,
+            ema_price: price,
+            ema_conf: conf,
+        },
+        posted_slot: 0,
+    };
+
+    let mut data = Vec::new();
+    price_update
+        .try_serialize(&mut data)
+        .expect("serialize PriceUpdateV2");
+
+    add_account_with_data(genesis, pubkey, owner, &data);
+}
+
+/// Adds Switchboard `AggregatorAccountData` with the latest round confirmed at genesis time.
+#[allow(dead_code)]
+pub fn add_switchboard_aggregator_account(
+    genesis: &mut TestValidatorGenesis,
+    pubkey: Pubkey,
+    price: i128,
+    std_deviation: i128,
+) {
+    let mut aggregator: AggregatorAccountData = bytemuck::Zeroable::zeroed();
+    aggregator.latest_confirmed_round.round_open_timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("now")
+        .as_secs() as i64;
+    aggregator.latest_confirmed_round.result = SwitchboardDecimal {
+        mantissa: price,
+        scale: 0,
+    };
+    aggregator.latest_confirmed_round.std_deviation = SwitchboardDecimal {
+        mantissa: std_deviation,
+        scale: 0,
+    };
+
+    let mut data = AggregatorAccountData::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(bytemuck::bytes_of(&aggregator));
+
+    add_account_with_data(genesis, pubkey, &SWITCHBOARD_PROGRAM_ID, &data);
+}
+
+/// Adds Marinade `State` account owned by `owner` and updated at genesis epoch.
+#[allow(dead_code)]
+pub fn add_marinade_state_account(
+    genesis: &mut TestValidatorGenesis,
+    pubkey: Pubkey,
+    owner: &Pubkey,
+    msol_price: u64,
+) {
+    let state = marinade::State {
+        msol_mint: pubkey!("mSoLzYCxHdYgdzU16g5QSh3i5K3z3KZK7ytfqcJm7So"),
+        msol_supply: 1_000_000_000_000,
+        msol_price,
+        ..Default::default()
+    };
+
+    let mut data = marinade::STATE_DISCRIMINATOR.to_vec();
+    data.extend(borsh::to_vec(&state).expect("serialize Marinade state"));
+    // Rest of the account isn't read by the program
+    data.resize(data.len() + 256, 0);
+
+    add_account_with_data(genesis, pubkey, owner, &data);
+}
+
+/// Adds SPL `StakePool` account owned by `owner` and updated at genesis epoch.
+#[allow(dead_code)]
+pub fn add_stake_pool_account(
+    genesis: &mut TestValidatorGenesis,
+    pubkey: Pubkey,
+    owner: &Pubkey,
+    total_lamports: u64,
+    pool_token_supply: u64,
+) {
+    let stake_pool = StakePool {
+        account_type: AccountType::StakePool,
+        total_lamports,
+        pool_token_supply,
+        last_update_epoch: 0,
+        ..Default::default()
+    };
+
+    let mut data = borsh::to_vec(&stake_pool).expect("serialize StakePool");
+    // Stake pool accounts are allocated with some space for future fields
+    data.resize(data.len() + 64, 0);
+
+    add_account_with_data(genesis, pubkey, owner, &data);
+}
+
+/// Adds off-chain Price-feed account of `authority` with price 150 in layout of version 1.
+#[allow(dead_code)]
+pub fn add_legacy_price_feed_account(
+    genesis: &mut TestValidatorGenesis,
+    pubkey: Pubkey,
+    authority: &Pubkey,
+) {
+    let params = PriceFeedParams::new(
+        "Direct",
+        "SOL",
+        "USD",
+        "full",
+        "/sol",
+        "off-chain",
+        "off-chain",
+    );
+    let mut price_feed =
+        PriceFeed::new(params, *authority, *authority, *authority).with_price(dec!(150), 0, 0);
+    price_feed.version = 1;
+
+    // Fields added since version 1 took place of zeroed padding
+    let data = &bytemuck::bytes_of(&price_feed)[..LEGACY_PRICE_FEED_SIZE];
+    add_account_with_data(genesis, pubkey, &price_proxy::ID, data);
+}
+
+fn add_account_with_data(
+    genesis: &mut TestValidatorGenesis,
+    pubkey: Pubkey,
+    owner: &Pubkey,
+    data: &[u8],
+) {
+    let mut account = AccountSharedData::new(
+        Rent::default().minimum_balance(data.len()),
+        data.len(),
+        owner,
+    );
+    account.set_data_from_slice(data);
+    genesis.add_account(pubkey, account);
+}
+
+/// Asserts that transaction failed with PriceProxy custom error `expected`.
+#[allow(dead_code)]
+#[track_caller]
+pub fn assert_custom_error<T>(result: anyhow::Result<T>, expected: PriceProxyErrorDiscriminants) {
+    let Err(err) = result else {
+        panic!("expected {expected:?} error, got success");
+    };
+    let code = format!("custom program error: {:#x}", expected as u32);
+    let err = format!("{err:#}");
+    assert!(
+        err.contains(&code),
+        "expected {expected:?} ({code}), got: {err}"
+    );
+}